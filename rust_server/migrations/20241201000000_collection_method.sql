@@ -0,0 +1,16 @@
+-- migrations/20241201000000_collection_method.sql
+-- 서버별 메트릭 수집 방식 (local: sysinfo, ssh: 원격 /proc, agent: 에이전트 push)
+DO $$ BEGIN
+    CREATE TYPE collection_method AS ENUM ('local', 'ssh', 'agent');
+EXCEPTION
+    WHEN duplicate_object THEN
+        NULL;
+END $$;
+
+ALTER TABLE servers
+    ADD COLUMN IF NOT EXISTS collection_method collection_method NOT NULL DEFAULT 'ssh';
+
+-- 기존에 등록된 로컬 호스트는 sysinfo 로 수집
+UPDATE servers
+SET collection_method = 'local'
+WHERE LOWER(hostname) IN ('localhost', '127.0.0.1', '::1');
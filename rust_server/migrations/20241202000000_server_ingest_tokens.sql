@@ -0,0 +1,14 @@
+-- migrations/20241202000000_server_ingest_tokens.sql
+-- 에이전트가 메트릭을 push 할 때 사용하는 서버별 토큰 (secret 은 해시로만 저장)
+CREATE TABLE IF NOT EXISTS server_ingest_tokens (
+    id VARCHAR(36) PRIMARY KEY,
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    token_hash TEXT NOT NULL,
+    description TEXT,
+    created_by VARCHAR(36) REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_server_ingest_tokens_server ON server_ingest_tokens(server_id);
@@ -0,0 +1,15 @@
+-- migrations/20241203000000_agent_enrollment_tokens.sql
+-- 에이전트 등록용 1회성 토큰 (secret 은 해시로만 저장)
+CREATE TABLE IF NOT EXISTS agent_enrollment_tokens (
+    id VARCHAR(36) PRIMARY KEY,
+    token_hash TEXT NOT NULL,
+    description TEXT,
+    created_by VARCHAR(36) REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    server_id VARCHAR(36) REFERENCES servers(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_agent_enrollment_tokens_expires ON agent_enrollment_tokens(expires_at)
+    WHERE used_at IS NULL;
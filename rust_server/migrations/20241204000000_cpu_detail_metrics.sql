@@ -0,0 +1,11 @@
+-- migrations/20241204000000_cpu_detail_metrics.sql
+-- 코어별 사용률, load average, CPU 시간 비율
+ALTER TABLE metrics_snapshots
+    ADD COLUMN IF NOT EXISTS cpu_per_core JSONB NOT NULL DEFAULT '[]',
+    ADD COLUMN IF NOT EXISTS load_1 DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS load_5 DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS load_15 DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS cpu_user DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS cpu_system DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS cpu_iowait DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS cpu_steal DOUBLE PRECISION;
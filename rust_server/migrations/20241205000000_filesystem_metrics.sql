@@ -0,0 +1,4 @@
+-- migrations/20241205000000_filesystem_metrics.sql
+-- 마운트 포인트별 디스크/inode 사용량
+ALTER TABLE metrics_snapshots
+    ADD COLUMN IF NOT EXISTS filesystems JSONB NOT NULL DEFAULT '[]';
@@ -0,0 +1,4 @@
+-- migrations/20241206000000_network_interface_metrics.sql
+-- 인터페이스별 처리량. network_rx/network_tx 는 이제 bytes/s 합계
+ALTER TABLE metrics_snapshots
+    ADD COLUMN IF NOT EXISTS network_interfaces JSONB NOT NULL DEFAULT '[]';
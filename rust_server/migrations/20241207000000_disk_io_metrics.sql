@@ -0,0 +1,7 @@
+-- migrations/20241207000000_disk_io_metrics.sql
+-- 블록 장치별 I/O 와 히스토리/알림용 요약 값
+ALTER TABLE metrics_snapshots
+    ADD COLUMN IF NOT EXISTS disk_io JSONB NOT NULL DEFAULT '[]',
+    ADD COLUMN IF NOT EXISTS disk_io_util DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS disk_read_bps BIGINT,
+    ADD COLUMN IF NOT EXISTS disk_write_bps BIGINT;
@@ -0,0 +1,10 @@
+-- migrations/20241208000000_memory_detail_metrics.sql
+-- 메모리 상세 (bytes). memory_usage 는 MemAvailable 기준
+ALTER TABLE metrics_snapshots
+    ADD COLUMN IF NOT EXISTS memory_total BIGINT,
+    ADD COLUMN IF NOT EXISTS memory_used BIGINT,
+    ADD COLUMN IF NOT EXISTS memory_available BIGINT,
+    ADD COLUMN IF NOT EXISTS memory_cached BIGINT,
+    ADD COLUMN IF NOT EXISTS memory_buffers BIGINT,
+    ADD COLUMN IF NOT EXISTS swap_total BIGINT,
+    ADD COLUMN IF NOT EXISTS swap_used BIGINT;
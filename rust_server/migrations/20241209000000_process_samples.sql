@@ -0,0 +1,16 @@
+-- migrations/20241209000000_process_samples.sql
+-- 스냅샷마다 저장되는 상위 프로세스 샘플 (시간대별 상위 소비 프로세스 조회용)
+CREATE TABLE IF NOT EXISTS process_samples (
+    id BIGSERIAL PRIMARY KEY,
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    timestamp TIMESTAMPTZ NOT NULL,
+    pid INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    cpu_usage DOUBLE PRECISION NOT NULL,
+    memory_usage BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_process_samples_server_time
+    ON process_samples (server_id, timestamp DESC);
+CREATE INDEX IF NOT EXISTS idx_process_samples_server_process
+    ON process_samples (server_id, pid, name, timestamp);
@@ -0,0 +1,16 @@
+-- migrations/20241210000000_process_watches.sql
+-- 서버별로 항상 실행 중이어야 하는 프로세스 (수집 주기마다 프로세스 목록과 비교)
+CREATE TABLE IF NOT EXISTS process_watches (
+    id VARCHAR(36) PRIMARY KEY,
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    pattern TEXT NOT NULL,
+    match_command BOOLEAN NOT NULL DEFAULT FALSE,
+    min_instances INTEGER NOT NULL DEFAULT 1,
+    max_cpu DOUBLE PRECISION,
+    max_memory BIGINT,
+    created_by VARCHAR(36) REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_process_watches_server ON process_watches(server_id);
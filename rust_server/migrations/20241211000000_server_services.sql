@@ -0,0 +1,8 @@
+-- migrations/20241211000000_server_services.sql
+-- 서버별로 상태를 확인할 systemd 유닛
+CREATE TABLE IF NOT EXISTS server_services (
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    unit VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (server_id, unit)
+);
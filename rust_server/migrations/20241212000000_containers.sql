@@ -0,0 +1,29 @@
+-- migrations/20241212000000_containers.sql
+-- 컨테이너 호스트(category = container)에서 실행 중인 컨테이너와 시간별 메트릭
+CREATE TABLE IF NOT EXISTS containers (
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    container_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    image TEXT,
+    state VARCHAR(32) NOT NULL,
+    restart_count INTEGER,
+    first_seen_at TIMESTAMPTZ NOT NULL,
+    last_seen_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (server_id, container_id)
+);
+
+CREATE TABLE IF NOT EXISTS container_metrics (
+    id BIGSERIAL PRIMARY KEY,
+    server_id VARCHAR(36) NOT NULL,
+    container_id VARCHAR(64) NOT NULL,
+    timestamp TIMESTAMPTZ NOT NULL,
+    cpu_usage DOUBLE PRECISION NOT NULL,
+    memory_usage BIGINT NOT NULL,
+    memory_limit BIGINT,
+    network_rx DOUBLE PRECISION,
+    network_tx DOUBLE PRECISION,
+    FOREIGN KEY (server_id, container_id) REFERENCES containers(server_id, container_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_container_metrics_container_time
+    ON container_metrics (server_id, container_id, timestamp DESC);
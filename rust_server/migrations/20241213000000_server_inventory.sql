@@ -0,0 +1,12 @@
+-- migrations/20241213000000_server_inventory.sql
+-- 현재 호스트 정보는 servers.metadata->'facts' 에 두고, 달라질 때마다 전체 스냅샷을 이력으로 남긴다
+CREATE TABLE IF NOT EXISTS server_inventory_history (
+    id BIGSERIAL PRIMARY KEY,
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    collected_at TIMESTAMPTZ NOT NULL,
+    changed_fields TEXT[] NOT NULL,
+    facts JSONB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_server_inventory_history_server_time
+    ON server_inventory_history (server_id, collected_at DESC);
@@ -0,0 +1,32 @@
+-- migrations/20241214000000_server_packages.sql
+-- 서버별 설치 패키지와 대기 중인 업데이트. 조회할 때마다 통째로 교체한다
+CREATE TABLE IF NOT EXISTS server_packages (
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    version TEXT NOT NULL,
+    architecture VARCHAR(32) NOT NULL DEFAULT '',
+    PRIMARY KEY (server_id, name, architecture)
+);
+
+CREATE INDEX IF NOT EXISTS idx_server_packages_name ON server_packages (name);
+
+CREATE TABLE IF NOT EXISTS server_package_updates (
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    current_version TEXT,
+    available_version TEXT NOT NULL,
+    security BOOLEAN NOT NULL DEFAULT false,
+    PRIMARY KEY (server_id, name)
+);
+
+CREATE TABLE IF NOT EXISTS server_package_status (
+    server_id VARCHAR(36) PRIMARY KEY REFERENCES servers(id) ON DELETE CASCADE,
+    manager VARCHAR(16) NOT NULL,
+    package_count INTEGER NOT NULL,
+    update_count INTEGER NOT NULL,
+    security_update_count INTEGER NOT NULL,
+    collected_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_server_package_status_security
+    ON server_package_status (security_update_count DESC);
@@ -0,0 +1,27 @@
+-- migrations/20241215000000_server_ports.sql
+-- 서버별 현재 대기 포트와 열림/닫힘 이력
+CREATE TABLE IF NOT EXISTS server_ports (
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    protocol VARCHAR(8) NOT NULL,
+    address VARCHAR(64) NOT NULL,
+    port INTEGER NOT NULL,
+    process VARCHAR(255),
+    pid INTEGER,
+    first_seen_at TIMESTAMPTZ NOT NULL,
+    last_seen_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (server_id, protocol, address, port)
+);
+
+CREATE TABLE IF NOT EXISTS server_port_changes (
+    id BIGSERIAL PRIMARY KEY,
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    protocol VARCHAR(8) NOT NULL,
+    address VARCHAR(64) NOT NULL,
+    port INTEGER NOT NULL,
+    process VARCHAR(255),
+    change VARCHAR(16) NOT NULL,      -- opened | closed
+    detected_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_server_port_changes_server_time
+    ON server_port_changes (server_id, detected_at DESC);
@@ -0,0 +1,29 @@
+-- migrations/20241216000000_checks.sql
+-- 서버별 합성 검사 (HTTP 등) 설정과 결과 시계열
+CREATE TABLE IF NOT EXISTS checks (
+    id VARCHAR(36) PRIMARY KEY,
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    check_type VARCHAR(16) NOT NULL,
+    config JSONB NOT NULL,
+    interval_seconds INTEGER NOT NULL DEFAULT 60,
+    failure_threshold INTEGER NOT NULL DEFAULT 3,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by VARCHAR(36) REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_checks_server ON checks(server_id);
+
+CREATE TABLE IF NOT EXISTS check_results (
+    id BIGSERIAL PRIMARY KEY,
+    check_id VARCHAR(36) NOT NULL REFERENCES checks(id) ON DELETE CASCADE,
+    timestamp TIMESTAMPTZ NOT NULL,
+    success BOOLEAN NOT NULL,
+    response_time_ms DOUBLE PRECISION,
+    status_code INTEGER,
+    message TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_check_results_check_time
+    ON check_results (check_id, timestamp DESC);
@@ -0,0 +1,14 @@
+-- migrations/20241217000000_check_certificates.sql
+-- TLS 검사가 마지막으로 받은 leaf 인증서
+CREATE TABLE IF NOT EXISTS check_certificates (
+    check_id VARCHAR(36) PRIMARY KEY REFERENCES checks(id) ON DELETE CASCADE,
+    subject TEXT NOT NULL,
+    issuer TEXT NOT NULL,
+    sans TEXT[] NOT NULL DEFAULT '{}',
+    serial VARCHAR(128) NOT NULL,
+    not_before TIMESTAMPTZ NOT NULL,
+    not_after TIMESTAMPTZ NOT NULL,
+    chain_valid BOOLEAN NOT NULL,
+    chain_error TEXT,
+    checked_at TIMESTAMPTZ NOT NULL
+);
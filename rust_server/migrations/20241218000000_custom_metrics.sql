@@ -0,0 +1,20 @@
+-- migrations/20241218000000_custom_metrics.sql
+-- 명령 검사(Nagios 플러그인)의 성능 데이터 시계열
+CREATE TABLE IF NOT EXISTS custom_metrics (
+    id BIGSERIAL PRIMARY KEY,
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    check_id VARCHAR(36) NOT NULL REFERENCES checks(id) ON DELETE CASCADE,
+    label TEXT NOT NULL,
+    timestamp TIMESTAMPTZ NOT NULL,
+    value DOUBLE PRECISION NOT NULL,
+    unit TEXT,
+    warn TEXT,
+    crit TEXT,
+    min DOUBLE PRECISION,
+    max DOUBLE PRECISION
+);
+
+CREATE INDEX IF NOT EXISTS idx_custom_metrics_check_time
+    ON custom_metrics (check_id, timestamp DESC);
+CREATE INDEX IF NOT EXISTS idx_custom_metrics_server_label_time
+    ON custom_metrics (server_id, label, timestamp DESC);
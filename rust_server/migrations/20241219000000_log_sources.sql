@@ -0,0 +1,21 @@
+-- migrations/20241219000000_log_sources.sql
+-- SSH 로 따라 읽는 서버별 로그 파일과 읽은 위치
+CREATE TABLE IF NOT EXISTS log_sources (
+    id VARCHAR(36) PRIMARY KEY,
+    server_id VARCHAR(36) NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    path TEXT NOT NULL,
+    component VARCHAR(255) NOT NULL,
+    level_rules JSONB NOT NULL DEFAULT '[]',
+    default_level log_level NOT NULL DEFAULT 'info',
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    inode BIGINT,
+    position BIGINT NOT NULL DEFAULT 0,
+    created_by VARCHAR(36) REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (server_id, path)
+);
+
+-- 따라 읽은 로그가 쌓인 서버도 삭제할 수 있도록
+ALTER TABLE logs DROP CONSTRAINT IF EXISTS logs_server_id_fkey;
+ALTER TABLE logs ADD CONSTRAINT logs_server_id_fkey
+    FOREIGN KEY (server_id) REFERENCES servers(id) ON DELETE CASCADE;
@@ -0,0 +1,94 @@
+// src/agent/buffer.rs
+use std::collections::VecDeque;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use anyhow::{Context, Result};
+use tracing::warn;
+use crate::models::metrics::ServerMetrics;
+
+// 전송 전 메트릭을 보관하는 버퍼. 가득 차면 가장 오래된 샘플부터 버린다.
+pub struct MetricsBuffer {
+    capacity: usize,
+    items: VecDeque<ServerMetrics>,
+    spool_path: Option<PathBuf>,
+}
+
+impl MetricsBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            items: VecDeque::new(),
+            spool_path: None,
+        }
+    }
+
+    // 파일에 남아 있던 샘플을 읽어서 버퍼 생성 (JSON lines)
+    pub fn with_spool(capacity: usize, path: PathBuf) -> Result<Self> {
+        let mut buffer = Self::new(capacity);
+
+        if path.exists() {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read buffer file {}", path.display()))?;
+            for line in content.lines().filter(|l| !l.trim().is_empty()) {
+                match serde_json::from_str::<ServerMetrics>(line) {
+                    Ok(metrics) => {
+                        buffer.push(metrics);
+                    }
+                    Err(e) => warn!("Skipping corrupt buffered sample: {}", e),
+                }
+            }
+        }
+
+        buffer.spool_path = Some(path);
+        Ok(buffer)
+    }
+
+    // 버려진 샘플 수 반환
+    pub fn push(&mut self, metrics: ServerMetrics) -> usize {
+        self.items.push_back(metrics);
+        let mut dropped = 0;
+        while self.items.len() > self.capacity {
+            self.items.pop_front();
+            dropped += 1;
+        }
+        dropped
+    }
+
+    pub fn peek_batch(&self, size: usize) -> Vec<ServerMetrics> {
+        self.items.iter().take(size).cloned().collect()
+    }
+
+    pub fn remove_front(&mut self, count: usize) {
+        let count = count.min(self.items.len());
+        self.items.drain(..count);
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn persist(&self) -> Result<()> {
+        let path = match &self.spool_path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        // 임시 파일에 쓰고 교체해서 중간에 죽어도 파일이 깨지지 않도록
+        let tmp_path = path.with_extension("tmp");
+        let mut file = fs::File::create(&tmp_path)
+            .with_context(|| format!("Failed to create {}", tmp_path.display()))?;
+        for metrics in &self.items {
+            serde_json::to_writer(&mut file, metrics)?;
+            file.write_all(b"\n")?;
+        }
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)
+            .with_context(|| format!("Failed to replace {}", path.display()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+// src/agent/config.rs
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use anyhow::{anyhow, Context, Result};
+use crate::models::metrics::MAX_BATCH_SIZE;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentConfig {
+    pub server_url: String,    // 예: https://monitor.example.com
+    pub server_id: String,
+    pub token: String,         // 서버별 ingest 토큰
+    #[serde(default = "default_interval_seconds")]
+    pub interval_seconds: u64,
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    #[serde(default = "default_buffer_capacity")]
+    pub buffer_capacity: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_path: Option<PathBuf>,   // 재시작 시에도 유지할 버퍼 파일
+    #[serde(default = "default_max_backoff_seconds")]
+    pub max_backoff_seconds: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert_path: Option<PathBuf>,  // 사설 CA 인증서 (PEM)
+    #[serde(default)]
+    pub allow_http: bool,               // 로컬 테스트 용도로만 사용
+}
+
+fn default_interval_seconds() -> u64 { 10 }
+fn default_batch_size() -> usize { 30 }
+fn default_buffer_capacity() -> usize { 8640 }  // 10초 간격 기준 하루치
+fn default_max_backoff_seconds() -> u64 { 300 }
+
+impl AgentConfig {
+    pub fn new(server_url: &str, server_id: &str, token: &str) -> Self {
+        Self {
+            server_url: server_url.trim_end_matches('/').to_string(),
+            server_id: server_id.to_string(),
+            token: token.to_string(),
+            interval_seconds: default_interval_seconds(),
+            batch_size: default_batch_size(),
+            buffer_capacity: default_buffer_capacity(),
+            buffer_path: None,
+            max_backoff_seconds: default_max_backoff_seconds(),
+            ca_cert_path: None,
+            allow_http: false,
+        }
+    }
+
+    // 설정 파일 + FLICK_AGENT_* 환경변수
+    pub fn load(path: &Path) -> Result<Self> {
+        let config: Self = config::Config::builder()
+            .add_source(config::File::from(path))
+            .add_source(config::Environment::with_prefix("FLICK_AGENT"))
+            .build()
+            .with_context(|| format!("Failed to read agent config {}", path.display()))?
+            .try_deserialize()
+            .context("Invalid agent config")?;
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.server_id.is_empty() {
+            return Err(anyhow!("server_id must be set"));
+        }
+        if self.token.is_empty() {
+            return Err(anyhow!("token must be set"));
+        }
+        if !(self.server_url.starts_with("https://") || (self.allow_http && self.server_url.starts_with("http://"))) {
+            return Err(anyhow!("server_url must use https:// (got {})", self.server_url));
+        }
+        if self.interval_seconds == 0 || self.batch_size == 0 || self.buffer_capacity == 0 {
+            return Err(anyhow!("interval_seconds, batch_size and buffer_capacity must be positive"));
+        }
+        // 서버가 거절하는 크기면 배치가 매번 버려진다
+        if self.batch_size > MAX_BATCH_SIZE {
+            return Err(anyhow!("batch_size must be at most {} (got {})", MAX_BATCH_SIZE, self.batch_size));
+        }
+        Ok(())
+    }
+
+    pub fn batch_url(&self) -> String {
+        format!(
+            "{}/api/v1/servers/{}/metrics/batch",
+            self.server_url.trim_end_matches('/'),
+            self.server_id
+        )
+    }
+
+    pub fn facts_url(&self) -> String {
+        format!(
+            "{}/api/v1/servers/{}/inventory",
+            self.server_url.trim_end_matches('/'),
+            self.server_id
+        )
+    }
+}
@@ -0,0 +1,102 @@
+// src/agent/enroll.rs
+use std::net::UdpSocket;
+use std::path::{Path, PathBuf};
+use anyhow::{anyhow, Context, Result};
+use sysinfo::{System, SystemExt};
+use crate::agent::config::AgentConfig;
+use crate::api::response::ApiResponse;
+use crate::models::agent::{EnrollRequest, EnrollResponse, HostFacts};
+use crate::monitoring::collector::system_host_facts;
+
+pub struct EnrollOptions {
+    pub server_url: String,
+    pub token: String,
+    pub name: Option<String>,
+    pub ca_cert_path: Option<PathBuf>,
+    pub allow_http: bool,
+}
+
+pub fn detect_host_facts(server_url: &str) -> HostFacts {
+    let mut system = System::new();
+    system.refresh_memory();
+    system.refresh_cpu();
+
+    HostFacts {
+        ip_address: outbound_ip(server_url),
+        ..system_host_facts(&system)
+    }
+}
+
+// 서버로 나가는 인터페이스의 주소 (UDP connect 는 패킷을 보내지 않는다)
+pub(crate) fn outbound_ip(server_url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(server_url).ok()?;
+    let host = url.host_str()?;
+    let port = url.port_or_known_default()?;
+
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect((host, port)).ok()?;
+    Some(socket.local_addr().ok()?.ip().to_string())
+}
+
+pub async fn enroll(options: &EnrollOptions) -> Result<AgentConfig> {
+    let server_url = options.server_url.trim_end_matches('/');
+    if !(server_url.starts_with("https://") || (options.allow_http && server_url.starts_with("http://"))) {
+        return Err(anyhow!("server_url must use https:// (got {})", server_url));
+    }
+
+    let facts = detect_host_facts(server_url);
+    if facts.hostname.is_empty() {
+        return Err(anyhow!("Could not detect hostname"));
+    }
+
+    let client = super::http_client(options.ca_cert_path.as_deref())?;
+    let response = client
+        .post(format!("{}/api/v1/agents/enroll", server_url))
+        .json(&EnrollRequest {
+            token: options.token.clone(),
+            name: options.name.clone(),
+            facts,
+        })
+        .send()
+        .await
+        .context("Enrollment request failed")?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(anyhow!(
+            "Enrollment rejected ({}): {}",
+            status,
+            response.text().await.unwrap_or_default()
+        ));
+    }
+
+    let body: ApiResponse<EnrollResponse> = response.json().await.context("Invalid enrollment response")?;
+    let enrolled = body.data.ok_or_else(|| anyhow!("Empty enrollment response"))?;
+
+    // 서버가 알려준 주소보다 에이전트가 실제로 접속한 주소를 사용
+    let mut config = enrolled.config;
+    config.server_url = server_url.to_string();
+    config.ca_cert_path = options.ca_cert_path.clone();
+    config.allow_http = options.allow_http;
+    config.validate()?;
+    Ok(config)
+}
+
+// 토큰이 포함되므로 소유자만 읽을 수 있게 저장
+pub fn write_config(config: &AgentConfig, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+
+    let content = toml::to_string(config).context("Failed to serialize agent config")?;
+    std::fs::write(path, content)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(())
+}
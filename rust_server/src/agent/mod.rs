@@ -0,0 +1,308 @@
+// src/agent/mod.rs
+pub mod buffer;
+pub mod config;
+pub mod enroll;
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use anyhow::{anyhow, Context, Result};
+use reqwest::StatusCode;
+use tracing::{debug, info, warn, error};
+use crate::api::ingest::FactsIngestResult;
+use crate::api::response::ApiResponse;
+use crate::models::agent::HostFacts;
+use crate::models::metrics::{IngestResult, MetricsBatch, ServerMetrics};
+use crate::monitoring::collector::LocalSource;
+use crate::monitoring::inventory::FACTS_INTERVAL;
+use crate::monitoring::MetricSource;
+use buffer::MetricsBuffer;
+use config::AgentConfig;
+
+#[derive(Debug)]
+pub enum SendError {
+    // 재시도해도 소용없는 요청 - 해당 배치는 버린다
+    Rejected(String),
+    // 네트워크 오류, 5xx, 인증 오류 등 - 버퍼에 두고 재시도
+    Retryable(String),
+}
+
+impl std::fmt::Display for SendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SendError::Rejected(msg) => write!(f, "Rejected: {}", msg),
+            SendError::Retryable(msg) => write!(f, "Retryable: {}", msg),
+        }
+    }
+}
+
+pub(crate) fn http_client(ca_cert_path: Option<&Path>) -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .user_agent(concat!("flick-agent/", env!("CARGO_PKG_VERSION")));
+
+    if let Some(path) = ca_cert_path {
+        let pem = std::fs::read(path)
+            .with_context(|| format!("Failed to read CA certificate {}", path.display()))?;
+        builder = builder.add_root_certificate(reqwest::Certificate::from_pem(&pem)?);
+    }
+
+    Ok(builder.build()?)
+}
+
+pub struct AgentClient {
+    http: reqwest::Client,
+    config: AgentConfig,
+}
+
+impl AgentClient {
+    pub fn new(config: AgentConfig) -> Result<Self> {
+        Ok(Self {
+            http: http_client(config.ca_cert_path.as_deref())?,
+            config,
+        })
+    }
+
+    pub async fn send_batch(&self, metrics: &[ServerMetrics]) -> Result<IngestResult, SendError> {
+        let response = self.http
+            .post(self.config.batch_url())
+            .bearer_auth(&self.config.token)
+            .json(&MetricsBatch { metrics: metrics.to_vec() })
+            .send()
+            .await
+            .map_err(|e| SendError::Retryable(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            let body: ApiResponse<IngestResult> = response
+                .json()
+                .await
+                .map_err(|e| SendError::Retryable(format!("Invalid response body: {}", e)))?;
+            return body.data.ok_or_else(|| SendError::Retryable("Empty response body".to_string()));
+        }
+
+        let message = format!("{}: {}", status, response.text().await.unwrap_or_default());
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN | StatusCode::NOT_FOUND
+            | StatusCode::TOO_MANY_REQUESTS | StatusCode::REQUEST_TIMEOUT => Err(SendError::Retryable(message)),
+            s if s.is_client_error() => Err(SendError::Rejected(message)),
+            _ => Err(SendError::Retryable(message)),
+        }
+    }
+
+    pub async fn send_facts(&self, facts: &HostFacts) -> Result<FactsIngestResult> {
+        let response = self.http
+            .post(self.config.facts_url())
+            .bearer_auth(&self.config.token)
+            .json(facts)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!("{}: {}", status, response.text().await.unwrap_or_default()));
+        }
+        let body: ApiResponse<FactsIngestResult> = response.json().await?;
+        body.data.ok_or_else(|| anyhow!("Empty response body"))
+    }
+}
+
+// 지수 백오프 (1초부터 두 배씩, 최대값 제한)
+pub struct Backoff {
+    attempt: u32,
+    max: Duration,
+    next_try: Option<Instant>,
+}
+
+impl Backoff {
+    pub fn new(max: Duration) -> Self {
+        Self { attempt: 0, max, next_try: None }
+    }
+
+    pub fn ready(&self, now: Instant) -> bool {
+        self.next_try.is_none_or(|next| now >= next)
+    }
+
+    pub fn fail(&mut self, now: Instant) -> Duration {
+        let delay = Duration::from_secs(1u64 << self.attempt.min(16)).min(self.max);
+        self.attempt += 1;
+        self.next_try = Some(now + delay);
+        delay
+    }
+
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+        self.next_try = None;
+    }
+}
+
+pub struct Agent {
+    config: AgentConfig,
+    client: AgentClient,
+    source: LocalSource,
+    buffer: MetricsBuffer,
+    backoff: Backoff,
+    facts_sent_at: Option<Instant>,
+}
+
+impl Agent {
+    pub fn new(config: AgentConfig) -> Result<Self> {
+        let buffer = match &config.buffer_path {
+            Some(path) => MetricsBuffer::with_spool(config.buffer_capacity, path.clone())?,
+            None => MetricsBuffer::new(config.buffer_capacity),
+        };
+        if !buffer.is_empty() {
+            info!("Loaded {} buffered samples", buffer.len());
+        }
+
+        Ok(Self {
+            client: AgentClient::new(config.clone())?,
+            backoff: Backoff::new(Duration::from_secs(config.max_backoff_seconds)),
+            source: LocalSource::new(),
+            buffer,
+            facts_sent_at: None,
+            config,
+        })
+    }
+
+    // 전송 없이 수집한 payload 만 출력
+    pub async fn dry_run(config: &AgentConfig) -> Result<String> {
+        let source = LocalSource::new();
+        // sysinfo CPU 사용률은 두 번째 갱신부터 의미가 있음
+        source.collect().await?;
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        let metrics = source.collect().await?;
+
+        let payload = MetricsBatch { metrics: vec![metrics] };
+        Ok(format!(
+            "POST {}\n{}",
+            config.batch_url(),
+            serde_json::to_string_pretty(&payload)?
+        ))
+    }
+
+    pub async fn run(mut self) -> Result<()> {
+        info!(
+            "Agent started for server {} (interval {}s) -> {}",
+            self.config.server_id, self.config.interval_seconds, self.config.server_url
+        );
+        let mut interval = tokio::time::interval(Duration::from_secs(self.config.interval_seconds));
+
+        loop {
+            interval.tick().await;
+
+            match self.source.collect().await {
+                Ok(metrics) => {
+                    let dropped = self.buffer.push(metrics);
+                    if dropped > 0 {
+                        warn!("Buffer full, dropped {} oldest samples", dropped);
+                    }
+                }
+                Err(e) => error!("Metric collection failed: {:#}", e),
+            }
+
+            self.flush().await;
+            self.send_facts_if_due().await;
+
+            if let Err(e) = self.buffer.persist() {
+                warn!("Failed to persist buffer: {:#}", e);
+            }
+        }
+    }
+
+    // 시작할 때와 하루에 한 번 호스트 정보를 보낸다. 실패하면 다음 주기에 다시 시도
+    async fn send_facts_if_due(&mut self) {
+        let due = self.facts_sent_at.is_none_or(|at| at.elapsed() >= FACTS_INTERVAL);
+        // 서버에 닿지 않는 동안은 메트릭 전송과 같은 백오프를 따른다
+        if !due || !self.backoff.ready(Instant::now()) {
+            return;
+        }
+        let mut facts = match self.source.facts().await {
+            Ok(facts) => facts,
+            Err(e) => {
+                warn!("Host facts collection failed: {:#}", e);
+                return;
+            }
+        };
+        // 서버에서 닿는 주소는 서버로 나가는 인터페이스의 주소다
+        facts.ip_address = enroll::outbound_ip(&self.config.server_url);
+
+        match self.client.send_facts(&facts).await {
+            Ok(result) => {
+                if !result.changed_fields.is_empty() {
+                    info!("Host facts updated: {}", result.changed_fields.join(", "));
+                }
+                self.facts_sent_at = Some(Instant::now());
+            }
+            Err(e) => warn!("Failed to send host facts: {:#}", e),
+        }
+    }
+
+    async fn flush(&mut self) {
+        while !self.buffer.is_empty() && self.backoff.ready(Instant::now()) {
+            let batch = self.buffer.peek_batch(self.config.batch_size);
+
+            match self.client.send_batch(&batch).await {
+                Ok(result) => {
+                    debug!("Sent {} samples (accepted {}, rejected {})", batch.len(), result.accepted, result.rejected);
+                    if result.rejected > 0 {
+                        warn!("Server rejected {} samples: {:?}", result.rejected, result.errors);
+                    }
+                    self.buffer.remove_front(batch.len());
+                    self.backoff.reset();
+                }
+                Err(SendError::Rejected(msg)) => {
+                    error!("Dropping batch of {} samples: {}", batch.len(), msg);
+                    self.buffer.remove_front(batch.len());
+                }
+                Err(SendError::Retryable(msg)) => {
+                    let delay = self.backoff.fail(Instant::now());
+                    warn!(
+                        "Failed to send metrics ({}), {} samples buffered, retrying in {:?}",
+                        msg, self.buffer.len(), delay
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn next_value<'a>(name: &str, iter: &mut impl Iterator<Item = &'a String>) -> Result<String> {
+    iter.next().cloned().ok_or_else(|| anyhow!("{} requires a value", name))
+}
+
+#[derive(Debug, Default)]
+pub struct AgentArgs {
+    pub config_path: PathBuf,
+    pub dry_run: bool,
+    pub enroll_token: Option<String>,
+    pub server_url: Option<String>,
+    pub name: Option<String>,
+    pub ca_cert_path: Option<PathBuf>,
+    pub allow_http: bool,
+}
+
+pub fn parse_args(args: &[String]) -> Result<AgentArgs> {
+    let mut parsed = AgentArgs::default();
+    let mut config_path = None;
+    let mut iter = args.iter().skip(1);
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--config" | "-c" => config_path = Some(next_value(arg, &mut iter)?.into()),
+            "--dry-run" => parsed.dry_run = true,
+            "--enroll" => parsed.enroll_token = Some(next_value(arg, &mut iter)?),
+            "--server-url" => parsed.server_url = Some(next_value(arg, &mut iter)?),
+            "--name" => parsed.name = Some(next_value(arg, &mut iter)?),
+            "--ca-cert" => parsed.ca_cert_path = Some(next_value(arg, &mut iter)?.into()),
+            "--allow-http" => parsed.allow_http = true,
+            other => return Err(anyhow!("Unknown argument: {}", other)),
+        }
+    }
+
+    if parsed.enroll_token.is_some() && parsed.server_url.is_none() {
+        return Err(anyhow!("--enroll requires --server-url"));
+    }
+
+    parsed.config_path = config_path.unwrap_or_else(|| "/etc/flick/agent.toml".into());
+    Ok(parsed)
+}
@@ -0,0 +1,234 @@
+// src/api/agents.rs
+use actix_web::{web, HttpRequest, HttpResponse};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sqlx::types::JsonValue;
+use tracing::{info, warn};
+use uuid::Uuid;
+use crate::agent::config::AgentConfig;
+use crate::api::response::ApiResponse;
+use crate::auth::ingest::{generate_token, split_token};
+use crate::auth::types::AuthenticatedUser;
+use crate::auth::utils::verify_password;
+use crate::db::models::{AuditLog, CollectionMethod, EnrollmentToken, IngestToken, Server, UserRole};
+use crate::db::repository::Repository;
+use crate::error::AppError;
+use crate::models::agent::{EnrollRequest, EnrollResponse};
+
+pub const DEFAULT_ENROLLMENT_TTL_MINUTES: i64 = 60;
+pub const MAX_ENROLLMENT_TTL_MINUTES: i64 = 7 * 24 * 60;
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct CreateEnrollmentTokenRequest {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub expires_in_minutes: Option<i64>,
+}
+
+// 발급 시에만 원문 토큰을 돌려준다
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IssuedEnrollmentToken {
+    pub id: String,
+    pub token: String,
+    pub description: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+// 감사 로그 기록 실패는 요청 처리에 영향을 주지 않는다
+async fn audit(
+    repo: &Repository,
+    req: &HttpRequest,
+    user_id: Option<String>,
+    action: &str,
+    entity_type: &str,
+    entity_id: &str,
+    changes: JsonValue,
+) {
+    let log = AuditLog {
+        id: 0,
+        user_id,
+        action: action.to_string(),
+        entity_type: entity_type.to_string(),
+        entity_id: entity_id.to_string(),
+        changes,
+        ip_address: req.connection_info().realip_remote_addr().map(|ip| ip.to_string()),
+        user_agent: req
+            .headers()
+            .get("User-Agent")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string()),
+        created_at: Utc::now(),
+    };
+
+    if let Err(e) = repo.create_audit_log(log).await {
+        warn!("Failed to write audit log for {}: {}", action, e);
+    }
+}
+
+pub async fn create_enrollment_token(
+    req: HttpRequest,
+    repo: web::Data<Repository>,
+    request: web::Json<CreateEnrollmentTokenRequest>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    if user.role != UserRole::Admin {
+        return Err(AppError::Forbidden("Only admins can create enrollment tokens".into()));
+    }
+
+    let request = request.into_inner();
+    let ttl = request.expires_in_minutes.unwrap_or(DEFAULT_ENROLLMENT_TTL_MINUTES);
+    if ttl <= 0 || ttl > MAX_ENROLLMENT_TTL_MINUTES {
+        return Err(AppError::ValidationError(format!(
+            "expires_in_minutes must be between 1 and {}",
+            MAX_ENROLLMENT_TTL_MINUTES
+        )));
+    }
+
+    let generated = generate_token()?;
+    let now = Utc::now();
+    let stored = repo.create_enrollment_token(EnrollmentToken {
+        id: generated.id,
+        token_hash: generated.secret_hash,
+        description: request.description,
+        created_by: Some(user.id.clone()),
+        created_at: now,
+        expires_at: now + Duration::minutes(ttl),
+        used_at: None,
+        server_id: None,
+    }).await?;
+
+    audit(
+        &repo, &req, Some(user.id), "enrollment_token_created", "enrollment_token", &stored.id,
+        json!({ "description": stored.description, "expires_at": stored.expires_at }),
+    ).await;
+
+    Ok(ApiResponse::success(IssuedEnrollmentToken {
+        id: stored.id,
+        token: generated.token,
+        description: stored.description,
+        expires_at: stored.expires_at,
+    }))
+}
+
+// 등록 토큰 검증 - 실패 사유는 감사 로그에만 남기고 응답은 동일하게 한다
+async fn verify_enrollment_token(
+    repo: &Repository,
+    token: &str,
+) -> Result<EnrollmentToken, (Option<String>, &'static str)> {
+    let (id, secret) = split_token(token).ok_or((None, "malformed token"))?;
+    let token_id = Some(id.to_string());
+
+    let stored = match repo.get_enrollment_token(id).await {
+        Ok(Some(stored)) => stored,
+        Ok(None) => return Err((token_id, "unknown token")),
+        Err(_) => return Err((token_id, "token lookup failed")),
+    };
+
+    if !verify_password(secret, &stored.token_hash).unwrap_or(false) {
+        return Err((token_id, "invalid secret"));
+    }
+    if stored.used_at.is_some() {
+        return Err((token_id, "token already used"));
+    }
+    if stored.expires_at <= Utc::now() {
+        return Err((token_id, "token expired"));
+    }
+    Ok(stored)
+}
+
+async fn audit_rejection(
+    repo: &Repository,
+    req: &HttpRequest,
+    token: &EnrollmentToken,
+    hostname: &str,
+    reason: &str,
+) {
+    audit(
+        repo, req, token.created_by.clone(), "agent_enroll_rejected", "enrollment_token", &token.id,
+        json!({ "reason": reason, "hostname": hostname }),
+    ).await;
+}
+
+pub async fn enroll_agent(
+    req: HttpRequest,
+    repo: web::Data<Repository>,
+    request: web::Json<EnrollRequest>,
+) -> Result<HttpResponse, AppError> {
+    let request = request.into_inner();
+    let hostname = request.facts.hostname.trim().to_lowercase();
+
+    let token = match verify_enrollment_token(&repo, &request.token).await {
+        Ok(token) => token,
+        Err((token_id, reason)) => {
+            audit(
+                &repo, &req, None, "agent_enroll_rejected", "enrollment_token",
+                token_id.as_deref().unwrap_or("unknown"),
+                json!({ "reason": reason, "hostname": hostname }),
+            ).await;
+            return Err(AppError::AuthError("Invalid or expired enrollment token".into()));
+        }
+    };
+
+    if hostname.is_empty() {
+        audit_rejection(&repo, &req, &token, &hostname, "missing hostname").await;
+        return Err(AppError::ValidationError("Host facts must include a hostname".into()));
+    }
+    if repo.get_server_by_hostname(&hostname).await?.is_some() {
+        audit_rejection(&repo, &req, &token, &hostname, "hostname already registered").await;
+        return Err(AppError::ValidationError(format!(
+            "Server with hostname {} already exists",
+            hostname
+        )));
+    }
+
+    let facts = request.facts;
+    let server = repo.create_server(Server {
+        id: Uuid::new_v4().to_string(),
+        name: request.name.filter(|n| !n.trim().is_empty()).unwrap_or_else(|| hostname.clone()),
+        hostname: hostname.clone(),
+        ip_address: facts.primary_ip(),
+        server_type: facts.server_type(),
+        collection_method: CollectionMethod::Agent,
+        metadata: Some(json!({ "facts": facts })),
+        created_by: token.created_by.clone(),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        ..Default::default()
+    }).await?;
+
+    // 동시에 같은 토큰으로 등록하는 경우 한 쪽만 성공하고 나머지는 되돌린다
+    if !repo.consume_enrollment_token(&token.id, &server.id).await? {
+        repo.delete_server(&server.id).await?;
+        audit_rejection(&repo, &req, &token, &hostname, "token already used").await;
+        return Err(AppError::AuthError("Invalid or expired enrollment token".into()));
+    }
+
+    let generated = generate_token()?;
+    repo.create_ingest_token(IngestToken {
+        id: generated.id,
+        server_id: server.id.clone(),
+        token_hash: generated.secret_hash,
+        description: Some(format!("Enrolled via token {}", token.id)),
+        created_by: token.created_by.clone(),
+        created_at: Utc::now(),
+        last_used_at: None,
+        revoked_at: None,
+    }).await?;
+
+    audit(
+        &repo, &req, token.created_by.clone(), "agent_enrolled", "server", &server.id,
+        json!({ "enrollment_token_id": token.id, "hostname": server.hostname, "facts": facts }),
+    ).await;
+    info!("Agent enrolled: {} ({})", server.hostname, server.id);
+
+    let server_url = {
+        let info = req.connection_info();
+        format!("{}://{}", info.scheme(), info.host())
+    };
+    Ok(ApiResponse::success(EnrollResponse {
+        config: AgentConfig::new(&server_url, &server.id, &generated.token),
+        server_id: server.id,
+    }))
+}
@@ -0,0 +1,217 @@
+// src/api/checks.rs
+use actix_web::{web, HttpResponse};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::api::response::ApiResponse;
+use crate::api::servers::MetricsQueryParams;
+use crate::auth::types::{find_owned_server, AuthenticatedUser};
+use crate::checks::CheckService;
+use crate::db::models::{
+    CheckAvailability, CheckCertificate, CheckResult, CollectionMethod, CustomMetricSeries, LatestCheckResult,
+    ServerCheck, UserRole,
+};
+use crate::db::repository::Repository;
+use crate::error::AppError;
+use crate::models::check::CheckConfig;
+
+pub const MIN_CHECK_INTERVAL_SECONDS: i32 = 10;
+pub const MAX_CHECK_INTERVAL_SECONDS: i32 = 86400;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateCheckRequest {
+    pub name: String,
+    pub config: CheckConfig,            // {"type": "http" | "tcp" | "dns" | "tls" | "command", ...}
+    #[serde(default = "default_interval_seconds")]
+    pub interval_seconds: i32,
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: i32,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_interval_seconds() -> i32 { 60 }
+fn default_failure_threshold() -> i32 { 3 }
+fn default_enabled() -> bool { true }
+
+impl CreateCheckRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() || self.name.len() > 255 {
+            return Err("name must be 1-255 characters".into());
+        }
+        if !(MIN_CHECK_INTERVAL_SECONDS..=MAX_CHECK_INTERVAL_SECONDS).contains(&self.interval_seconds) {
+            return Err(format!(
+                "interval_seconds must be {}-{}",
+                MIN_CHECK_INTERVAL_SECONDS, MAX_CHECK_INTERVAL_SECONDS
+            ));
+        }
+        if !(1..=100).contains(&self.failure_threshold) {
+            return Err("failure_threshold must be 1-100".into());
+        }
+        self.config.validate()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckState {
+    Up,
+    Down,
+    Pending,    // 아직 결과 없음
+}
+
+// 검사 설정과 가장 최근 결과
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckStatus {
+    #[serde(flatten)]
+    pub check: ServerCheck,
+    pub state: CheckState,
+    pub last_checked_at: Option<DateTime<Utc>>,
+    pub last_response_time_ms: Option<f64>,
+    pub last_message: Option<String>,
+}
+
+impl CheckStatus {
+    pub fn new(check: ServerCheck, latest: Option<&LatestCheckResult>) -> Self {
+        let state = match latest {
+            Some(result) if result.success => CheckState::Up,
+            Some(_) => CheckState::Down,
+            None => CheckState::Pending,
+        };
+        Self {
+            check,
+            state,
+            last_checked_at: latest.map(|result| result.timestamp),
+            last_response_time_ms: latest.and_then(|result| result.response_time_ms),
+            last_message: latest.and_then(|result| result.message.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckResultsResponse {
+    pub check: ServerCheck,
+    pub availability: CheckAvailability,
+    pub results: Vec<CheckResult>,
+    pub certificate: Option<CheckCertificate>,  // TLS 검사만
+}
+
+pub async fn create_check(
+    repo: web::Data<Repository>,
+    checks: web::Data<CheckService>,
+    server_id: web::Path<String>,
+    request: web::Json<CreateCheckRequest>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let request = request.into_inner();
+    request.validate().map_err(AppError::ValidationError)?;
+    let server = find_owned_server(&repo, &server_id, &user).await?;
+    // 원격 명령은 관리자만, SSH 로 수집하는 서버에만 붙일 수 있다
+    if let CheckConfig::Command(_) = request.config {
+        if user.role != UserRole::Admin {
+            return Err(AppError::Forbidden("Only admins can add command checks".into()));
+        }
+        if server.collection_method != CollectionMethod::Ssh {
+            return Err(AppError::ValidationError("Command checks require an SSH server".into()));
+        }
+    }
+    let config = request.config.with_default_host(&server.hostname);
+
+    let check = repo.create_check(ServerCheck {
+        id: Uuid::new_v4().to_string(),
+        server_id: server.id,
+        name: request.name.trim().to_string(),
+        check_type: config.kind().to_string(),
+        config: serde_json::to_value(&config).map_err(|e| AppError::InternalError(e.to_string()))?,
+        interval_seconds: request.interval_seconds,
+        failure_threshold: request.failure_threshold,
+        enabled: request.enabled,
+        created_by: Some(user.id),
+        created_at: Utc::now(),
+    }).await?;
+
+    checks.schedule(check.clone()).await;
+    Ok(ApiResponse::success(check))
+}
+
+// 검사 목록과 각 검사의 up/down 상태
+pub async fn list_checks(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    find_owned_server(&repo, &server_id, &user).await?;
+    let latest = repo.list_latest_check_results(&server_id).await?;
+    let checks: Vec<CheckStatus> = repo
+        .list_checks(&server_id)
+        .await?
+        .into_iter()
+        .map(|check| {
+            let result = latest.iter().find(|result| result.check_id == check.id);
+            CheckStatus::new(check, result)
+        })
+        .collect();
+    Ok(ApiResponse::success(checks))
+}
+
+pub async fn delete_check(
+    repo: web::Data<Repository>,
+    checks: web::Data<CheckService>,
+    path: web::Path<(String, String)>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let (server_id, check_id) = path.into_inner();
+    find_owned_server(&repo, &server_id, &user).await?;
+
+    if !repo.delete_check(&server_id, &check_id).await? {
+        return Ok(ApiResponse::<()>::not_found("Check not found"));
+    }
+    checks.unschedule(&check_id).await;
+    Ok(ApiResponse::success(()))
+}
+
+// 기간 내 검사 결과와 가용성
+pub async fn get_check_results(
+    repo: web::Data<Repository>,
+    path: web::Path<(String, String)>,
+    query: web::Query<MetricsQueryParams>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let (server_id, check_id) = path.into_inner();
+    if query.from >= query.to {
+        return Err(AppError::ValidationError("from must be earlier than to".into()));
+    }
+    find_owned_server(&repo, &server_id, &user).await?;
+
+    let check = repo
+        .get_check(&server_id, &check_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Check {} not found", check_id)))?;
+    let availability = repo.get_check_availability(&check.id, query.from, query.to).await?;
+    let results = repo.get_check_results(&check.id, query.from, query.to).await?;
+    let certificate = repo.get_check_certificate(&check.id).await?;
+
+    Ok(ApiResponse::success(CheckResultsResponse { check, availability, results, certificate }))
+}
+
+// 명령 검사의 성능 데이터를 라벨별 시계열로
+pub async fn get_check_metrics(
+    repo: web::Data<Repository>,
+    path: web::Path<(String, String)>,
+    query: web::Query<MetricsQueryParams>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let (server_id, check_id) = path.into_inner();
+    if query.from >= query.to {
+        return Err(AppError::ValidationError("from must be earlier than to".into()));
+    }
+    find_owned_server(&repo, &server_id, &user).await?;
+
+    let check = repo
+        .get_check(&server_id, &check_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Check {} not found", check_id)))?;
+    let metrics = repo.get_custom_metrics(&check.id, query.from, query.to).await?;
+
+    Ok(ApiResponse::success(CustomMetricSeries::group(metrics)))
+}
@@ -0,0 +1,37 @@
+// src/api/containers.rs
+use actix_web::{web, HttpResponse};
+use crate::api::response::ApiResponse;
+use crate::api::servers::MetricsQueryParams;
+use crate::db::repository::Repository;
+use crate::error::AppError;
+
+// 서버에서 발견된 컨테이너와 가장 최근 메트릭
+pub async fn list_server_containers(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    let server_id = server_id.into_inner();
+    if repo.get_server(&server_id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Server {} not found", server_id)));
+    }
+
+    let containers = repo.list_containers(&server_id).await?;
+    Ok(ApiResponse::success(containers))
+}
+
+// 컨테이너 하나의 기간별 메트릭
+pub async fn get_container_metrics(
+    repo: web::Data<Repository>,
+    path: web::Path<(String, String)>,
+    query: web::Query<MetricsQueryParams>,
+) -> Result<HttpResponse, AppError> {
+    let (server_id, container_id) = path.into_inner();
+    if query.from >= query.to {
+        return Err(AppError::ValidationError("from must be earlier than to".into()));
+    }
+
+    let metrics = repo
+        .get_container_metrics(&server_id, &container_id, query.from, query.to)
+        .await?;
+    Ok(ApiResponse::success(metrics))
+}
@@ -0,0 +1,220 @@
+// src/api/ingest.rs
+use actix_web::{web, HttpRequest, HttpResponse};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::api::response::ApiResponse;
+use crate::auth::ingest::{generate_token, verify_ingest_request};
+use crate::auth::types::{find_owned_server, AuthenticatedUser};
+use crate::db::models::{IngestToken, Server};
+use crate::db::repository::Repository;
+use crate::error::AppError;
+use crate::models::agent::HostFacts;
+use crate::models::metrics::{IngestResult, MetricsBatch, ServerMetrics, MAX_BATCH_SIZE};
+use crate::monitoring::MonitoringService;
+
+pub const MAX_FUTURE_SKEW_SECONDS: i64 = 300;
+// 에이전트 배치는 기본 JSON 제한(4KB)보다 크다
+pub const INGEST_JSON_LIMIT: usize = 4 * 1024 * 1024;
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct CreateIngestTokenRequest {
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+// 발급 시에만 원문 토큰을 돌려준다
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IssuedIngestToken {
+    pub id: String,
+    pub server_id: String,
+    pub token: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+// 샘플 검증: 미래 시각, 마지막 수신 이후가 아닌 샘플, 범위를 벗어난 사용률은 거부
+pub fn validate_sample(
+    metrics: &ServerMetrics,
+    last_accepted: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Result<(), String> {
+    if metrics.timestamp > now + Duration::seconds(MAX_FUTURE_SKEW_SECONDS) {
+        return Err(format!("timestamp {} is too far in the future", metrics.timestamp));
+    }
+    if let Some(last) = last_accepted {
+        if metrics.timestamp <= last {
+            return Err(format!(
+                "timestamp {} is not after last accepted sample {}",
+                metrics.timestamp, last
+            ));
+        }
+    }
+
+    let percentages = [
+        ("cpu_usage", metrics.cpu_usage),
+        ("memory_usage", metrics.memory_usage),
+        ("disk_usage", metrics.disk_usage),
+    ];
+    for (name, value) in percentages {
+        if !(0.0..=100.0).contains(&value) {
+            return Err(format!("{} out of range: {}", name, value));
+        }
+    }
+    if let Some(value) = metrics.cpu_per_core.iter().find(|v| !(0.0..=100.0).contains(*v)) {
+        return Err(format!("cpu_per_core out of range: {}", value));
+    }
+    Ok(())
+}
+
+async fn ingest_samples(
+    repo: &Repository,
+    monitoring: &MonitoringService,
+    server_id: &str,
+    mut samples: Vec<ServerMetrics>,
+) -> Result<IngestResult, AppError> {
+    let now = Utc::now();
+    let mut last_accepted = repo.get_latest_metrics_timestamp(server_id).await?;
+    let mut result = IngestResult::default();
+
+    // 배치 안에서는 시간순으로 처리
+    samples.sort_by_key(|m| m.timestamp);
+
+    for sample in samples {
+        if let Err(reason) = validate_sample(&sample, last_accepted, now) {
+            result.rejected += 1;
+            result.errors.push(reason);
+            continue;
+        }
+
+        let timestamp = sample.timestamp;
+        monitoring.record_metrics(server_id, sample).await?;
+        last_accepted = Some(timestamp);
+        result.accepted += 1;
+    }
+
+    if result.accepted > 0 {
+        repo.mark_server_seen(server_id, now).await?;
+    }
+    Ok(result)
+}
+
+async fn find_server(repo: &Repository, server_id: &str) -> Result<Server, AppError> {
+    repo.get_server(server_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Server {} not found", server_id)))
+}
+
+pub async fn ingest_metrics(
+    req: HttpRequest,
+    repo: web::Data<Repository>,
+    monitoring: web::Data<MonitoringService>,
+    server_id: web::Path<String>,
+    metrics: web::Json<ServerMetrics>,
+) -> Result<HttpResponse, AppError> {
+    verify_ingest_request(&req, &repo, &server_id).await?;
+    find_server(&repo, &server_id).await?;
+
+    let result = ingest_samples(&repo, &monitoring, &server_id, vec![metrics.into_inner()]).await?;
+    Ok(ApiResponse::success(result))
+}
+
+pub async fn ingest_metrics_batch(
+    req: HttpRequest,
+    repo: web::Data<Repository>,
+    monitoring: web::Data<MonitoringService>,
+    server_id: web::Path<String>,
+    batch: web::Json<MetricsBatch>,
+) -> Result<HttpResponse, AppError> {
+    verify_ingest_request(&req, &repo, &server_id).await?;
+    find_server(&repo, &server_id).await?;
+
+    let batch = batch.into_inner();
+    if batch.metrics.len() > MAX_BATCH_SIZE {
+        return Err(AppError::ValidationError(format!(
+            "Batch too large: {} samples (max {})",
+            batch.metrics.len(),
+            MAX_BATCH_SIZE
+        )));
+    }
+
+    let result = ingest_samples(&repo, &monitoring, &server_id, batch.metrics).await?;
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FactsIngestResult {
+    pub changed_fields: Vec<String>,
+}
+
+// 에이전트가 시작할 때와 하루에 한 번 보내는 호스트 정보
+pub async fn ingest_host_facts(
+    req: HttpRequest,
+    monitoring: web::Data<MonitoringService>,
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    facts: web::Json<HostFacts>,
+) -> Result<HttpResponse, AppError> {
+    verify_ingest_request(&req, &repo, &server_id).await?;
+    find_server(&repo, &server_id).await?;
+
+    let facts = facts.into_inner();
+    if facts.hostname.trim().is_empty() {
+        return Err(AppError::ValidationError("Host facts must include a hostname".into()));
+    }
+
+    let changed_fields = monitoring.record_facts(&server_id, facts).await?;
+    Ok(ApiResponse::success(FactsIngestResult { changed_fields }))
+}
+
+pub async fn create_ingest_token(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    request: web::Json<CreateIngestTokenRequest>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let server = find_owned_server(&repo, &server_id, &user).await?;
+    let generated = generate_token()?;
+
+    let stored = repo.create_ingest_token(IngestToken {
+        id: generated.id,
+        server_id: server.id,
+        token_hash: generated.secret_hash,
+        description: request.into_inner().description,
+        created_by: Some(user.id),
+        created_at: Utc::now(),
+        last_used_at: None,
+        revoked_at: None,
+    }).await?;
+
+    Ok(ApiResponse::success(IssuedIngestToken {
+        id: stored.id,
+        server_id: stored.server_id,
+        token: generated.token,
+        description: stored.description,
+        created_at: stored.created_at,
+    }))
+}
+
+pub async fn list_ingest_tokens(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    find_owned_server(&repo, &server_id, &user).await?;
+    let tokens = repo.list_ingest_tokens(&server_id).await?;
+    Ok(ApiResponse::success(tokens))
+}
+
+pub async fn revoke_ingest_token(
+    repo: web::Data<Repository>,
+    path: web::Path<(String, String)>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let (server_id, token_id) = path.into_inner();
+    find_owned_server(&repo, &server_id, &user).await?;
+
+    if !repo.revoke_ingest_token(&server_id, &token_id).await? {
+        return Ok(ApiResponse::<()>::not_found("Ingest token not found"));
+    }
+    Ok(ApiResponse::success(()))
+}
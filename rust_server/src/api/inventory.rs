@@ -0,0 +1,72 @@
+// src/api/inventory.rs
+use actix_web::{web, HttpResponse};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::api::response::ApiResponse;
+use crate::db::models::ServerType;
+use crate::db::repository::Repository;
+use crate::error::AppError;
+use crate::models::agent::HostFacts;
+
+pub const DEFAULT_HISTORY_LIMIT: i64 = 20;
+pub const MAX_HISTORY_LIMIT: i64 = 200;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServerInventory {
+    pub server_id: String,
+    pub ip_address: Option<String>,
+    pub server_type: ServerType,
+    pub collected_at: Option<DateTime<Utc>>,
+    pub facts: Option<HostFacts>,    // 아직 수집 전이면 None
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct InventoryHistoryQuery {
+    pub limit: Option<i64>,
+}
+
+impl InventoryHistoryQuery {
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_HISTORY_LIMIT).clamp(1, MAX_HISTORY_LIMIT)
+    }
+}
+
+// 가장 최근에 수집한 호스트 정보
+pub async fn get_server_inventory(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    let server_id = server_id.into_inner();
+    let server = repo
+        .get_server(&server_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Server {} not found", server_id)))?;
+
+    let metadata = server.metadata.unwrap_or_default();
+    Ok(ApiResponse::success(ServerInventory {
+        server_id: server.id,
+        ip_address: server.ip_address,
+        server_type: server.server_type,
+        collected_at: metadata
+            .get("facts_collected_at")
+            .and_then(|at| serde_json::from_value(at.clone()).ok()),
+        facts: metadata
+            .get("facts")
+            .and_then(|facts| serde_json::from_value(facts.clone()).ok()),
+    }))
+}
+
+// 호스트 정보가 달라진 이력 (최신순)
+pub async fn get_inventory_history(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    query: web::Query<InventoryHistoryQuery>,
+) -> Result<HttpResponse, AppError> {
+    let server_id = server_id.into_inner();
+    if repo.get_server(&server_id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Server {} not found", server_id)));
+    }
+
+    let history = repo.get_inventory_history(&server_id, query.limit()).await?;
+    Ok(ApiResponse::success(history))
+}
@@ -0,0 +1,113 @@
+// src/api/log_sources.rs
+use actix_web::{web, HttpResponse};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::api::response::ApiResponse;
+use crate::auth::types::{find_owned_server, AuthenticatedUser};
+use crate::db::models::{CollectionMethod, LogSource};
+use crate::db::repository::Repository;
+use crate::error::AppError;
+use crate::logs::LogTailService;
+use crate::models::log_source::{file_name, validate_log_path, LevelParser, LevelRule};
+use crate::models::logs::LogLevel;
+
+pub const MAX_LEVEL_RULES: usize = 50;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateLogSourceRequest {
+    pub path: String,
+    pub component: Option<String>,      // 기본값은 파일 이름
+    #[serde(default)]
+    pub level_rules: Vec<LevelRule>,    // 비어 있으면 기본 키워드 규칙
+    #[serde(default = "default_level")]
+    pub default_level: LogLevel,        // 어느 규칙에도 맞지 않는 줄
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_level() -> LogLevel { LogLevel::Info }
+fn default_enabled() -> bool { true }
+
+impl CreateLogSourceRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_log_path(&self.path)?;
+        if let Some(component) = &self.component {
+            if component.trim().is_empty() || component.len() > 255 {
+                return Err("component must be 1-255 characters".into());
+            }
+        }
+        if self.level_rules.len() > MAX_LEVEL_RULES {
+            return Err(format!("At most {} level rules are allowed", MAX_LEVEL_RULES));
+        }
+        LevelParser::new(&self.level_rules, self.default_level.clone()).map(|_| ())
+    }
+
+    pub fn component(&self) -> String {
+        match &self.component {
+            Some(component) => component.trim().to_string(),
+            None => file_name(&self.path).to_string(),
+        }
+    }
+}
+
+pub async fn create_log_source(
+    repo: web::Data<Repository>,
+    tails: web::Data<LogTailService>,
+    server_id: web::Path<String>,
+    request: web::Json<CreateLogSourceRequest>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let request = request.into_inner();
+    request.validate().map_err(AppError::ValidationError)?;
+    let server = find_owned_server(&repo, &server_id, &user).await?;
+    if server.collection_method != CollectionMethod::Ssh {
+        return Err(AppError::ValidationError("Log tailing requires an SSH server".into()));
+    }
+    if repo.list_log_sources(&server.id).await?.iter().any(|source| source.path == request.path) {
+        return Err(AppError::ValidationError(format!("{} is already being tailed", request.path)));
+    }
+
+    let source = repo.create_log_source(LogSource {
+        id: Uuid::new_v4().to_string(),
+        server_id: server.id,
+        component: request.component(),
+        level_rules: serde_json::to_value(&request.level_rules).map_err(|e| AppError::InternalError(e.to_string()))?,
+        path: request.path,
+        default_level: request.default_level,
+        enabled: request.enabled,
+        inode: None,
+        position: 0,
+        created_by: Some(user.id),
+        created_at: Utc::now(),
+    }).await?;
+
+    tails.schedule(source.clone()).await;
+    Ok(ApiResponse::success(source))
+}
+
+pub async fn list_log_sources(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    find_owned_server(&repo, &server_id, &user).await?;
+    let sources = repo.list_log_sources(&server_id).await?;
+    Ok(ApiResponse::success(sources))
+}
+
+pub async fn delete_log_source(
+    repo: web::Data<Repository>,
+    tails: web::Data<LogTailService>,
+    path: web::Path<(String, String)>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let (server_id, source_id) = path.into_inner();
+    find_owned_server(&repo, &server_id, &user).await?;
+
+    if !repo.delete_log_source(&server_id, &source_id).await? {
+        return Ok(ApiResponse::<()>::not_found("Log source not found"));
+    }
+    tails.unschedule(&source_id).await;
+    Ok(ApiResponse::success(()))
+}
@@ -1,10 +1,20 @@
 // src/api/mod.rs
+pub mod agents;
 pub mod alerts;
+pub mod checks;
+pub mod containers;
 pub mod handlers;
 pub mod health;
+pub mod ingest;
+pub mod inventory;
+pub mod log_sources;
+pub mod packages;
+pub mod ports;
+pub mod processes;
 pub mod response;
 pub mod routes;
 pub mod servers;
+pub mod services;
 pub mod logs;
 
 pub use routes::configure_routes;
\ No newline at end of file
@@ -0,0 +1,114 @@
+// src/api/packages.rs
+use std::cmp::Ordering;
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
+use crate::api::response::ApiResponse;
+use crate::auth::types::AuthenticatedUser;
+use crate::db::models::{PackageInstallation, ServerPackageStatus, UserRole};
+use crate::db::repository::Repository;
+use crate::error::AppError;
+use crate::models::package::{compare_versions, InstalledPackage};
+
+#[derive(Debug, Deserialize, Default)]
+pub struct ServerPackagesQuery {
+    pub name: Option<String>,     // 이름에 포함된 패키지만
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PackageUpdatesQuery {
+    #[serde(default)]
+    pub security: bool,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PackageServersQuery {
+    pub below: Option<String>,    // 이 버전보다 낮은 서버만
+}
+
+impl PackageServersQuery {
+    pub fn matches(&self, version: &str) -> bool {
+        self.below
+            .as_deref()
+            .is_none_or(|below| compare_versions(version, below) == Ordering::Less)
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct SecurityUpdatesQuery {
+    pub min: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServerPackagesResponse {
+    pub status: Option<ServerPackageStatus>,   // 아직 조회 전이면 None
+    pub packages: Vec<InstalledPackage>,
+}
+
+// 관리자가 아니면 자신이 만든 서버만 본다
+fn owner_filter(user: &AuthenticatedUser) -> Option<&str> {
+    (user.role != UserRole::Admin).then_some(user.id.as_str())
+}
+
+// 서버에 설치된 패키지와 마지막 조회 요약
+pub async fn get_server_packages(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    query: web::Query<ServerPackagesQuery>,
+) -> Result<HttpResponse, AppError> {
+    let server_id = server_id.into_inner();
+    if repo.get_server(&server_id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Server {} not found", server_id)));
+    }
+
+    let name = query.name.as_deref().map(str::trim).filter(|name| !name.is_empty());
+    Ok(ApiResponse::success(ServerPackagesResponse {
+        status: repo.get_package_status(&server_id).await?,
+        packages: repo.get_server_packages(&server_id, name).await?,
+    }))
+}
+
+// 대기 중인 업데이트 (보안 업데이트 먼저)
+pub async fn get_server_package_updates(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    query: web::Query<PackageUpdatesQuery>,
+) -> Result<HttpResponse, AppError> {
+    let server_id = server_id.into_inner();
+    if repo.get_server(&server_id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Server {} not found", server_id)));
+    }
+
+    let updates = repo.get_package_updates(&server_id, query.security).await?;
+    Ok(ApiResponse::success(updates))
+}
+
+// 패키지가 설치된 서버 - below 를 주면 그보다 낮은 버전만 ("openssl < 3.0.2-0ubuntu1.15")
+pub async fn get_package_servers(
+    repo: web::Data<Repository>,
+    name: web::Path<String>,
+    query: web::Query<PackageServersQuery>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    if query.below.as_deref().is_some_and(|below| below.trim().is_empty()) {
+        return Err(AppError::ValidationError("below must not be empty".into()));
+    }
+
+    let installations: Vec<PackageInstallation> = repo
+        .find_package_installations(&name, owner_filter(&user))
+        .await?
+        .into_iter()
+        .filter(|installation| query.matches(&installation.version))
+        .collect();
+    Ok(ApiResponse::success(installations))
+}
+
+// 보안 업데이트가 대기 중인 서버 (기본 1개 이상)
+pub async fn get_security_update_servers(
+    repo: web::Data<Repository>,
+    query: web::Query<SecurityUpdatesQuery>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let min = query.min.unwrap_or(1).max(1);
+    let servers = repo.list_servers_with_security_updates(min, owner_filter(&user)).await?;
+    Ok(ApiResponse::success(servers))
+}
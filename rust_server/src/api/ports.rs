@@ -0,0 +1,49 @@
+// src/api/ports.rs
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use crate::api::response::ApiResponse;
+use crate::db::repository::Repository;
+use crate::error::AppError;
+
+pub const DEFAULT_PORT_CHANGES_LIMIT: i64 = 50;
+pub const MAX_PORT_CHANGES_LIMIT: i64 = 500;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PortChangesQuery {
+    pub limit: Option<i64>,
+}
+
+impl PortChangesQuery {
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_PORT_CHANGES_LIMIT).clamp(1, MAX_PORT_CHANGES_LIMIT)
+    }
+}
+
+// 마지막 수집 기준으로 대기 중인 포트와 소유 프로세스
+pub async fn get_server_ports(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    let server_id = server_id.into_inner();
+    if repo.get_server(&server_id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Server {} not found", server_id)));
+    }
+
+    let ports = repo.list_listening_ports(&server_id).await?;
+    Ok(ApiResponse::success(ports))
+}
+
+// 포트가 열리고 닫힌 이력 (최신순)
+pub async fn get_port_changes(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    query: web::Query<PortChangesQuery>,
+) -> Result<HttpResponse, AppError> {
+    let server_id = server_id.into_inner();
+    if repo.get_server(&server_id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Server {} not found", server_id)));
+    }
+
+    let changes = repo.get_port_changes(&server_id, query.limit()).await?;
+    Ok(ApiResponse::success(changes))
+}
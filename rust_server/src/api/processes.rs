@@ -0,0 +1,162 @@
+// src/api/processes.rs
+use actix_web::{web, HttpResponse};
+use chrono::Utc;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::api::response::ApiResponse;
+use crate::auth::types::{find_owned_server, AuthenticatedUser};
+use crate::db::models::{CollectionMethod, ProcessWatch};
+use crate::db::repository::Repository;
+use crate::error::AppError;
+use crate::models::process::{ProcessHistoryQuery, ProcessQuery, TopProcessQuery};
+use crate::monitoring::MonitoringService;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateProcessWatchRequest {
+    pub name: String,
+    pub pattern: String,                // 정규식
+    #[serde(default)]
+    pub match_command: bool,            // true 면 이름 대신 명령줄에 적용
+    #[serde(default = "default_min_instances")]
+    pub min_instances: i32,
+    pub max_cpu: Option<f64>,
+    pub max_memory: Option<i64>,
+}
+
+fn default_min_instances() -> i32 { 1 }
+
+impl CreateProcessWatchRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() || self.name.len() > 255 {
+            return Err("name must be 1-255 characters".into());
+        }
+        Regex::new(&self.pattern).map_err(|e| format!("Invalid pattern: {}", e))?;
+        if self.min_instances < 0 {
+            return Err("min_instances must not be negative".into());
+        }
+        if self.max_cpu.is_some_and(|cpu| cpu <= 0.0) {
+            return Err("max_cpu must be greater than 0".into());
+        }
+        if self.max_memory.is_some_and(|memory| memory <= 0) {
+            return Err("max_memory must be greater than 0".into());
+        }
+        if self.min_instances == 0 && self.max_cpu.is_none() && self.max_memory.is_none() {
+            return Err("A watch needs min_instances of at least 1 or a resource limit".into());
+        }
+        Ok(())
+    }
+}
+
+// 서버의 전체 프로세스 목록 - 요청할 때마다 수집원에서 새로 가져온다
+pub async fn list_server_processes(
+    repo: web::Data<Repository>,
+    monitoring: web::Data<MonitoringService>,
+    server_id: web::Path<String>,
+    query: web::Query<ProcessQuery>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let server_id = server_id.into_inner();
+    find_owned_server(&repo, &server_id, &user).await?;
+
+    let processes = monitoring
+        .list_processes(&server_id)
+        .await
+        .map_err(|e| AppError::ExternalService(format!("Failed to list processes: {:#}", e)))?
+        .ok_or_else(|| {
+            AppError::BadRequest("Process listing is not available for this server's collection method".into())
+        })?;
+
+    let page = query.apply(processes).map_err(AppError::ValidationError)?;
+    Ok(ApiResponse::success(page))
+}
+
+// 기간 내 CPU/메모리 상위 소비 프로세스
+pub async fn get_top_processes(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    query: web::Query<TopProcessQuery>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let server_id = server_id.into_inner();
+    let (rank_by, limit) = query.ranking().map_err(AppError::ValidationError)?;
+    find_owned_server(&repo, &server_id, &user).await?;
+
+    let processes = repo
+        .get_top_processes(&server_id, query.from, query.to, rank_by, limit)
+        .await?;
+    Ok(ApiResponse::success(processes))
+}
+
+// 단일 프로세스의 시간별 CPU/메모리 사용량
+pub async fn get_process_history(
+    repo: web::Data<Repository>,
+    path: web::Path<(String, i32)>,
+    query: web::Query<ProcessHistoryQuery>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let (server_id, pid) = path.into_inner();
+    query.validate().map_err(AppError::ValidationError)?;
+    find_owned_server(&repo, &server_id, &user).await?;
+
+    let samples = repo
+        .get_process_history(&server_id, pid, query.name.as_deref(), query.from, query.to)
+        .await?;
+    Ok(ApiResponse::success(samples))
+}
+
+pub async fn create_process_watch(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    request: web::Json<CreateProcessWatchRequest>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let request = request.into_inner();
+    request.validate().map_err(AppError::ValidationError)?;
+    let server = find_owned_server(&repo, &server_id, &user).await?;
+    // 에이전트는 전체 프로세스 목록을 보내지 않으므로 감시할 수 없다
+    if server.collection_method == CollectionMethod::Agent {
+        return Err(AppError::BadRequest(
+            "Process watches are not available for agent-collected servers".into(),
+        ));
+    }
+
+    let watch = repo.create_process_watch(ProcessWatch {
+        id: Uuid::new_v4().to_string(),
+        server_id: server.id,
+        name: request.name.trim().to_string(),
+        pattern: request.pattern,
+        match_command: request.match_command,
+        min_instances: request.min_instances,
+        max_cpu: request.max_cpu,
+        max_memory: request.max_memory,
+        created_by: Some(user.id),
+        created_at: Utc::now(),
+    }).await?;
+
+    Ok(ApiResponse::success(watch))
+}
+
+pub async fn list_process_watches(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    find_owned_server(&repo, &server_id, &user).await?;
+    let watches = repo.list_process_watches(&server_id).await?;
+    Ok(ApiResponse::success(watches))
+}
+
+pub async fn delete_process_watch(
+    repo: web::Data<Repository>,
+    path: web::Path<(String, String)>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let (server_id, watch_id) = path.into_inner();
+    find_owned_server(&repo, &server_id, &user).await?;
+
+    if !repo.delete_process_watch(&server_id, &watch_id).await? {
+        return Ok(ApiResponse::<()>::not_found("Process watch not found"));
+    }
+    Ok(ApiResponse::success(()))
+}
@@ -1,11 +1,29 @@
 // src/api/routes.rs
-use actix_web::web;
+use actix_web::{guard, web};
 use crate::auth::handlers::*;
+use crate::api::agents::{create_enrollment_token, enroll_agent};
+use crate::api::checks::{create_check, delete_check, get_check_metrics, get_check_results, list_checks};
+use crate::api::containers::{get_container_metrics, list_server_containers};
 use crate::api::health::health_check;
 use crate::api::servers::{
     create_server, delete_server, get_server, get_servers,
     update_server_status, get_server_metrics, test_connection, get_server_status,
 };
+use crate::api::ingest::{
+    ingest_metrics, ingest_metrics_batch, ingest_host_facts, create_ingest_token,
+    list_ingest_tokens, revoke_ingest_token, INGEST_JSON_LIMIT,
+};
+use crate::api::inventory::{get_inventory_history, get_server_inventory};
+use crate::api::log_sources::{create_log_source, delete_log_source, list_log_sources};
+use crate::api::packages::{
+    get_package_servers, get_security_update_servers, get_server_package_updates, get_server_packages,
+};
+use crate::api::ports::{get_port_changes, get_server_ports};
+use crate::api::processes::{
+    create_process_watch, delete_process_watch, get_process_history, get_top_processes,
+    list_process_watches, list_server_processes,
+};
+use crate::api::services::{get_server_services, update_server_services};
 use crate::api::logs::{create_log, get_logs, get_log, delete_logs};
 use crate::api::alerts::{list_alerts, acknowledge_alert};
 use crate::websocket::ws_index;
@@ -37,6 +55,63 @@ pub fn configure_routes(cfg: &mut web::ServiceConfig) {
                     .route("/{server_id}/status", web::put().to(update_server_status))
                     .route("/{server_id}", web::delete().to(delete_server))
                     .route("/{server_id}/metrics", web::get().to(get_server_metrics))
+                    .route("/{server_id}/processes", web::get().to(list_server_processes))
+                    .route("/{server_id}/processes/top", web::get().to(get_top_processes))
+                    .route("/{server_id}/processes/{pid}/history", web::get().to(get_process_history))
+                    .route("/{server_id}/process-watches", web::post().to(create_process_watch))
+                    .route("/{server_id}/process-watches", web::get().to(list_process_watches))
+                    .route("/{server_id}/process-watches/{watch_id}", web::delete().to(delete_process_watch))
+                    .route("/{server_id}/services", web::get().to(get_server_services))
+                    .route("/{server_id}/services", web::put().to(update_server_services))
+                    .route("/{server_id}/containers", web::get().to(list_server_containers))
+                    .route("/{server_id}/containers/{container_id}/metrics", web::get().to(get_container_metrics))
+                    .route("/{server_id}/inventory", web::get().to(get_server_inventory))
+                    .route("/{server_id}/inventory/history", web::get().to(get_inventory_history))
+                    .route("/{server_id}/packages", web::get().to(get_server_packages))
+                    .route("/{server_id}/packages/updates", web::get().to(get_server_package_updates))
+                    .route("/{server_id}/ports", web::get().to(get_server_ports))
+                    .route("/{server_id}/ports/changes", web::get().to(get_port_changes))
+                    .route("/{server_id}/checks", web::post().to(create_check))
+                    .route("/{server_id}/checks", web::get().to(list_checks))
+                    .route("/{server_id}/checks/{check_id}", web::delete().to(delete_check))
+                    .route("/{server_id}/checks/{check_id}/results", web::get().to(get_check_results))
+                    .route("/{server_id}/checks/{check_id}/metrics", web::get().to(get_check_metrics))
+                    .route("/{server_id}/log-sources", web::post().to(create_log_source))
+                    .route("/{server_id}/log-sources", web::get().to(list_log_sources))
+                    .route("/{server_id}/log-sources/{source_id}", web::delete().to(delete_log_source))
+                    // 에이전트 메트릭 수집 (ingest 토큰 인증)
+                    .service(
+                        web::resource("/{server_id}/metrics")
+                            .guard(guard::Post())
+                            .app_data(web::JsonConfig::default().limit(INGEST_JSON_LIMIT))
+                            .route(web::post().to(ingest_metrics))
+                    )
+                    .service(
+                        web::resource("/{server_id}/metrics/batch")
+                            .app_data(web::JsonConfig::default().limit(INGEST_JSON_LIMIT))
+                            .route(web::post().to(ingest_metrics_batch))
+                    )
+                    .service(
+                        web::resource("/{server_id}/inventory")
+                            .guard(guard::Post())
+                            .app_data(web::JsonConfig::default().limit(INGEST_JSON_LIMIT))
+                            .route(web::post().to(ingest_host_facts))
+                    )
+                    .route("/{server_id}/ingest-tokens", web::post().to(create_ingest_token))
+                    .route("/{server_id}/ingest-tokens", web::get().to(list_ingest_tokens))
+                    .route("/{server_id}/ingest-tokens/{token_id}", web::delete().to(revoke_ingest_token))
+            )
+            .service(
+                web::scope("/packages")
+                    .wrap(AuthMiddleware)
+                    .route("/security-updates", web::get().to(get_security_update_servers))
+                    .route("/{name}/servers", web::get().to(get_package_servers))
+            )
+            .service(
+                web::scope("/agents")
+                    .wrap(AuthMiddleware)  // enroll 은 등록 토큰으로 인증
+                    .route("/enrollment-tokens", web::post().to(create_enrollment_token))
+                    .route("/enroll", web::post().to(enroll_agent))
             )
             .service(
                 web::scope("/logs")
@@ -9,8 +9,9 @@ use std::time::Duration;
 use std::fmt;
 use serde_json::json;
 use tracing::{debug, info};
-use crate::db::{models::{Server, ServerType, ServerCategory}, repository::Repository};
+use crate::db::{models::{Server, ServerType, ServerCategory, CollectionMethod}, repository::Repository};
 use crate::models::logs::LogEntry;
+use crate::models::metrics::{DiskIoMetrics, MemoryMetrics, NetworkInterfaceMetrics, ServerMetrics};
 use crate::config::ServerConfig;
 use crate::utils::encryption::Encryptor;
 use crate::monitoring::MonitoringService;
@@ -31,6 +32,8 @@ pub struct CreateServerRequest {
     #[serde(rename = "type")]
     pub server_type: ServerType,
     pub category: ServerCategory,
+    #[serde(default)]
+    pub collection_method: Option<CollectionMethod>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -63,26 +66,108 @@ pub struct ServerStatusResponse {
     pub status: String,
     pub resources: ResourceUsage,
     pub uptime: String,
+    pub uptime_seconds: Option<u64>,
+    pub boot_time: Option<DateTime<Utc>>,
+    pub kernel: Option<String>,
+    pub os: Option<String>,
     pub processes: Vec<ProcessInfo>,
     pub recent_logs: Vec<LogEntry>,
+    pub hostname: String,
+    pub port: i32,
+    pub username: String,
+    #[serde(rename = "type")]
+    pub server_type: String,
+    pub category: String,
 }
 
 #[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ProcessInfo {
-    pub pid: i32,
+    pub pid: u32,
     pub name: String,
-    pub cpu_usage: f64,
-    pub memory_usage: f64,
+    pub cpu_usage: f32,
+    pub memory_usage: u64,
 }
 
 #[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ResourceUsage {
-    pub cpu: f64,
-    pub memory: f64,
-    pub disk: f64,
+    pub cpu: f32,
+    pub memory: f32,
+    pub disk: f32,
     pub network: String,
+    pub network_rx: u64,
+    pub network_tx: u64,
+    pub network_interfaces: Vec<NetworkInterfaceMetrics>,
+    pub disk_io: Vec<DiskIoMetrics>,
+    pub memory_detail: Option<MemoryMetrics>,
     pub history: Vec<ResourceHistory>,
-    pub last_updated: Option<DateTime<Utc>>,
+    pub last_updated: DateTime<Utc>,
+}
+
+pub const DEFAULT_STATUS_LOG_LIMIT: i64 = 10;
+pub const MAX_STATUS_LOG_LIMIT: i64 = 100;
+
+#[derive(serde::Deserialize, Default)]
+pub struct ServerStatusQuery {
+    pub logs: Option<i64>,      // 함께 돌려줄 최근 로그 수
+}
+
+impl ServerStatusQuery {
+    // 기본 10개, 최대 100개. 0 이면 로그를 싣지 않는다
+    pub fn log_limit(&self) -> i64 {
+        self.logs.unwrap_or(DEFAULT_STATUS_LOG_LIMIT).clamp(0, MAX_STATUS_LOG_LIMIT)
+    }
+}
+
+impl ServerStatusResponse {
+    pub fn build(
+        server: Server,
+        metrics: Option<ServerMetrics>,
+        history: Vec<ResourceHistory>,
+        recent_logs: Vec<LogEntry>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let host = metrics.as_ref().and_then(|m| m.host.clone());
+        Self {
+            id: server.id,
+            name: server.name,
+            status: if server.is_online { "online" } else { "offline" }.to_string(),
+            resources: ResourceUsage {
+                cpu: metrics.as_ref().map_or(0.0, |m| m.cpu_usage),
+                memory: metrics.as_ref().map_or(0.0, |m| m.memory_usage),
+                disk: metrics.as_ref().map_or(0.0, |m| m.disk_usage),
+                network: format!("{} B/s", metrics.as_ref().map_or(0, |m| m.total_network_usage())),
+                network_rx: metrics.as_ref().map_or(0, |m| m.network_rx),
+                network_tx: metrics.as_ref().map_or(0, |m| m.network_tx),
+                network_interfaces: metrics.as_ref().map_or_else(Vec::new, |m| m.network_interfaces.clone()),
+                disk_io: metrics.as_ref().map_or_else(Vec::new, |m| m.disk_io.clone()),
+                memory_detail: metrics.as_ref().and_then(|m| m.memory),
+                history,
+                last_updated: now,
+            },
+            uptime: host.as_ref().map_or_else(|| "unknown".to_string(), |h| h.uptime_display()),
+            uptime_seconds: host.as_ref().map(|h| h.uptime_seconds),
+            boot_time: host.as_ref().map(|h| h.boot_time),
+            kernel: host.as_ref().and_then(|h| h.kernel.clone()),
+            os: host.and_then(|h| h.os),
+            processes: metrics.as_ref().map_or_else(
+                Vec::new,
+                |m| m.processes.iter().map(|p| ProcessInfo {
+                    pid: p.pid,
+                    name: p.name.clone(),
+                    cpu_usage: p.cpu_usage,
+                    memory_usage: p.memory_usage,
+                }).collect()
+            ),
+            recent_logs,
+            hostname: server.hostname,
+            port: server.port,
+            username: server.username,
+            server_type: server.server_type.to_string(),
+            category: server.category.to_string(),
+        }
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -92,6 +177,8 @@ pub struct ResourceHistory {
     pub memory: f64,
     pub disk: f64,
     pub network: String,
+    pub disk_io: f64,            // 가장 바쁜 블록 장치의 사용률(%)
+    pub swap: f64,               // 스왑 사용률(%)
 }
 
 
@@ -170,6 +257,7 @@ pub async fn create_server(
         username: server_info.username.clone(),
         encrypted_password,
         server_type: server_info.server_type.clone(),
+        collection_method: server_info.collection_method.clone().unwrap_or_default(),
         created_by: Some(user.id),
         created_at: Utc::now(),
         updated_at: Utc::now(),
@@ -256,24 +344,21 @@ pub async fn get_server_status(
     repo: web::Data<Repository>,
     monitoring: web::Data<MonitoringService>,
     server_id: web::Path<String>,
+    query: web::Query<ServerStatusQuery>,
 ) -> Result<HttpResponse> {
-    println!("get_server_status called for server_id: {}", server_id);
+    debug!("get_server_status called for server_id: {}", server_id);
 
     let server = match repo.get_server(&server_id).await
         .map_err(|e| actix_web::error::ErrorInternalServerError(e))? {
-        Some(server) => {
-            println!("Found server: {:?}", server);
-            server
-        },
+        Some(server) => server,
         None => {
-            println!("Server not found: {}", server_id);
+            debug!("Server not found: {}", server_id);
             return Ok(HttpResponse::NotFound().finish())
         },
     };
 
     // 모니터링 서비스에서 메트릭 가져오기
     let metrics = monitoring.get_server_metrics(&server_id).await;
-    println!("Got metrics for {}: {:?}", server_id, metrics);
 
     let now = chrono::Utc::now();
     let one_hour_ago = now - chrono::Duration::hours(1);
@@ -282,41 +367,21 @@ pub async fn get_server_status(
     let history = repo.get_server_metrics_history(&server_id, one_hour_ago, now)
         .await
         .unwrap_or_default();
-    
-    let response = json!({
-        "id": server.id,
-        "name": server.name,
-        "status": if server.is_online { "online" } else { "offline" },
-        "resources": {
-            "cpu": metrics.as_ref().map_or(0.0, |m| m.cpu_usage),
-            "memory": metrics.as_ref().map_or(0.0, |m| m.memory_usage),
-            "disk": metrics.as_ref().map_or(0.0, |m| m.disk_usage),
-            "network": metrics.as_ref().map_or(
-                "0 B/s".to_string(), 
-                |m| format!("{} B/s", (m.network_rx + m.network_tx) / 2)
-            ),
-            "history": history,  // 히스토리 데이터 추가
-            "lastUpdated": now.to_rfc3339()
-        },
-        "uptime": "0s",
-        "processes": metrics.as_ref().map_or_else(
-            Vec::new,
-            |m| m.processes.iter().map(|p| json!({
-                "pid": p.pid,
-                "name": p.name,
-                "cpuUsage": p.cpu_usage,
-                "memoryUsage": p.memory_usage
-            })).collect::<Vec<_>>()
-        ),
-        "recent_logs": [],
-        "hostname": server.hostname,
-        "port": server.port,
-        "username": server.username,
-        "type": server.server_type.to_string(),
-        "category": server.category.to_string()
-    });
-
-    println!("Sending response for {}: {}", server_id, response);
+
+    let log_limit = query.log_limit();
+    let recent_logs = if log_limit > 0 {
+        repo.get_recent_server_logs(&server_id, log_limit)
+            .await
+            .unwrap_or_else(|e| {
+                error!("Failed to load recent logs for {}: {}", server_id, e);
+                Vec::new()
+            })
+    } else {
+        Vec::new()
+    };
+
+    let response = ServerStatusResponse::build(server, metrics, history, recent_logs, now);
+
     Ok(HttpResponse::Ok().json(response))
 }
 
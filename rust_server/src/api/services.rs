@@ -0,0 +1,72 @@
+// src/api/services.rs
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
+use crate::api::response::ApiResponse;
+use crate::auth::types::{find_owned_server, AuthenticatedUser};
+use crate::db::repository::Repository;
+use crate::error::AppError;
+use crate::models::service::is_valid_unit_name;
+use crate::monitoring::MonitoringService;
+
+pub const MAX_SERVICE_UNITS: usize = 100;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpdateServiceUnitsRequest {
+    pub units: Vec<String>,
+}
+
+impl UpdateServiceUnitsRequest {
+    // 이름만 준 경우 .service 를 붙이고 중복은 제거한다
+    pub fn normalized(&self) -> Result<Vec<String>, String> {
+        if self.units.len() > MAX_SERVICE_UNITS {
+            return Err(format!("At most {} units can be monitored", MAX_SERVICE_UNITS));
+        }
+        let mut units = Vec::new();
+        for unit in &self.units {
+            let unit = unit.trim();
+            if !is_valid_unit_name(unit) {
+                return Err(format!("Invalid unit name '{}'", unit));
+            }
+            let unit = if unit.contains('.') { unit.to_string() } else { format!("{}.service", unit) };
+            if !units.contains(&unit) {
+                units.push(unit);
+            }
+        }
+        Ok(units)
+    }
+}
+
+// 설정된 systemd 유닛의 현재 상태 - 요청할 때마다 수집원에서 새로 가져온다
+pub async fn get_server_services(
+    repo: web::Data<Repository>,
+    monitoring: web::Data<MonitoringService>,
+    server_id: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    let server_id = server_id.into_inner();
+    if repo.get_server(&server_id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Server {} not found", server_id)));
+    }
+
+    let services = monitoring
+        .list_services(&server_id)
+        .await
+        .map_err(|e| AppError::ExternalService(format!("Failed to read service status: {:#}", e)))?
+        .ok_or_else(|| {
+            AppError::BadRequest("Service status is only available for SSH-monitored servers".into())
+        })?;
+
+    Ok(ApiResponse::success(services))
+}
+
+pub async fn update_server_services(
+    repo: web::Data<Repository>,
+    server_id: web::Path<String>,
+    request: web::Json<UpdateServiceUnitsRequest>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let units = request.normalized().map_err(AppError::ValidationError)?;
+    let server = find_owned_server(&repo, &server_id, &user).await?;
+
+    repo.set_service_units(&server.id, &units).await?;
+    Ok(ApiResponse::success(units))
+}
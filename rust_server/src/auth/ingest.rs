@@ -0,0 +1,84 @@
+// src/auth/ingest.rs
+use actix_web::HttpRequest;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use rand::{thread_rng, RngCore};
+use uuid::Uuid;
+use crate::auth::utils::{hash_password, verify_password};
+use crate::db::repository::Repository;
+use crate::error::AppError;
+
+// 에이전트용 ingest 토큰: "<token_id>.<secret>" 형식, DB 에는 secret 의 해시만 저장
+pub struct GeneratedToken {
+    pub id: String,
+    pub token: String,
+    pub secret_hash: String,
+}
+
+pub fn generate_token() -> Result<GeneratedToken, AppError> {
+    let id = Uuid::new_v4().to_string();
+    let mut secret = [0u8; 32];
+    thread_rng().fill_bytes(&mut secret);
+    let secret = URL_SAFE_NO_PAD.encode(secret);
+
+    Ok(GeneratedToken {
+        token: format!("{}.{}", id, secret),
+        secret_hash: hash_password(&secret)?,
+        id,
+    })
+}
+
+pub fn split_token(token: &str) -> Option<(&str, &str)> {
+    let (id, secret) = token.split_once('.')?;
+    if id.is_empty() || secret.is_empty() {
+        return None;
+    }
+    Some((id, secret))
+}
+
+// 사용자 JWT 대신 ingest 토큰으로 인증하는 경로 (AuthMiddleware 에서 통과)
+pub fn is_ingest_path(method: &actix_web::http::Method, path: &str) -> bool {
+    if method != actix_web::http::Method::POST {
+        return false;
+    }
+
+    let segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+    matches!(
+        segments.as_slice(),
+        ["", "api", "v1", "servers", id, "metrics"]
+        | ["", "api", "v1", "servers", id, "metrics", "batch"]
+        | ["", "api", "v1", "servers", id, "inventory"] if !id.is_empty()
+    )
+}
+
+pub fn bearer_token(req: &HttpRequest) -> Option<&str> {
+    req.headers()
+        .get("Authorization")?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+}
+
+// 요청의 토큰이 해당 서버에 발급된 유효한 토큰인지 확인
+pub async fn verify_ingest_request(
+    req: &HttpRequest,
+    repo: &Repository,
+    server_id: &str,
+) -> Result<(), AppError> {
+    let token = bearer_token(req)
+        .ok_or_else(|| AppError::AuthError("Missing ingest token".into()))?;
+    let (token_id, secret) = split_token(token)
+        .ok_or_else(|| AppError::AuthError("Invalid ingest token format".into()))?;
+
+    let stored = repo.get_ingest_token(token_id).await?
+        .ok_or_else(|| AppError::AuthError("Invalid ingest token".into()))?;
+
+    if stored.server_id != server_id || stored.revoked_at.is_some() {
+        return Err(AppError::AuthError("Invalid ingest token".into()));
+    }
+    if !verify_password(secret, &stored.token_hash)? {
+        return Err(AppError::AuthError("Invalid ingest token".into()));
+    }
+
+    repo.touch_ingest_token(token_id).await?;
+    Ok(())
+}
@@ -9,6 +9,7 @@ use actix_web::{
 use actix_web::error::ErrorUnauthorized;
 use futures::Future;
 use crate::auth::jwt::{verify_token, Claims};
+use crate::auth::ingest::is_ingest_path;
 
 impl FromRequest for Claims {
     type Error = Error;
@@ -70,11 +71,13 @@ where
             "/api/v1/auth/refresh",      
             "/api/v1/health",
             "/api/v1/ws",
-            "/api/v1/servers/test-connection"
+            "/api/v1/servers/test-connection",
+            "/api/v1/agents/enroll"
         ];
 
         let path = req.path().trim_end_matches('/');
-        if public_paths.contains(&path) {
+        // 에이전트 메트릭 수집 경로는 핸들러에서 ingest 토큰으로 인증
+        if public_paths.contains(&path) || is_ingest_path(req.method(), path) {
             let fut = service.call(req);
             return Box::pin(async move {
                 let res = fut.await?;
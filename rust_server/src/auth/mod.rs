@@ -1,5 +1,6 @@
 // src/auth/mod.rs
 pub mod handlers;
+pub mod ingest;
 pub mod jwt;
 pub mod middleware;
 pub mod types;
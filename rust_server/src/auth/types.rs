@@ -1,6 +1,8 @@
 // src/auth/types.rs
 use serde::{Deserialize, Serialize};
-use crate::db::models::{UserRole, AuthProvider};
+use crate::db::models::{UserRole, AuthProvider, Server};
+use crate::db::repository::Repository;
+use crate::error::AppError;
 use actix_web::{FromRequest, HttpRequest, dev::Payload, Error, HttpMessage};
 use std::future::{ready, Ready};
 use actix_web::error::ErrorUnauthorized;
@@ -65,6 +67,30 @@ pub struct AuthenticatedUser {
     pub role: UserRole,
 }
 
+impl AuthenticatedUser {
+    // 관리자 또는 서버 생성자만 서버를 관리할 수 있다
+    pub fn can_manage(&self, server: &Server) -> bool {
+        self.role == UserRole::Admin || server.created_by.as_deref() == Some(self.id.as_str())
+    }
+}
+
+// 서버 단위 API 공통: 서버가 있고 요청한 사용자가 관리할 수 있는지 확인
+pub async fn find_owned_server(
+    repo: &Repository,
+    server_id: &str,
+    user: &AuthenticatedUser,
+) -> Result<Server, AppError> {
+    let server = repo.get_server(server_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Server {} not found", server_id)))?;
+    if !user.can_manage(&server) {
+        return Err(AppError::Forbidden(
+            "You don't have permission to manage this server".into(),
+        ));
+    }
+    Ok(server)
+}
+
 impl From<Claims> for AuthenticatedUser {
     fn from(claims: Claims) -> Self {
         Self {
@@ -0,0 +1,71 @@
+// src/bin/agent.rs
+use rust_server::agent::enroll::{enroll, write_config, EnrollOptions};
+use rust_server::agent::{config::AgentConfig, parse_args, Agent};
+
+const USAGE: &str = "Usage: flick-agent [--config <path>] [--dry-run]
+       flick-agent --enroll <token> --server-url <url> [--name <name>] [--ca-cert <path>] [--allow-http] [--config <path>]";
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
+        )
+        .init();
+
+    let args: Vec<String> = std::env::args().collect();
+    let args = match parse_args(&args) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    // 등록 후 설정 파일을 쓰고 종료
+    if let (Some(token), Some(server_url)) = (args.enroll_token.clone(), args.server_url.clone()) {
+        let options = EnrollOptions {
+            server_url,
+            token,
+            name: args.name.clone(),
+            ca_cert_path: args.ca_cert_path.clone(),
+            allow_http: args.allow_http,
+        };
+        let result = match enroll(&options).await {
+            Ok(config) => write_config(&config, &args.config_path).map(|_| config),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(config) => {
+                println!("Enrolled as server {}, config written to {}", config.server_id, args.config_path.display());
+                return;
+            }
+            Err(e) => {
+                eprintln!("{:#}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let config = match AgentConfig::load(&args.config_path) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{:#}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let result = if args.dry_run {
+        Agent::dry_run(&config).await.map(|payload| println!("{}", payload))
+    } else {
+        match Agent::new(config) {
+            Ok(agent) => agent.run().await,
+            Err(e) => Err(e),
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("{:#}", e);
+        std::process::exit(1);
+    }
+}
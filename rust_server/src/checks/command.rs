@@ -0,0 +1,127 @@
+// src/checks/command.rs
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::time::timeout;
+use crate::db::models::{Alert, AlertSeverity, ServerCheck};
+use crate::models::check::{CheckOutcome, CommandCheckConfig};
+use crate::models::plugin::{PluginOutput, PluginStatus};
+use crate::monitoring::ssh::SshSession;
+
+const SESSION_TIMEOUT_MARGIN_SECONDS: u64 = 5;
+
+// 플러그인을 SSH 로 실행하고 종료 코드와 출력을 해석한다.
+// 실행 자체가 안 되면 UNKNOWN, 제한 시간을 넘기면 CRITICAL
+pub async fn run(session: &Arc<SshSession>, config: &CommandCheckConfig) -> (CheckOutcome, PluginOutput) {
+    let started = Instant::now();
+    let output = match timeout(Duration::from_secs(config.timeout_seconds), session.exec_status(&config.command)).await {
+        Ok(Ok((code, output))) => PluginOutput::parse(code, &output),
+        Ok(Err(e)) => {
+            let output = PluginOutput::unknown(format!("Failed to run command: {:#}", e));
+            return (outcome(&output, None), output);
+        }
+        Err(_) => PluginOutput {
+            status: PluginStatus::Critical,
+            summary: format!("Plugin timed out after {}s", config.timeout_seconds),
+            perfdata: Vec::new(),
+        },
+    };
+    let elapsed = started.elapsed().as_secs_f64() * 1000.0;
+    (outcome(&output, Some(elapsed)), output)
+}
+
+// 검사에 쓸 SSH 세션의 읽기 제한 시간. 검사 제한 시간보다 먼저 끝나면 시간 초과(CRITICAL)가
+// SSH 오류(UNKNOWN)로 보고되므로 여유를 둔다
+pub fn session_timeout(config: &CommandCheckConfig) -> Duration {
+    Duration::from_secs(config.timeout_seconds + SESSION_TIMEOUT_MARGIN_SECONDS)
+}
+
+// OK 만 성공. status_code 에는 플러그인 상태 코드(0~3)를 남긴다
+pub fn outcome(output: &PluginOutput, response_time_ms: Option<f64>) -> CheckOutcome {
+    match (output.status, response_time_ms) {
+        (PluginStatus::Ok, Some(elapsed)) => CheckOutcome::up(elapsed, Some(output.status.code())),
+        (status, elapsed) => CheckOutcome::down(
+            elapsed,
+            Some(status.code()),
+            format!("{}: {}", status.as_str(), output.summary),
+        ),
+    }
+}
+
+// 검사별 확정(hard) 상태. OK 에서 벗어날 때는 failure_threshold 번 연속 같은 상태여야 확정하고,
+// 그 밖의 상태 변화(복구 포함)는 바로 확정한다. 확정 상태가 바뀔 때만 알린다
+#[derive(Default)]
+pub struct PluginStateTracker {
+    state: Mutex<HashMap<String, PluginState>>,
+}
+
+struct PluginState {
+    hard: PluginStatus,
+    pending: Option<(PluginStatus, u32)>,
+}
+
+impl Default for PluginState {
+    fn default() -> Self {
+        Self { hard: PluginStatus::Ok, pending: None }
+    }
+}
+
+impl PluginStateTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, check: &ServerCheck, output: &PluginOutput) -> Option<Alert> {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let entry = state.entry(check.id.clone()).or_default();
+        if output.status == entry.hard {
+            entry.pending = None;
+            return None;
+        }
+
+        let attempts = match entry.pending {
+            Some((status, attempts)) if status == output.status => attempts + 1,
+            _ => 1,
+        };
+        if entry.hard == PluginStatus::Ok && attempts < check.failure_threshold.max(1) as u32 {
+            entry.pending = Some((output.status, attempts));
+            return None;
+        }
+        let previous = entry.hard;
+        entry.hard = output.status;
+        entry.pending = None;
+
+        let (alert_type, severity, message) = match output.status {
+            PluginStatus::Ok => (
+                "check_recovered",
+                AlertSeverity::Info,
+                format!("Check '{}' recovered from {}: {}", check.name, previous.as_str(), output.summary),
+            ),
+            status => {
+                let (alert_type, severity) = match status {
+                    PluginStatus::Critical => ("check_critical", AlertSeverity::Critical),
+                    PluginStatus::Warning => ("check_warning", AlertSeverity::Warning),
+                    _ => ("check_unknown", AlertSeverity::Warning),
+                };
+                (alert_type, severity, format!("Check '{}' is {}: {}", check.name, status.as_str(), output.summary))
+            }
+        };
+        Some(super::alert(&check.server_id, alert_type, severity, message))
+    }
+
+    pub fn hard_state(&self, check_id: &str) -> PluginStatus {
+        self.state
+            .lock()
+            .map(|state| state.get(check_id).map_or(PluginStatus::Ok, |entry| entry.hard))
+            .unwrap_or(PluginStatus::Ok)
+    }
+
+    pub fn clear(&self, check_id: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state.remove(check_id);
+        }
+    }
+}
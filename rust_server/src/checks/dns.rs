@@ -0,0 +1,246 @@
+// src/checks/dns.rs
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::{Duration, Instant};
+use anyhow::{anyhow, bail, Result};
+use tokio::net::UdpSocket;
+use tokio::time::timeout;
+use crate::models::check::{CheckOutcome, DnsCheckConfig, DnsRecordType};
+
+const RESOLV_CONF: &str = "/etc/resolv.conf";
+const MAX_POINTER_JUMPS: usize = 64;
+
+// 응답 코드와 질의한 타입의 응답 값
+#[derive(Debug, Clone, PartialEq)]
+pub struct DnsResponse {
+    pub rcode: u8,
+    pub answers: Vec<String>,
+}
+
+// 리졸버에 UDP 로 한 번 질의하고 응답을 기대값과 비교한다
+pub async fn run(config: &DnsCheckConfig) -> CheckOutcome {
+    let resolver = match config.resolver_addr().or_else(system_resolver) {
+        Some(resolver) => resolver,
+        None => return CheckOutcome::down(None, None, "No resolver configured"),
+    };
+    let deadline = Duration::from_secs(config.timeout_seconds);
+    let id: u16 = rand::random();
+    let query = build_query(id, &config.name, config.record_type);
+
+    let started = Instant::now();
+    let packet = match timeout(deadline, exchange(resolver, id, &query)).await {
+        Ok(Ok(packet)) => packet,
+        Ok(Err(e)) => return CheckOutcome::down(None, None, format!("Query to {} failed: {}", resolver, e)),
+        Err(_) => return CheckOutcome::down(None, None, format!("No answer from {} after {}s", resolver, config.timeout_seconds)),
+    };
+    let elapsed = started.elapsed().as_secs_f64() * 1000.0;
+
+    let response = match parse_response(id, config.record_type, &packet) {
+        Ok(response) => response,
+        Err(e) => return CheckOutcome::down(Some(elapsed), None, format!("Invalid response from {}: {}", resolver, e)),
+    };
+    match evaluate(config, &response) {
+        Ok(()) => CheckOutcome::up(elapsed, Some(response.rcode as u16)),
+        Err(message) => CheckOutcome::down(Some(elapsed), Some(response.rcode as u16), message),
+    }
+}
+
+async fn exchange(resolver: SocketAddr, id: u16, query: &[u8]) -> std::io::Result<Vec<u8>> {
+    let bind: SocketAddr = if resolver.is_ipv4() {
+        (Ipv4Addr::UNSPECIFIED, 0).into()
+    } else {
+        (Ipv6Addr::UNSPECIFIED, 0).into()
+    };
+    let socket = UdpSocket::bind(bind).await?;
+    socket.connect(resolver).await?;
+    socket.send(query).await?;
+
+    // 이전 질의의 늦은 응답은 버린다
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = socket.recv(&mut buf).await?;
+        if n >= 2 && u16::from_be_bytes([buf[0], buf[1]]) == id {
+            return Ok(buf[..n].to_vec());
+        }
+    }
+}
+
+// 재귀 질의(RD) 하나짜리 패킷
+pub fn build_query(id: u16, name: &str, record_type: DnsRecordType) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(512);
+    packet.extend_from_slice(&id.to_be_bytes());
+    packet.extend_from_slice(&0x0100u16.to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes());
+    packet.extend_from_slice(&[0; 6]);
+    for label in name.trim_end_matches('.').split('.') {
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+    packet.extend_from_slice(&record_type.code().to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes());
+    packet
+}
+
+pub fn parse_response(id: u16, record_type: DnsRecordType, packet: &[u8]) -> Result<DnsResponse> {
+    if packet.len() < 12 {
+        bail!("Packet too short ({} bytes)", packet.len());
+    }
+    if read_u16(packet, 0)? != id {
+        bail!("Unexpected query id");
+    }
+    let flags = read_u16(packet, 2)?;
+    if flags & 0x8000 == 0 {
+        bail!("Not a response");
+    }
+    let questions = read_u16(packet, 4)?;
+    let answer_count = read_u16(packet, 6)?;
+
+    let mut pos = 12;
+    for _ in 0..questions {
+        pos = read_name(packet, pos)?.1 + 4;
+    }
+
+    // CNAME 을 따라간 경우 질의한 타입의 레코드만 모은다
+    let mut answers = Vec::new();
+    for _ in 0..answer_count {
+        let (_, next) = read_name(packet, pos)?;
+        let rtype = read_u16(packet, next)?;
+        let length = read_u16(packet, next + 8)? as usize;
+        let start = next + 10;
+        let rdata = packet
+            .get(start..start + length)
+            .ok_or_else(|| anyhow!("Record data out of bounds"))?;
+        if rtype == record_type.code() {
+            answers.push(format_rdata(record_type, packet, start, rdata)?);
+        }
+        pos = start + length;
+    }
+
+    Ok(DnsResponse {
+        rcode: (flags & 0x000f) as u8,
+        answers,
+    })
+}
+
+fn format_rdata(record_type: DnsRecordType, packet: &[u8], start: usize, rdata: &[u8]) -> Result<String> {
+    let value = match record_type {
+        DnsRecordType::A => {
+            let octets: [u8; 4] = rdata.try_into().map_err(|_| anyhow!("Invalid A record"))?;
+            Ipv4Addr::from(octets).to_string()
+        }
+        DnsRecordType::Aaaa => {
+            let octets: [u8; 16] = rdata.try_into().map_err(|_| anyhow!("Invalid AAAA record"))?;
+            Ipv6Addr::from(octets).to_string()
+        }
+        DnsRecordType::Cname | DnsRecordType::Ns => read_name(packet, start)?.0,
+        DnsRecordType::Mx => format!("{} {}", read_u16(packet, start)?, read_name(packet, start + 2)?.0),
+        DnsRecordType::Txt => {
+            // 여러 문자열은 이어 붙인다
+            let mut text = String::new();
+            let mut pos = 0;
+            while pos < rdata.len() {
+                let length = rdata[pos] as usize;
+                let chunk = rdata
+                    .get(pos + 1..pos + 1 + length)
+                    .ok_or_else(|| anyhow!("Invalid TXT record"))?;
+                text.push_str(&String::from_utf8_lossy(chunk));
+                pos += 1 + length;
+            }
+            text
+        }
+    };
+    Ok(value)
+}
+
+// 압축 포인터를 따라 이름을 읽는다. 반환 위치는 원래 이름 바로 다음
+fn read_name(packet: &[u8], mut pos: usize) -> Result<(String, usize)> {
+    let mut labels = Vec::new();
+    let mut end = None;
+    let mut jumps = 0;
+    loop {
+        let length = *packet.get(pos).ok_or_else(|| anyhow!("Name out of bounds"))? as usize;
+        if length == 0 {
+            break;
+        }
+        if length & 0xc0 == 0xc0 {
+            jumps += 1;
+            if jumps > MAX_POINTER_JUMPS {
+                bail!("Too many compression pointers");
+            }
+            end.get_or_insert(pos + 2);
+            pos = (read_u16(packet, pos)? & 0x3fff) as usize;
+            continue;
+        }
+        let label = packet
+            .get(pos + 1..pos + 1 + length)
+            .ok_or_else(|| anyhow!("Label out of bounds"))?;
+        labels.push(String::from_utf8_lossy(label).into_owned());
+        pos += 1 + length;
+    }
+    Ok((labels.join("."), end.unwrap_or(pos + 1)))
+}
+
+fn read_u16(packet: &[u8], pos: usize) -> Result<u16> {
+    packet
+        .get(pos..pos + 2)
+        .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+        .ok_or_else(|| anyhow!("Unexpected end of packet"))
+}
+
+// 응답 코드, 응답 유무, 기대값 순서로 확인한다
+pub fn evaluate(config: &DnsCheckConfig, response: &DnsResponse) -> Result<(), String> {
+    if response.rcode != 0 {
+        return Err(format!("Resolver returned {}", rcode_name(response.rcode)));
+    }
+    if response.answers.is_empty() {
+        return Err(format!("No {} records for {}", config.record_type.as_str(), config.name));
+    }
+    let missing: Vec<&str> = config
+        .expected
+        .iter()
+        .filter(|expected| !response.answers.iter().any(|answer| same_answer(config.record_type, expected, answer)))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "Missing expected answers: {} (got: {})",
+            missing.join(", "),
+            response.answers.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+// TXT 외에는 대소문자와 끝의 '.' 를 무시한다
+fn same_answer(record_type: DnsRecordType, expected: &str, answer: &str) -> bool {
+    if record_type == DnsRecordType::Txt {
+        return expected == answer;
+    }
+    let normalize = |value: &str| value.trim().trim_end_matches('.').to_ascii_lowercase();
+    normalize(expected) == normalize(answer)
+}
+
+fn rcode_name(rcode: u8) -> String {
+    match rcode {
+        1 => "FORMERR".to_string(),
+        2 => "SERVFAIL".to_string(),
+        3 => "NXDOMAIN".to_string(),
+        4 => "NOTIMP".to_string(),
+        5 => "REFUSED".to_string(),
+        other => format!("RCODE {}", other),
+    }
+}
+
+pub fn system_resolver() -> Option<SocketAddr> {
+    parse_resolv_conf(&std::fs::read_to_string(RESOLV_CONF).ok()?)
+}
+
+// 첫 번째로 쓸 수 있는 nameserver (zone 이 붙은 링크 로컬 주소는 건너뛴다)
+pub fn parse_resolv_conf(content: &str) -> Option<SocketAddr> {
+    content
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("nameserver"))
+        .filter_map(|address| address.trim().parse::<std::net::IpAddr>().ok())
+        .map(|ip| SocketAddr::from((ip, 53)))
+        .next()
+}
@@ -0,0 +1,159 @@
+// src/checks/http.rs
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use regex::Regex;
+use crate::models::check::{CheckOutcome, HttpCheckConfig};
+
+// 본문 비교에 쓰는 최대 크기. 넘는 부분은 읽지 않는다
+pub const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+// URL 을 한 번 요청하고 상태 코드, 본문, 응답 시간을 설정과 비교한다
+pub async fn run(client: &reqwest::Client, config: &HttpCheckConfig) -> CheckOutcome {
+    match compile_body_regex(config) {
+        Ok(body_regex) => run_compiled(client, config, body_regex.as_ref()).await,
+        Err(message) => CheckOutcome::down(None, None, message),
+    }
+}
+
+// 컴파일해 둔 body_regex 로 검사한다. 주기적 검사는 BodyRegexCache 에서 꺼내 쓴다
+pub async fn run_compiled(client: &reqwest::Client, config: &HttpCheckConfig, body_regex: Option<&Regex>) -> CheckOutcome {
+    let method = match reqwest::Method::from_bytes(config.method.as_bytes()) {
+        Ok(method) => method,
+        Err(_) => return CheckOutcome::down(None, None, format!("Invalid method: {}", config.method)),
+    };
+    let mut request = client
+        .request(method, &config.url)
+        .timeout(Duration::from_secs(config.timeout_seconds));
+    for (name, value) in &config.headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+
+    let started = Instant::now();
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(e) => {
+            let message = if e.is_timeout() {
+                format!("Timed out after {}s", config.timeout_seconds)
+            } else {
+                format!("Request failed: {}", e)
+            };
+            return CheckOutcome::down(None, None, message);
+        }
+    };
+    let status = response.status().as_u16();
+    let body = if config.needs_body() {
+        match read_body(response).await {
+            Ok(body) => Some(body),
+            Err(e) => {
+                let elapsed = elapsed_ms(started);
+                return CheckOutcome::down(Some(elapsed), Some(status), format!("Failed to read body: {}", e));
+            }
+        }
+    } else {
+        None
+    };
+    let elapsed = elapsed_ms(started);
+
+    match evaluate(config, body_regex, status, body.as_deref(), elapsed) {
+        Ok(()) => CheckOutcome::up(elapsed, Some(status)),
+        Err(message) => CheckOutcome::down(Some(elapsed), Some(status), message),
+    }
+}
+
+// 본문은 MAX_BODY_BYTES 까지만 읽는다
+async fn read_body(mut response: reqwest::Response) -> reqwest::Result<String> {
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        let remaining = MAX_BODY_BYTES - body.len();
+        body.extend_from_slice(&chunk[..chunk.len().min(remaining)]);
+        if body.len() >= MAX_BODY_BYTES {
+            break;
+        }
+    }
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+// 응답을 설정과 비교해 첫 번째 실패 사유를 돌려준다
+pub fn evaluate(
+    config: &HttpCheckConfig,
+    body_regex: Option<&Regex>,
+    status: u16,
+    body: Option<&str>,
+    elapsed_ms: f64,
+) -> Result<(), String> {
+    if !config.status_ok(status) {
+        return Err(format!("Unexpected status {}", status));
+    }
+    let body = body.unwrap_or_default();
+    if let Some(needle) = &config.body_contains {
+        if !body.contains(needle.as_str()) {
+            return Err(format!("Body does not contain '{}'", needle));
+        }
+    }
+    if let Some(regex) = body_regex {
+        if !regex.is_match(body) {
+            return Err(format!("Body does not match /{}/", regex.as_str()));
+        }
+    }
+    if let Some(budget) = config.max_response_ms {
+        if elapsed_ms > budget as f64 {
+            return Err(format!("Response took {:.0}ms (budget {}ms)", elapsed_ms, budget));
+        }
+    }
+    Ok(())
+}
+
+fn compile_body_regex(config: &HttpCheckConfig) -> Result<Option<Regex>, String> {
+    config
+        .body_regex
+        .as_deref()
+        .map(|pattern| Regex::new(pattern).map_err(|e| format!("Invalid body_regex: {}", e)))
+        .transpose()
+}
+
+// 검사별로 컴파일한 body_regex. 패턴이 바뀔 때만 다시 컴파일한다
+#[derive(Default)]
+pub struct BodyRegexCache {
+    state: Mutex<HashMap<String, Regex>>,
+}
+
+impl BodyRegexCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, check_id: &str, config: &HttpCheckConfig) -> Result<Option<Regex>, String> {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let pattern = match config.body_regex.as_deref() {
+            Some(pattern) => pattern,
+            None => {
+                state.remove(check_id);
+                return Ok(None);
+            }
+        };
+        if let Some(regex) = state.get(check_id).filter(|regex| regex.as_str() == pattern) {
+            return Ok(Some(regex.clone()));
+        }
+        let regex = compile_body_regex(config)?;
+        if let Some(regex) = &regex {
+            state.insert(check_id.to_string(), regex.clone());
+        }
+        Ok(regex)
+    }
+
+    pub fn clear(&self, check_id: &str) {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        state.remove(check_id);
+    }
+}
+
+fn elapsed_ms(started: Instant) -> f64 {
+    started.elapsed().as_secs_f64() * 1000.0
+}
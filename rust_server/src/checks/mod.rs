@@ -0,0 +1,303 @@
+// src/checks/mod.rs
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use anyhow::anyhow;
+use chrono::Utc;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+use crate::config::{EncryptionConfig, ServerConfig};
+use crate::db::models::{Alert, AlertSeverity, ServerCheck};
+use crate::db::repository::Repository;
+use crate::models::check::{CertificateInfo, CheckConfig, CheckOutcome, CommandCheckConfig, TlsCheckConfig};
+use crate::models::plugin::PluginOutput;
+use crate::monitoring::ssh::{SshSession, SshTarget};
+use crate::utils::encryption::Encryptor;
+
+pub mod command;
+pub mod dns;
+pub mod http;
+pub mod tcp;
+pub mod tls;
+
+// 서버별 합성 검사(HTTP, TCP, DNS, TLS, SSH 명령)를 검사마다 정해진 주기로 실행하고 결과를 시계열로 저장한다.
+// 메트릭 수집(MonitoringService)과는 별도의 태스크로 돈다
+#[derive(Clone)]
+pub struct CheckService {
+    repo: Repository,
+    client: reqwest::Client,
+    encryption: EncryptionConfig,
+    failures: Arc<FailureTracker>,
+    expiry: Arc<tls::ExpiryTracker>,
+    body_regexes: Arc<http::BodyRegexCache>,
+    plugin_states: Arc<command::PluginStateTracker>,
+    tasks: Arc<RwLock<HashMap<String, JoinHandle<()>>>>,
+}
+
+impl CheckService {
+    pub fn new(repo_data: actix_web::web::Data<Repository>, client: reqwest::Client) -> Self {
+        Self {
+            repo: repo_data.get_ref().clone(),
+            client,
+            encryption: EncryptionConfig::default(),
+            failures: Arc::new(FailureTracker::new()),
+            expiry: Arc::new(tls::ExpiryTracker::new()),
+            body_regexes: Arc::new(http::BodyRegexCache::new()),
+            plugin_states: Arc::new(command::PluginStateTracker::new()),
+            tasks: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    // 명령 검사의 SSH 비밀번호 복호화에 쓴다
+    pub fn with_config(mut self, config: &ServerConfig) -> Self {
+        self.encryption = config.encryption.clone();
+        self
+    }
+
+    // 활성화된 검사를 모두 스케줄에 올린다
+    pub async fn start(&self) {
+        match self.repo.list_enabled_checks().await {
+            Ok(checks) => {
+                info!("Scheduling {} checks", checks.len());
+                for check in checks {
+                    self.schedule(check).await;
+                }
+            }
+            Err(e) => error!("Failed to load checks: {}", e),
+        }
+    }
+
+    // 이미 돌고 있는 검사라면 교체한다
+    pub async fn schedule(&self, check: ServerCheck) {
+        self.unschedule(&check.id).await;
+        if !check.enabled {
+            return;
+        }
+
+        let service = self.clone();
+        let check_id = check.id.clone();
+        let period = tokio::time::Duration::from_secs(check.interval_seconds.max(1) as u64);
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                // 검사나 서버가 삭제되면 종료
+                let check = match service.repo.get_check(&check.server_id, &check.id).await {
+                    Ok(Some(check)) if check.enabled => check,
+                    Ok(_) => break,
+                    Err(e) => {
+                        error!("Failed to load check {}: {}", check.id, e);
+                        continue;
+                    }
+                };
+                service.run_check(&check).await;
+            }
+            service.failures.clear(&check.id);
+            service.expiry.clear(&check.id);
+            service.body_regexes.clear(&check.id);
+            service.plugin_states.clear(&check.id);
+        });
+        self.tasks.write().await.insert(check_id, handle);
+    }
+
+    pub async fn unschedule(&self, check_id: &str) {
+        if let Some(handle) = self.tasks.write().await.remove(check_id) {
+            handle.abort();
+        }
+        self.failures.clear(check_id);
+        self.expiry.clear(check_id);
+        self.body_regexes.clear(check_id);
+        self.plugin_states.clear(check_id);
+    }
+
+    // 설정대로 검사를 한 번 실행. 명령 검사는 server_id 의 SSH 계정으로 돈다
+    pub async fn execute(&self, server_id: &str, config: &CheckConfig) -> CheckOutcome {
+        match config {
+            CheckConfig::Http(config) => http::run(&self.client, config).await,
+            CheckConfig::Tcp(config) => tcp::run(config).await,
+            CheckConfig::Dns(config) => dns::run(config).await,
+            CheckConfig::Tls(config) => tls::run(config).await.0,
+            CheckConfig::Command(config) => self.run_plugin(server_id, config).await.0,
+        }
+    }
+
+    async fn run_plugin(&self, server_id: &str, config: &CommandCheckConfig) -> (CheckOutcome, PluginOutput) {
+        match self.ssh_target(server_id).await {
+            Ok(target) => {
+                let session = SshSession::new(target).with_timeout(command::session_timeout(config));
+                command::run(&Arc::new(session), config).await
+            }
+            Err(e) => {
+                let output = PluginOutput::unknown(format!("Cannot reach server over SSH: {:#}", e));
+                (command::outcome(&output, None), output)
+            }
+        }
+    }
+
+    async fn ssh_target(&self, server_id: &str) -> anyhow::Result<SshTarget> {
+        let server = self
+            .repo
+            .get_server(server_id)
+            .await?
+            .ok_or_else(|| anyhow!("Server {} not found", server_id))?;
+        let encryptor = Encryptor::new(&self.encryption.key, &self.encryption.nonce)
+            .map_err(|e| anyhow!(e.to_string()))?;
+        SshTarget::from_server(&server, &encryptor)
+    }
+
+    async fn run_check(&self, check: &ServerCheck) {
+        let config = match check.check_config() {
+            Ok(config) => config,
+            Err(e) => {
+                warn!("Invalid config for check {}: {}", check.id, e);
+                return;
+            }
+        };
+        let outcome = match &config {
+            CheckConfig::Tls(config) => {
+                let (outcome, certificate) = tls::run(config).await;
+                if let Some(certificate) = certificate {
+                    self.record_certificate(check, config, &certificate).await;
+                }
+                outcome
+            }
+            // 명령 검사는 연속 실패 대신 플러그인 상태 변화로 알린다
+            CheckConfig::Command(config) => {
+                let (outcome, output) = self.run_plugin(&check.server_id, config).await;
+                self.record_plugin_output(check, &output).await;
+                outcome
+            }
+            CheckConfig::Http(config) => match self.body_regexes.get(&check.id, config) {
+                Ok(body_regex) => http::run_compiled(&self.client, config, body_regex.as_ref()).await,
+                Err(message) => CheckOutcome::down(None, None, message),
+            },
+            config => self.execute(&check.server_id, config).await,
+        };
+        if let Err(e) = self.repo.save_check_result(&check.id, Utc::now(), &outcome).await {
+            error!("Failed to save result for check {}: {}", check.id, e);
+        }
+
+        if matches!(config, CheckConfig::Command(_)) {
+            return;
+        }
+        if let Some(alert) = self.failures.record(check, &outcome) {
+            if let Err(e) = self.repo.create_alert(alert).await {
+                error!("Failed to create alert for {}: {}", check.server_id, e);
+            }
+        }
+    }
+
+    // 성능 데이터를 사용자 정의 메트릭으로 저장하고 확정 상태가 바뀌면 알린다
+    async fn record_plugin_output(&self, check: &ServerCheck, output: &PluginOutput) {
+        if !output.perfdata.is_empty() {
+            if let Err(e) = self
+                .repo
+                .save_custom_metrics(&check.server_id, &check.id, Utc::now(), &output.perfdata)
+                .await
+            {
+                error!("Failed to save perfdata for check {}: {}", check.id, e);
+            }
+        }
+        if let Some(alert) = self.plugin_states.record(check, output) {
+            if let Err(e) = self.repo.create_alert(alert).await {
+                error!("Failed to create alert for {}: {}", check.server_id, e);
+            }
+        }
+    }
+
+    // 마지막으로 받은 인증서를 저장하고 만료가 가까우면 알린다
+    async fn record_certificate(&self, check: &ServerCheck, config: &TlsCheckConfig, certificate: &CertificateInfo) {
+        let now = Utc::now();
+        if let Err(e) = self.repo.save_check_certificate(&check.id, now, certificate).await {
+            error!("Failed to save certificate for check {}: {}", check.id, e);
+        }
+        if let Some(alert) = self.expiry.record(check, config, certificate, now) {
+            if let Err(e) = self.repo.create_alert(alert).await {
+                error!("Failed to create alert for {}: {}", check.server_id, e);
+            }
+        }
+    }
+}
+
+// 검사별 연속 실패 횟수. 임계값에 닿을 때 한 번 알리고, 알린 뒤 성공하면 복구를 알린다
+#[derive(Default)]
+pub struct FailureTracker {
+    state: Mutex<HashMap<String, FailureState>>,
+}
+
+#[derive(Default)]
+struct FailureState {
+    consecutive: u32,
+    alerted: bool,
+}
+
+impl FailureTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, check: &ServerCheck, outcome: &CheckOutcome) -> Option<Alert> {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let entry = state.entry(check.id.clone()).or_default();
+
+        if outcome.success {
+            let recovered = entry.alerted.then(|| {
+                alert(
+                    &check.server_id,
+                    "check_recovered",
+                    AlertSeverity::Info,
+                    format!("Check '{}' recovered after {} failures", check.name, entry.consecutive),
+                )
+            });
+            *entry = FailureState::default();
+            return recovered;
+        }
+
+        entry.consecutive += 1;
+        if entry.alerted || entry.consecutive < check.failure_threshold.max(1) as u32 {
+            return None;
+        }
+        entry.alerted = true;
+        Some(alert(
+            &check.server_id,
+            "check_failed",
+            AlertSeverity::Critical,
+            format!(
+                "Check '{}' failed {} times in a row: {}",
+                check.name,
+                entry.consecutive,
+                outcome.message.as_deref().unwrap_or("unknown error")
+            ),
+        ))
+    }
+
+    pub fn consecutive_failures(&self, check_id: &str) -> u32 {
+        self.state
+            .lock()
+            .map(|state| state.get(check_id).map_or(0, |entry| entry.consecutive))
+            .unwrap_or(0)
+    }
+
+    pub fn clear(&self, check_id: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state.remove(check_id);
+        }
+    }
+}
+
+fn alert(server_id: &str, alert_type: &str, severity: AlertSeverity, message: String) -> Alert {
+    Alert {
+        id: 0,
+        server_id: server_id.to_string(),
+        alert_type: alert_type.to_string(),
+        severity,
+        message,
+        created_at: Utc::now(),
+        acknowledged_at: None,
+        acknowledged_by: None,
+    }
+}
@@ -0,0 +1,63 @@
+// src/checks/tcp.rs
+use std::time::{Duration, Instant};
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use crate::models::check::{CheckOutcome, TcpCheckConfig};
+
+const MAX_BANNER_BYTES: usize = 4096;
+
+// host:port 에 연결해 본다. banner 가 있으면 연결 후 받은 데이터에 포함되는지도 확인한다.
+// 응답 시간은 이름 조회를 포함한 연결 시간
+pub async fn run(config: &TcpCheckConfig) -> CheckOutcome {
+    let deadline = Duration::from_secs(config.timeout_seconds);
+    let started = Instant::now();
+
+    let addr = match timeout(deadline, tokio::net::lookup_host((config.host.as_str(), config.port))).await {
+        Ok(Ok(mut addrs)) => match addrs.next() {
+            Some(addr) => addr,
+            None => return CheckOutcome::down(None, None, format!("Could not resolve {}", config.host)),
+        },
+        Ok(Err(e)) => return CheckOutcome::down(None, None, format!("Could not resolve {}: {}", config.host, e)),
+        Err(_) => return CheckOutcome::down(None, None, format!("Timed out resolving {}", config.host)),
+    };
+
+    let remaining = deadline.saturating_sub(started.elapsed());
+    let mut stream = match timeout(remaining, TcpStream::connect(addr)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) => return CheckOutcome::down(None, None, format!("Connection to {} failed: {}", addr, e)),
+        Err(_) => {
+            return CheckOutcome::down(None, None, format!("Timed out after {}s", config.timeout_seconds))
+        }
+    };
+    let connect_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+    let banner = match &config.banner {
+        Some(banner) => banner,
+        None => return CheckOutcome::up(connect_ms, None),
+    };
+    let remaining = deadline.saturating_sub(started.elapsed());
+    match timeout(remaining, read_until(&mut stream, banner)).await {
+        Ok(Ok(true)) => CheckOutcome::up(connect_ms, None),
+        Ok(Ok(false)) => CheckOutcome::down(Some(connect_ms), None, format!("Banner does not contain '{}'", banner)),
+        Ok(Err(e)) => CheckOutcome::down(Some(connect_ms), None, format!("Failed to read banner: {}", e)),
+        Err(_) => CheckOutcome::down(Some(connect_ms), None, format!("No banner containing '{}' before timeout", banner)),
+    }
+}
+
+// 문자열이 나오거나 연결이 닫히거나 한도에 닿을 때까지 읽는다
+async fn read_until(stream: &mut TcpStream, banner: &str) -> std::io::Result<bool> {
+    let mut received = Vec::new();
+    let mut buf = [0u8; 1024];
+    while received.len() < MAX_BANNER_BYTES {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        received.extend_from_slice(&buf[..n]);
+        if String::from_utf8_lossy(&received).contains(banner) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
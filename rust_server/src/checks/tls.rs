@@ -0,0 +1,273 @@
+// src/checks/tls.rs
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use rustls::client::{ServerCertVerified, ServerCertVerifier, WebPkiVerifier};
+use rustls::{Certificate, ClientConfig, OwnedTrustAnchor, RootCertStore, ServerName};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_rustls::TlsConnector;
+use x509_parser::extensions::GeneralName;
+use crate::db::models::{Alert, AlertSeverity, ServerCheck};
+use crate::models::check::{CertificateInfo, CheckOutcome, TlsCheckConfig};
+use crate::monitoring::alerts::rank;
+
+// host:port 와 TLS 핸드셰이크를 하고 leaf 인증서를 읽는다.
+// 체인이 유효하지 않아도 인증서 정보는 남기도록 검증 결과만 기록하고 핸드셰이크는 계속한다
+pub async fn run(config: &TlsCheckConfig) -> (CheckOutcome, Option<CertificateInfo>) {
+    let deadline = Duration::from_secs(config.timeout_seconds);
+    let started = Instant::now();
+
+    let server_name = match ServerName::try_from(config.server_name()) {
+        Ok(server_name) => server_name,
+        Err(_) => return (CheckOutcome::down(None, None, format!("Invalid server name: {}", config.server_name())), None),
+    };
+    let roots = match root_store(config.ca_pem.as_deref()) {
+        Ok(roots) => roots,
+        Err(e) => return (CheckOutcome::down(None, None, e.to_string()), None),
+    };
+    let verifier = Arc::new(RecordingVerifier::new(roots));
+    let client_config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_custom_certificate_verifier(verifier.clone())
+        .with_no_client_auth();
+
+    let stream = match timeout(deadline, TcpStream::connect((config.host.as_str(), config.port))).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) => {
+            return (CheckOutcome::down(None, None, format!("Connection to {}:{} failed: {}", config.host, config.port, e)), None)
+        }
+        Err(_) => return (CheckOutcome::down(None, None, format!("Timed out after {}s", config.timeout_seconds)), None),
+    };
+
+    let remaining = deadline.saturating_sub(started.elapsed());
+    let connector = TlsConnector::from(Arc::new(client_config));
+    let stream = match timeout(remaining, connector.connect(server_name, stream)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) => return (CheckOutcome::down(None, None, format!("TLS handshake failed: {}", e)), None),
+        Err(_) => return (CheckOutcome::down(None, None, format!("Timed out after {}s", config.timeout_seconds)), None),
+    };
+    let handshake_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+    let leaf = match stream.get_ref().1.peer_certificates().and_then(|certs| certs.first()) {
+        Some(leaf) => leaf.0.clone(),
+        None => return (CheckOutcome::down(Some(handshake_ms), None, "Server sent no certificate"), None),
+    };
+    let certificate = match parse_certificate(&leaf, verifier.result()) {
+        Ok(certificate) => certificate,
+        Err(e) => return (CheckOutcome::down(Some(handshake_ms), None, format!("Invalid certificate: {}", e)), None),
+    };
+
+    let outcome = match evaluate(&certificate, Utc::now()) {
+        Ok(()) => CheckOutcome::up(handshake_ms, None),
+        Err(message) => CheckOutcome::down(Some(handshake_ms), None, message),
+    };
+    (outcome, Some(certificate))
+}
+
+// ca_pem 이 있으면 그 인증서만, 없으면 webpki-roots 의 공개 루트를 신뢰한다
+fn root_store(ca_pem: Option<&str>) -> Result<RootCertStore> {
+    let mut roots = RootCertStore::empty();
+    match ca_pem {
+        Some(pem) => {
+            let certs = rustls_pemfile::certs(&mut pem.as_bytes())
+                .map_err(|e| anyhow!("Invalid ca_pem: {}", e))?;
+            for cert in certs {
+                roots
+                    .add(&Certificate(cert))
+                    .map_err(|e| anyhow!("Invalid ca_pem: {}", e))?;
+            }
+        }
+        None => roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|anchor| {
+            OwnedTrustAnchor::from_subject_spki_name_constraints(
+                anchor.subject,
+                anchor.spki,
+                anchor.name_constraints,
+            )
+        })),
+    }
+    Ok(roots)
+}
+
+// webpki 검증 결과를 기록하고 인증서는 항상 받아들인다
+struct RecordingVerifier {
+    inner: WebPkiVerifier,
+    result: Mutex<Option<Result<(), String>>>,
+}
+
+impl RecordingVerifier {
+    fn new(roots: RootCertStore) -> Self {
+        Self {
+            inner: WebPkiVerifier::new(roots, None),
+            result: Mutex::new(None),
+        }
+    }
+
+    fn result(&self) -> Result<(), String> {
+        self.result
+            .lock()
+            .ok()
+            .and_then(|result| result.clone())
+            .unwrap_or_else(|| Err("Certificate was not verified".to_string()))
+    }
+}
+
+impl ServerCertVerifier for RecordingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        intermediates: &[Certificate],
+        server_name: &ServerName,
+        scts: &mut dyn Iterator<Item = &[u8]>,
+        ocsp_response: &[u8],
+        now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let result = self
+            .inner
+            .verify_server_cert(end_entity, intermediates, server_name, scts, ocsp_response, now)
+            .map(|_| ())
+            .map_err(|e| e.to_string());
+        if let Ok(mut recorded) = self.result.lock() {
+            *recorded = Some(result);
+        }
+        Ok(ServerCertVerified::assertion())
+    }
+}
+
+// DER 인증서에서 주체, 발급자, SAN, 유효 기간을 꺼낸다
+pub fn parse_certificate(der: &[u8], chain: Result<(), String>) -> Result<CertificateInfo> {
+    let (_, cert) = x509_parser::parse_x509_certificate(der)
+        .map_err(|e| anyhow!("{}", e))?;
+
+    let sans = match cert.subject_alternative_name() {
+        Ok(Some(extension)) => extension
+            .value
+            .general_names
+            .iter()
+            .filter_map(|name| match name {
+                GeneralName::DNSName(name) => Some(name.to_string()),
+                GeneralName::IPAddress(bytes) => ip_address(bytes).map(|ip| ip.to_string()),
+                _ => None,
+            })
+            .collect(),
+        Ok(None) => Vec::new(),
+        Err(e) => return Err(anyhow!("Invalid subjectAltName: {}", e)),
+    };
+    let validity = cert.validity();
+
+    Ok(CertificateInfo {
+        subject: cert.subject().to_string(),
+        issuer: cert.issuer().to_string(),
+        sans,
+        serial: cert.raw_serial_as_string(),
+        not_before: timestamp(validity.not_before.timestamp())?,
+        not_after: timestamp(validity.not_after.timestamp())?,
+        chain_valid: chain.is_ok(),
+        chain_error: chain.err(),
+    })
+}
+
+fn ip_address(bytes: &[u8]) -> Option<IpAddr> {
+    match bytes.len() {
+        4 => <[u8; 4]>::try_from(bytes).ok().map(IpAddr::from),
+        16 => <[u8; 16]>::try_from(bytes).ok().map(IpAddr::from),
+        _ => None,
+    }
+}
+
+fn timestamp(seconds: i64) -> Result<DateTime<Utc>> {
+    Utc.timestamp_opt(seconds, 0)
+        .single()
+        .ok_or_else(|| anyhow!("Invalid validity time: {}", seconds))
+}
+
+// 유효 기간, 체인 순서로 확인한다. 만료 임박은 실패가 아니라 ExpiryTracker 가 알린다
+pub fn evaluate(certificate: &CertificateInfo, now: DateTime<Utc>) -> Result<(), String> {
+    if certificate.not_after <= now {
+        return Err(format!("Certificate expired at {}", certificate.not_after.to_rfc3339()));
+    }
+    if certificate.not_before > now {
+        return Err(format!("Certificate is not valid until {}", certificate.not_before.to_rfc3339()));
+    }
+    if let Some(error) = &certificate.chain_error {
+        return Err(format!("Certificate chain is not valid: {}", error));
+    }
+    Ok(())
+}
+
+// 남은 일수에 따른 단계. critical_days 이하면 Critical, warning_days 이하면 Warning
+pub fn expiry_severity(config: &TlsCheckConfig, days_left: i64) -> Option<AlertSeverity> {
+    if days_left <= config.critical_days as i64 {
+        Some(AlertSeverity::Critical)
+    } else if days_left <= config.warning_days as i64 {
+        Some(AlertSeverity::Warning)
+    } else {
+        None
+    }
+}
+
+// 검사별로 알린 만료 단계. 같은 인증서로 단계가 올라갈 때만 다시 알리고,
+// 인증서가 바뀌거나 임계 밖으로 나가면 초기화한다
+#[derive(Default)]
+pub struct ExpiryTracker {
+    state: Mutex<HashMap<String, (String, AlertSeverity)>>,
+}
+
+impl ExpiryTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(
+        &self,
+        check: &ServerCheck,
+        config: &TlsCheckConfig,
+        certificate: &CertificateInfo,
+        now: DateTime<Utc>,
+    ) -> Option<Alert> {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let days_left = certificate.days_left(now);
+        let severity = match expiry_severity(config, days_left) {
+            Some(severity) => severity,
+            None => {
+                state.remove(&check.id);
+                return None;
+            }
+        };
+
+        let escalated = match state.get(&check.id) {
+            Some((serial, previous)) if *serial == certificate.serial => {
+                rank(&severity) > rank(previous)
+            }
+            _ => true,
+        };
+        if !escalated {
+            return None;
+        }
+        state.insert(check.id.clone(), (certificate.serial.clone(), severity.clone()));
+
+        let when = if certificate.not_after <= now {
+            format!("expired on {}", certificate.not_after.format("%Y-%m-%d"))
+        } else {
+            format!("expires in {} days ({})", days_left, certificate.not_after.format("%Y-%m-%d"))
+        };
+        Some(super::alert(
+            &check.server_id,
+            "certificate_expiring",
+            severity,
+            format!("Certificate for {} (check '{}') {}", config.server_name(), check.name, when),
+        ))
+    }
+
+    pub fn clear(&self, check_id: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state.remove(check_id);
+        }
+    }
+}
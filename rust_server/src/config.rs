@@ -1,5 +1,6 @@
 // src/config.rs
 use serde::Deserialize;
+use std::net::IpAddr;
 
 pub use self::ServerConfig as Config;
 
@@ -10,6 +11,8 @@ pub struct ServerConfig {
     pub auth: AuthConfig,
     pub monitoring: MonitoringConfig,
     pub encryption: EncryptionConfig,
+    #[serde(default)]
+    pub syslog: SyslogConfig,
 }
 
 impl Default for ServerConfig {
@@ -33,6 +36,44 @@ impl Default for EncryptionConfig {
     }
 }
 
+// syslog 수신. 주소를 주지 않은 프로토콜은 열지 않는다
+#[derive(Debug, Deserialize, Clone)]
+pub struct SyslogConfig {
+    #[serde(default)]
+    pub udp_bind: Option<String>,       // 예: "0.0.0.0:514"
+    #[serde(default)]
+    pub tcp_bind: Option<String>,
+    #[serde(default = "default_syslog_batch_size")]
+    pub batch_size: usize,              // 이만큼 모이거나
+    #[serde(default = "default_syslog_flush_interval_ms")]
+    pub flush_interval_ms: u64,         // 이 시간이 지나면 한 번에 저장
+    #[serde(default)]
+    pub trusted_relays: Vec<IpAddr>,    // 메시지의 HOSTNAME 을 믿고 서버를 찾을 중계 서버
+}
+
+fn default_syslog_batch_size() -> usize { 500 }
+fn default_syslog_flush_interval_ms() -> u64 { 1000 }
+
+impl Default for SyslogConfig {
+    fn default() -> Self {
+        Self {
+            udp_bind: std::env::var("SYSLOG_UDP_BIND").ok().filter(|addr| !addr.is_empty()),
+            tcp_bind: std::env::var("SYSLOG_TCP_BIND").ok().filter(|addr| !addr.is_empty()),
+            batch_size: default_syslog_batch_size(),
+            flush_interval_ms: default_syslog_flush_interval_ms(),
+            trusted_relays: std::env::var("SYSLOG_TRUSTED_RELAYS")
+                .map(|relays| relays.split(',').filter_map(|ip| ip.trim().parse().ok()).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl SyslogConfig {
+    pub fn enabled(&self) -> bool {
+        self.udp_bind.is_some() || self.tcp_bind.is_some()
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct HttpServerConfig {
     pub host: String,
@@ -81,8 +122,20 @@ pub struct MonitoringConfig {
     pub metrics_interval_seconds: u64,
     pub retention_days: i64,
     pub alert_thresholds: AlertThresholds,
+    #[serde(default)]
+    pub filesystems: FilesystemConfig,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    #[serde(default)]
+    pub disk_io: DiskIoConfig,
+    #[serde(default = "default_process_snapshot_limit")]
+    pub process_snapshot_limit: usize,
 }
 
+pub const DEFAULT_PROCESS_SNAPSHOT_LIMIT: usize = 10;
+
+fn default_process_snapshot_limit() -> usize { DEFAULT_PROCESS_SNAPSHOT_LIMIT }
+
 
 impl Default for MonitoringConfig {
     fn default() -> Self {
@@ -90,10 +143,112 @@ impl Default for MonitoringConfig {
             metrics_interval_seconds: 60,
             retention_days: 30,
             alert_thresholds: AlertThresholds::default(),
+            filesystems: FilesystemConfig::default(),
+            network: NetworkConfig::default(),
+            disk_io: DiskIoConfig::default(),
+            process_snapshot_limit: DEFAULT_PROCESS_SNAPSHOT_LIMIT,
         }
     }
 }
 
+// 수집에서 제외할 파일시스템 (가상 파일시스템 등)
+#[derive(Debug, Deserialize, Clone)]
+pub struct FilesystemConfig {
+    #[serde(default = "default_excluded_fs_types")]
+    pub exclude_types: Vec<String>,
+    #[serde(default)]
+    pub exclude_mount_prefixes: Vec<String>,
+}
+
+fn default_excluded_fs_types() -> Vec<String> {
+    [
+        "tmpfs", "devtmpfs", "overlay", "squashfs", "proc", "sysfs", "cgroup", "cgroup2",
+        "devpts", "mqueue", "debugfs", "tracefs", "securityfs", "pstore", "bpf", "autofs",
+        "nsfs", "fusectl", "configfs", "hugetlbfs", "ramfs", "efivarfs", "binfmt_misc",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+impl Default for FilesystemConfig {
+    fn default() -> Self {
+        Self {
+            exclude_types: default_excluded_fs_types(),
+            exclude_mount_prefixes: vec!["/snap/".to_string()],
+        }
+    }
+}
+
+impl FilesystemConfig {
+    pub fn excludes(&self, fs_type: &str, mount_point: &str) -> bool {
+        self.exclude_types.iter().any(|t| t == fs_type)
+            || self.exclude_mount_prefixes.iter().any(|p| mount_point.starts_with(p.as_str()))
+    }
+}
+
+// 처리량 합계에서 제외할 인터페이스 (loopback, 컨테이너/가상 브리지 등)
+#[derive(Debug, Deserialize, Clone)]
+pub struct NetworkConfig {
+    #[serde(default = "default_true")]
+    pub exclude_loopback: bool,
+    #[serde(default = "default_excluded_interface_prefixes")]
+    pub exclude_interface_prefixes: Vec<String>,
+}
+
+fn default_true() -> bool { true }
+
+fn default_excluded_interface_prefixes() -> Vec<String> {
+    ["veth", "docker", "br-", "virbr", "cni", "flannel", "cali", "vxlan"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            exclude_loopback: true,
+            exclude_interface_prefixes: default_excluded_interface_prefixes(),
+        }
+    }
+}
+
+impl NetworkConfig {
+    pub fn excludes(&self, interface: &str) -> bool {
+        (self.exclude_loopback && (interface == "lo" || interface.starts_with("lo:")))
+            || self.exclude_interface_prefixes.iter().any(|p| interface.starts_with(p.as_str()))
+    }
+}
+
+// I/O 수집에서 제외할 블록 장치 (loop, ramdisk, 광학 드라이브 등)
+#[derive(Debug, Deserialize, Clone)]
+pub struct DiskIoConfig {
+    #[serde(default = "default_excluded_device_prefixes")]
+    pub exclude_device_prefixes: Vec<String>,
+}
+
+fn default_excluded_device_prefixes() -> Vec<String> {
+    ["loop", "ram", "zram", "sr", "fd"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+impl Default for DiskIoConfig {
+    fn default() -> Self {
+        Self {
+            exclude_device_prefixes: default_excluded_device_prefixes(),
+        }
+    }
+}
+
+impl DiskIoConfig {
+    pub fn excludes(&self, device: &str) -> bool {
+        self.exclude_device_prefixes.iter().any(|p| device.starts_with(p.as_str()))
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct AlertThresholds {
     pub cpu_warning: f32,
@@ -102,8 +257,39 @@ pub struct AlertThresholds {
     pub memory_critical: f32,
     pub disk_warning: f32,
     pub disk_critical: f32,
+    #[serde(default = "default_inode_warning")]
+    pub inode_warning: f32,
+    #[serde(default = "default_inode_critical")]
+    pub inode_critical: f32,
+    #[serde(default = "default_disk_io_warning")]
+    pub disk_io_warning: f32,
+    #[serde(default = "default_disk_io_critical")]
+    pub disk_io_critical: f32,
+    #[serde(default = "default_disk_await_warning_ms")]
+    pub disk_await_warning_ms: f32,
+    #[serde(default = "default_disk_await_critical_ms")]
+    pub disk_await_critical_ms: f32,
+    #[serde(default = "default_swap_warning")]
+    pub swap_warning: f32,
+    #[serde(default = "default_swap_critical")]
+    pub swap_critical: f32,
+    #[serde(default = "default_security_updates_warning")]
+    pub security_updates_warning: u32,
+    #[serde(default = "default_security_updates_critical")]
+    pub security_updates_critical: u32,
 }
 
+fn default_inode_warning() -> f32 { 80.0 }
+fn default_inode_critical() -> f32 { 90.0 }
+fn default_disk_io_warning() -> f32 { 80.0 }
+fn default_disk_io_critical() -> f32 { 95.0 }
+fn default_disk_await_warning_ms() -> f32 { 50.0 }
+fn default_disk_await_critical_ms() -> f32 { 200.0 }
+fn default_swap_warning() -> f32 { 50.0 }
+fn default_swap_critical() -> f32 { 80.0 }
+fn default_security_updates_warning() -> u32 { 1 }
+fn default_security_updates_critical() -> u32 { 10 }
+
 
 impl Default for AlertThresholds {
     fn default() -> Self {
@@ -114,6 +300,16 @@ impl Default for AlertThresholds {
             memory_critical: 90.0,
             disk_warning: 80.0,
             disk_critical: 90.0,
+            inode_warning: default_inode_warning(),
+            inode_critical: default_inode_critical(),
+            disk_io_warning: default_disk_io_warning(),
+            disk_io_critical: default_disk_io_critical(),
+            disk_await_warning_ms: default_disk_await_warning_ms(),
+            disk_await_critical_ms: default_disk_await_critical_ms(),
+            swap_warning: default_swap_warning(),
+            swap_critical: default_swap_critical(),
+            security_updates_warning: default_security_updates_warning(),
+            security_updates_critical: default_security_updates_critical(),
         }
     }
 }
@@ -154,9 +350,24 @@ impl ServerConfig {
                     memory_critical: 90.0,
                     disk_warning: 80.0,
                     disk_critical: 90.0,
+                    inode_warning: default_inode_warning(),
+                    inode_critical: default_inode_critical(),
+                    disk_io_warning: default_disk_io_warning(),
+                    disk_io_critical: default_disk_io_critical(),
+                    disk_await_warning_ms: default_disk_await_warning_ms(),
+                    disk_await_critical_ms: default_disk_await_critical_ms(),
+                    swap_warning: default_swap_warning(),
+                    swap_critical: default_swap_critical(),
+                    security_updates_warning: default_security_updates_warning(),
+                    security_updates_critical: default_security_updates_critical(),
                 },
+                filesystems: FilesystemConfig::default(),
+                network: NetworkConfig::default(),
+                disk_io: DiskIoConfig::default(),
+                process_snapshot_limit: DEFAULT_PROCESS_SNAPSHOT_LIMIT,
             },
             encryption: EncryptionConfig::default(),
+            syslog: SyslogConfig::default(),
         }
     }
 }
\ No newline at end of file
@@ -5,6 +5,11 @@ use sqlx::types::JsonValue;
 use std::fmt::Display;
 use std::str::FromStr;
 use uuid::Uuid;
+use crate::models::check::CheckConfig;
+use crate::models::log_source::LevelRule;
+use crate::models::logs::LogLevel;
+use crate::models::metrics::ServerMetrics;
+use crate::models::port::ListeningPort;
 
 // Common traits implementation macro
 macro_rules! impl_common_traits {
@@ -68,6 +73,18 @@ impl Default for ServerCategory {
     }
 }
 
+// 메트릭 수집 방식
+#[derive(Debug, Serialize, Deserialize, sqlx::Type, Clone, PartialEq, Default)]
+#[sqlx(type_name = "collection_method")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum CollectionMethod {
+    Local,
+    #[default]
+    Ssh,
+    Agent
+}
+
 // Common traits 구현
 impl_common_traits!(ServerType, {
     Linux => "linux",
@@ -81,6 +98,12 @@ impl_common_traits!(ServerCategory, {
     Container => "container"
 });
 
+impl_common_traits!(CollectionMethod, {
+    Local => "local",
+    Ssh => "ssh",
+    Agent => "agent"
+});
+
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Server {
@@ -96,6 +119,7 @@ pub struct Server {
     #[sqlx(rename = "server_type")]
     pub server_type: ServerType,
     pub category: ServerCategory,
+    pub collection_method: CollectionMethod,
     pub is_online: bool,
     pub last_seen_at: Option<DateTime<Utc>>,
     pub metadata: Option<JsonValue>,
@@ -118,6 +142,7 @@ impl Default for Server {
             description: None,
             server_type: ServerType::default(),
             category: ServerCategory::default(),
+            collection_method: CollectionMethod::default(),
             is_online: false,
             last_seen_at: None,
             metadata: Some(serde_json::json!({})),
@@ -170,6 +195,120 @@ pub struct MetricsSnapshot {
     pub network_tx: i64,
     pub processes: JsonValue,
     pub timestamp: DateTime<Utc>,
+    pub cpu_per_core: JsonValue,
+    pub load_1: Option<f64>,
+    pub load_5: Option<f64>,
+    pub load_15: Option<f64>,
+    pub cpu_user: Option<f64>,
+    pub cpu_system: Option<f64>,
+    pub cpu_iowait: Option<f64>,
+    pub cpu_steal: Option<f64>,
+    pub filesystems: JsonValue,
+    pub network_interfaces: JsonValue,
+    pub disk_io: JsonValue,
+    pub disk_io_util: Option<f64>,
+    pub disk_read_bps: Option<i64>,
+    pub disk_write_bps: Option<i64>,
+    pub memory_total: Option<i64>,
+    pub memory_used: Option<i64>,
+    pub memory_available: Option<i64>,
+    pub memory_cached: Option<i64>,
+    pub memory_buffers: Option<i64>,
+    pub swap_total: Option<i64>,
+    pub swap_used: Option<i64>,
+}
+
+impl MetricsSnapshot {
+    pub fn from_metrics(server_id: &str, metrics: &ServerMetrics) -> Self {
+        Self {
+            id: 0,
+            server_id: server_id.to_string(),
+            cpu_usage: metrics.cpu_usage as f64,
+            memory_usage: metrics.memory_usage as f64,
+            disk_usage: metrics.disk_usage as f64,
+            network_rx: metrics.network_rx as i64,
+            network_tx: metrics.network_tx as i64,
+            processes: serde_json::to_value(&metrics.processes).unwrap_or_default(),
+            timestamp: metrics.timestamp,
+            cpu_per_core: serde_json::to_value(&metrics.cpu_per_core).unwrap_or_default(),
+            load_1: metrics.load_average.map(|l| l.one),
+            load_5: metrics.load_average.map(|l| l.five),
+            load_15: metrics.load_average.map(|l| l.fifteen),
+            cpu_user: metrics.cpu_breakdown.map(|b| b.user as f64),
+            cpu_system: metrics.cpu_breakdown.map(|b| b.system as f64),
+            cpu_iowait: metrics.cpu_breakdown.map(|b| b.iowait as f64),
+            cpu_steal: metrics.cpu_breakdown.map(|b| b.steal as f64),
+            filesystems: serde_json::to_value(&metrics.filesystems).unwrap_or_default(),
+            network_interfaces: serde_json::to_value(&metrics.network_interfaces).unwrap_or_default(),
+            disk_io: serde_json::to_value(&metrics.disk_io).unwrap_or_default(),
+            disk_io_util: metrics.max_disk_io_utilization().map(|u| u as f64),
+            disk_read_bps: (!metrics.disk_io.is_empty())
+                .then(|| metrics.disk_io.iter().map(|d| d.read_bytes_per_sec).sum::<f64>() as i64),
+            disk_write_bps: (!metrics.disk_io.is_empty())
+                .then(|| metrics.disk_io.iter().map(|d| d.write_bytes_per_sec).sum::<f64>() as i64),
+            memory_total: metrics.memory.map(|m| m.total as i64),
+            memory_used: metrics.memory.map(|m| m.used as i64),
+            memory_available: metrics.memory.map(|m| m.available as i64),
+            memory_cached: metrics.memory.map(|m| m.cached as i64),
+            memory_buffers: metrics.memory.map(|m| m.buffers as i64),
+            swap_total: metrics.memory.map(|m| m.swap_total as i64),
+            swap_used: metrics.memory.map(|m| m.swap_used as i64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct IngestToken {
+    pub id: String,
+    pub server_id: String,
+    #[serde(skip_serializing)]
+    pub token_hash: String,
+    pub description: Option<String>,
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+// 서버에서 항상 실행 중이어야 하는 프로세스. pattern 은 이름(또는 명령줄)에 대한 정규식
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ProcessWatch {
+    pub id: String,
+    pub server_id: String,
+    pub name: String,
+    pub pattern: String,
+    pub match_command: bool,
+    pub min_instances: i32,
+    pub max_cpu: Option<f64>,       // 인스턴스별 CPU 상한 (%)
+    pub max_memory: Option<i64>,    // 인스턴스별 RSS 상한 (bytes)
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct EnrollmentToken {
+    pub id: String,
+    #[serde(skip_serializing)]
+    pub token_hash: String,
+    pub description: Option<String>,
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub server_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AuditLog {
+    pub id: i64,
+    pub user_id: Option<String>,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub changes: JsonValue,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -186,6 +325,285 @@ pub struct Alert {
 }
 
 
+// 서버에서 발견된 컨테이너와 가장 최근 메트릭
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Container {
+    pub server_id: String,
+    pub container_id: String,
+    pub name: String,
+    pub image: Option<String>,
+    pub state: String,
+    pub restart_count: Option<i32>,
+    pub first_seen_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub cpu_usage: Option<f64>,
+    pub memory_usage: Option<i64>,
+    pub memory_limit: Option<i64>,
+    pub network_rx: Option<f64>,
+    pub network_tx: Option<f64>,
+}
+
+// container_metrics 의 한 행
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ContainerMetricsSample {
+    pub timestamp: DateTime<Utc>,
+    pub cpu_usage: f64,
+    pub memory_usage: i64,
+    pub memory_limit: Option<i64>,
+    pub network_rx: Option<f64>,
+    pub network_tx: Option<f64>,
+}
+
+// server_inventory_history 의 한 행 - 호스트 정보가 달라진 시점의 전체 스냅샷
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct InventoryChange {
+    pub id: i64,
+    pub collected_at: DateTime<Utc>,
+    pub changed_fields: Vec<String>,
+    pub facts: JsonValue,
+}
+
+// 서버의 마지막 패키지 조회 결과 요약
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ServerPackageStatus {
+    pub server_id: String,
+    pub server_name: String,
+    pub manager: String,
+    pub package_count: i32,
+    pub update_count: i32,
+    pub security_update_count: i32,
+    pub collected_at: DateTime<Utc>,
+}
+
+// 특정 패키지가 설치된 서버
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PackageInstallation {
+    pub server_id: String,
+    pub server_name: String,
+    pub version: String,
+    pub architecture: String,
+}
+
+// server_ports 의 한 행 - 현재 대기 중인 포트
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ServerPort {
+    pub protocol: String,
+    pub address: String,
+    pub port: i32,
+    pub process: Option<String>,
+    pub pid: Option<i32>,
+    pub first_seen_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl From<&ServerPort> for ListeningPort {
+    fn from(port: &ServerPort) -> Self {
+        Self {
+            protocol: port.protocol.clone(),
+            address: port.address.clone(),
+            port: port.port as u16,
+            process: port.process.clone(),
+            pid: port.pid.map(|pid| pid as u32),
+        }
+    }
+}
+
+// 서버별 합성 검사 설정 (HTTP, TCP, DNS). config 는 종류별 설정 JSON
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ServerCheck {
+    pub id: String,
+    pub server_id: String,
+    pub name: String,
+    pub check_type: String,
+    pub config: JsonValue,
+    pub interval_seconds: i32,
+    pub failure_threshold: i32,     // 연속 실패 몇 번에 알릴지
+    pub enabled: bool,
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ServerCheck {
+    pub fn check_config(&self) -> Result<CheckConfig, serde_json::Error> {
+        serde_json::from_value(self.config.clone())
+    }
+}
+
+// check_results 의 한 행
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CheckResult {
+    pub timestamp: DateTime<Utc>,
+    pub success: bool,
+    pub response_time_ms: Option<f64>,
+    pub status_code: Option<i32>,
+    pub message: Option<String>,
+}
+
+// 검사별 가장 최근 결과 - up/down 상태 표시용
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LatestCheckResult {
+    pub check_id: String,
+    pub timestamp: DateTime<Utc>,
+    pub success: bool,
+    pub response_time_ms: Option<f64>,
+    pub message: Option<String>,
+}
+
+// TLS 검사가 마지막으로 받은 인증서
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CheckCertificate {
+    pub check_id: String,
+    pub subject: String,
+    pub issuer: String,
+    pub sans: Vec<String>,
+    pub serial: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub chain_valid: bool,
+    pub chain_error: Option<String>,
+    pub checked_at: DateTime<Utc>,
+}
+
+// custom_metrics 의 한 행 - 명령 검사의 성능 데이터
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CustomMetric {
+    pub label: String,
+    pub timestamp: DateTime<Utc>,
+    pub value: f64,
+    pub unit: Option<String>,
+    pub warn: Option<String>,
+    pub crit: Option<String>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomMetricPoint {
+    pub timestamp: DateTime<Utc>,
+    pub value: f64,
+}
+
+// 라벨별 시계열. 단위와 임계값은 가장 최근 값
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomMetricSeries {
+    pub label: String,
+    pub unit: Option<String>,
+    pub warn: Option<String>,
+    pub crit: Option<String>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub points: Vec<CustomMetricPoint>,
+}
+
+impl CustomMetricSeries {
+    // 라벨, 시간 순으로 정렬된 행을 묶는다
+    pub fn group(rows: Vec<CustomMetric>) -> Vec<Self> {
+        let mut series: Vec<Self> = Vec::new();
+        for row in rows {
+            let point = CustomMetricPoint { timestamp: row.timestamp, value: row.value };
+            match series.last_mut() {
+                Some(last) if last.label == row.label => {
+                    last.unit = row.unit;
+                    last.warn = row.warn;
+                    last.crit = row.crit;
+                    last.min = row.min;
+                    last.max = row.max;
+                    last.points.push(point);
+                }
+                _ => series.push(Self {
+                    label: row.label,
+                    unit: row.unit,
+                    warn: row.warn,
+                    crit: row.crit,
+                    min: row.min,
+                    max: row.max,
+                    points: vec![point],
+                }),
+            }
+        }
+        series
+    }
+}
+
+// 기간 내 검사 성공률 (%)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckAvailability {
+    pub total_checks: i64,
+    pub successful_checks: i64,
+    pub availability: Option<f64>,      // 결과가 없으면 None
+    pub avg_response_time_ms: Option<f64>,
+}
+
+impl CheckAvailability {
+    pub fn new(total_checks: i64, successful_checks: i64, avg_response_time_ms: Option<f64>) -> Self {
+        Self {
+            total_checks,
+            successful_checks,
+            availability: (total_checks > 0)
+                .then(|| successful_checks as f64 / total_checks as f64 * 100.0),
+            avg_response_time_ms,
+        }
+    }
+}
+
+// SSH 로 따라 읽는 로그 파일. inode/position 은 마지막으로 읽은 위치
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LogSource {
+    pub id: String,
+    pub server_id: String,
+    pub path: String,
+    pub component: String,
+    pub level_rules: JsonValue,     // [{"pattern": "...", "level": "..."}], 비어 있으면 기본 규칙
+    pub default_level: LogLevel,
+    pub enabled: bool,
+    pub inode: Option<i64>,
+    pub position: i64,
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LogSource {
+    pub fn level_rules(&self) -> Result<Vec<LevelRule>, serde_json::Error> {
+        serde_json::from_value(self.level_rules.clone())
+    }
+}
+
+// server_port_changes 의 한 행
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PortChange {
+    pub id: i64,
+    pub protocol: String,
+    pub address: String,
+    pub port: i32,
+    pub process: Option<String>,
+    pub change: String,           // opened | closed
+    pub detected_at: DateTime<Utc>,
+}
+
+// process_samples 의 한 행 - 특정 프로세스의 시점별 사용량
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ProcessSample {
+    pub timestamp: DateTime<Utc>,
+    pub pid: i32,
+    pub name: String,
+    pub cpu_usage: f64,
+    pub memory_usage: i64,
+}
+
+// 기간 내 프로세스별 사용량 집계
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ProcessUsage {
+    pub pid: i32,
+    pub name: String,
+    pub avg_cpu: f64,
+    pub max_cpu: f64,
+    pub avg_memory: i64,
+    pub max_memory: i64,
+    pub samples: i64,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+}
+
 #[derive(Debug, Serialize, Deserialize, sqlx::Type, Clone, PartialEq)]
 #[sqlx(type_name = "auth_provider")]
 #[sqlx(rename_all = "lowercase")]
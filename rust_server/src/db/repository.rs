@@ -9,6 +9,13 @@ use sqlx::{Row, QueryBuilder};
 use tracing::debug;
 //use std::str::FromStr;
 use crate::api::servers::ResourceHistory;
+use crate::models::agent::HostFacts;
+use crate::models::check::{CertificateInfo, CheckOutcome};
+use crate::models::container::ContainerMetrics;
+use crate::models::package::{InstalledPackage, PackageInventory, PackageUpdate};
+use crate::models::plugin::PerfData;
+use crate::models::port::ListeningPort;
+use crate::models::metrics::{ProcessMetrics, ServerMetrics};
 
 #[derive(Clone)]
 pub struct Repository {
@@ -27,15 +34,16 @@ impl Repository {
             r#"
             INSERT INTO servers 
             (id, name, hostname, ip_address, port, username, encrypted_password,
-             location, description, server_type, server_category, is_online, last_seen_at, 
+             location, description, server_type, server_category, collection_method, is_online, last_seen_at, 
              metadata, created_by, created_at, updated_at)
             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10::text::server_type, 
-                    $11::text::server_category, $12, $13, $14, $15, $16, $17)
+                    $11::text::server_category, $12::text::collection_method, $13, $14, $15, $16, $17, $18)
             RETURNING 
                 id, name, hostname, ip_address, port, username, encrypted_password,
                 location, description,
                 server_type as "server_type: ServerType",
                 server_category as "category: ServerCategory",
+                collection_method as "collection_method: CollectionMethod",
                 is_online, last_seen_at, metadata, created_by, created_at, updated_at
             "#,
             server.id,
@@ -49,6 +57,7 @@ impl Repository {
             server.description,
             server.server_type.to_string(),
             server.category.to_string(),
+            server.collection_method.to_string(),
             server.is_online,
             server.last_seen_at,
             server.metadata,
@@ -72,6 +81,7 @@ impl Repository {
                 location, description,
                 server_type as "server_type: ServerType",
                 server_category as "category: ServerCategory",
+                collection_method as "collection_method: CollectionMethod",
                 is_online, last_seen_at, metadata, created_by, created_at, updated_at
             FROM servers 
             WHERE id = $1
@@ -93,6 +103,7 @@ impl Repository {
                 location, description,
                 server_type as "server_type: ServerType",
                 server_category as "category: ServerCategory",
+                collection_method as "collection_method: CollectionMethod",
                 is_online, last_seen_at, metadata, created_by, created_at, updated_at
             FROM servers 
             ORDER BY created_at DESC
@@ -113,6 +124,7 @@ impl Repository {
                 location, description,
                 server_type as "server_type: ServerType",
                 server_category as "category: ServerCategory",
+                collection_method as "collection_method: CollectionMethod",
                 is_online, last_seen_at, metadata, created_by, created_at, updated_at
             FROM servers 
             WHERE created_by = $1
@@ -133,6 +145,7 @@ impl Repository {
                 location, description,
                 server_type as "server_type: ServerType",
                 server_category as "category: ServerCategory",
+                collection_method as "collection_method: CollectionMethod",
                 is_online, last_seen_at, metadata, created_by, created_at, updated_at
             FROM servers 
             WHERE LOWER(hostname) = LOWER($1)
@@ -145,13 +158,1258 @@ impl Repository {
         Ok(result)
     }
 
+    // 같은 주소의 서버가 여럿이면 먼저 등록된 서버
+    pub async fn get_server_by_ip_address(&self, ip_address: &str) -> Result<Option<Server>> {
+        let result = sqlx::query_as!(
+            Server,
+            r#"
+            SELECT 
+                id, name, hostname, ip_address, port, username, encrypted_password,
+                location, description,
+                server_type as "server_type: ServerType",
+                server_category as "category: ServerCategory",
+                collection_method as "collection_method: CollectionMethod",
+                is_online, last_seen_at, metadata, created_by, created_at, updated_at
+            FROM servers 
+            WHERE ip_address = $1
+            ORDER BY created_at ASC
+            LIMIT 1
+            "#,
+            ip_address
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
     pub async fn delete_server(&self, id: &str) -> Result<()> {
         sqlx::query!(
             r#"
-            DELETE FROM servers
-            WHERE id = $1
+            DELETE FROM servers
+            WHERE id = $1
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn create_initial_metrics(&self, server_id: &str) -> Result<()> {
+        let initial_snapshot = MetricsSnapshot::from_metrics(
+            server_id,
+            &ServerMetrics::new(0.0, 0.0, 0.0, 0, 0, Vec::new()),
+        );
+    
+        self.save_metrics(initial_snapshot).await?;
+        Ok(())
+    }
+    pub async fn save_metrics(&self, snapshot: MetricsSnapshot) -> Result<i64> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO metrics_snapshots 
+            (server_id, cpu_usage, memory_usage, disk_usage, network_rx, network_tx, processes, timestamp,
+             cpu_per_core, load_1, load_5, load_15, cpu_user, cpu_system, cpu_iowait, cpu_steal,
+             filesystems, network_interfaces, disk_io, disk_io_util, disk_read_bps, disk_write_bps,
+             memory_total, memory_used, memory_available, memory_cached, memory_buffers, swap_total, swap_used)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
+                    $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29)
+            RETURNING id
+            "#,
+            snapshot.server_id,
+            snapshot.cpu_usage,
+            snapshot.memory_usage,
+            snapshot.disk_usage,
+            snapshot.network_rx,
+            snapshot.network_tx,
+            snapshot.processes as JsonValue,
+            snapshot.timestamp,
+            snapshot.cpu_per_core as JsonValue,
+            snapshot.load_1,
+            snapshot.load_5,
+            snapshot.load_15,
+            snapshot.cpu_user,
+            snapshot.cpu_system,
+            snapshot.cpu_iowait,
+            snapshot.cpu_steal,
+            snapshot.filesystems as JsonValue,
+            snapshot.network_interfaces as JsonValue,
+            snapshot.disk_io as JsonValue,
+            snapshot.disk_io_util,
+            snapshot.disk_read_bps,
+            snapshot.disk_write_bps,
+            snapshot.memory_total,
+            snapshot.memory_used,
+            snapshot.memory_available,
+            snapshot.memory_cached,
+            snapshot.memory_buffers,
+            snapshot.swap_total,
+            snapshot.swap_used
+        )
+        .fetch_one(&self.pool)
+        .await?;
+    
+        Ok(result.id)
+    }
+
+
+    pub async fn get_latest_metrics_timestamp(&self, server_id: &str) -> Result<Option<DateTime<Utc>>> {
+        let result = sqlx::query!(
+            r#"
+            SELECT MAX(timestamp) as latest
+            FROM metrics_snapshots
+            WHERE server_id = $1
+            "#,
+            server_id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result.latest)
+    }
+
+    pub async fn mark_server_seen(&self, id: &str, seen_at: DateTime<Utc>) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE servers
+            SET is_online = true,
+                last_seen_at = GREATEST(COALESCE(last_seen_at, $1), $1),
+                updated_at = $2
+            WHERE id = $3
+            "#,
+            seen_at,
+            Utc::now(),
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn create_ingest_token(&self, token: IngestToken) -> Result<IngestToken> {
+        let result = sqlx::query_as!(
+            IngestToken,
+            r#"
+            INSERT INTO server_ingest_tokens
+            (id, server_id, token_hash, description, created_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, server_id, token_hash, description, created_by,
+                      created_at, last_used_at, revoked_at
+            "#,
+            token.id,
+            token.server_id,
+            token.token_hash,
+            token.description,
+            token.created_by,
+            token.created_at
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_ingest_token(&self, id: &str) -> Result<Option<IngestToken>> {
+        let result = sqlx::query_as!(
+            IngestToken,
+            r#"
+            SELECT id, server_id, token_hash, description, created_by,
+                   created_at, last_used_at, revoked_at
+            FROM server_ingest_tokens
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn list_ingest_tokens(&self, server_id: &str) -> Result<Vec<IngestToken>> {
+        let results = sqlx::query_as!(
+            IngestToken,
+            r#"
+            SELECT id, server_id, token_hash, description, created_by,
+                   created_at, last_used_at, revoked_at
+            FROM server_ingest_tokens
+            WHERE server_id = $1
+            ORDER BY created_at DESC
+            "#,
+            server_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    pub async fn touch_ingest_token(&self, id: &str) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE server_ingest_tokens
+            SET last_used_at = $1
+            WHERE id = $2
+            "#,
+            Utc::now(),
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn revoke_ingest_token(&self, server_id: &str, id: &str) -> Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE server_ingest_tokens
+            SET revoked_at = $1
+            WHERE id = $2 AND server_id = $3 AND revoked_at IS NULL
+            "#,
+            Utc::now(),
+            id,
+            server_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn create_enrollment_token(&self, token: EnrollmentToken) -> Result<EnrollmentToken> {
+        let result = sqlx::query_as!(
+            EnrollmentToken,
+            r#"
+            INSERT INTO agent_enrollment_tokens
+            (id, token_hash, description, created_by, created_at, expires_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, token_hash, description, created_by, created_at,
+                      expires_at, used_at, server_id
+            "#,
+            token.id,
+            token.token_hash,
+            token.description,
+            token.created_by,
+            token.created_at,
+            token.expires_at
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_enrollment_token(&self, id: &str) -> Result<Option<EnrollmentToken>> {
+        let result = sqlx::query_as!(
+            EnrollmentToken,
+            r#"
+            SELECT id, token_hash, description, created_by, created_at,
+                   expires_at, used_at, server_id
+            FROM agent_enrollment_tokens
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    // 사용되지 않았고 만료 전인 토큰만 사용 처리 (동시 등록 방지)
+    pub async fn consume_enrollment_token(&self, id: &str, server_id: &str) -> Result<bool> {
+        let now = Utc::now();
+        let result = sqlx::query!(
+            r#"
+            UPDATE agent_enrollment_tokens
+            SET used_at = $1, server_id = $2
+            WHERE id = $3 AND used_at IS NULL AND expires_at > $1
+            "#,
+            now,
+            server_id,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn create_audit_log(&self, log: AuditLog) -> Result<AuditLog> {
+        let result = sqlx::query_as!(
+            AuditLog,
+            r#"
+            INSERT INTO audit_logs
+            (user_id, action, entity_type, entity_id, changes, ip_address, user_agent, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id, user_id, action, entity_type, entity_id, changes,
+                      ip_address, user_agent, created_at
+            "#,
+            log.user_id,
+            log.action,
+            log.entity_type,
+            log.entity_id,
+            log.changes,
+            log.ip_address,
+            log.user_agent,
+            log.created_at
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_server_metrics_history(
+        &self, 
+        server_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<Vec<ResourceHistory>> {
+        let metrics = sqlx::query!(
+            r#"
+            WITH time_series AS (
+                SELECT generate_series($2, $3, '1 minute'::interval) as ts
+            ),
+            metrics_with_intervals AS (
+                SELECT 
+                    ts,
+                    cpu_usage,
+                    memory_usage,
+                    disk_usage,
+                    network_rx + network_tx as network_total,
+                    disk_io_util,
+                    CASE WHEN swap_total > 0 THEN swap_used::float8 / swap_total * 100.0 END as swap_usage
+                FROM time_series
+                LEFT JOIN metrics_snapshots ms 
+                    ON ms.server_id = $1 
+                    AND ms.timestamp <= ts 
+                    AND ms.timestamp > ts - '1 minute'::interval
+                ORDER BY ts DESC
+            )
+            SELECT 
+                ts as "timestamp!",
+                COALESCE(cpu_usage, 0.0) as "cpu!: f64",
+                COALESCE(memory_usage, 0.0) as "memory!: f64",
+                COALESCE(disk_usage, 0.0) as "disk!: f64",
+                COALESCE(network_total, 0) as "network!: i64",
+                COALESCE(disk_io_util, 0.0) as "disk_io!: f64",
+                COALESCE(swap_usage, 0.0) as "swap!: f64"
+            FROM metrics_with_intervals
+            WHERE ts >= $2 AND ts <= $3
+            ORDER BY ts ASC
+            "#,
+            server_id,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(metrics.into_iter().map(|row| ResourceHistory {
+            timestamp: row.timestamp,
+            cpu: row.cpu,
+            memory: row.memory,
+            disk: row.disk,
+            network: format!("{} B/s", row.network),
+            disk_io: row.disk_io,
+            swap: row.swap,
+        }).collect())
+    }
+
+    pub async fn get_recent_server_logs(&self, server_id: &str, limit: i64) -> Result<Vec<LogEntry>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT 
+                id,
+                level as "level!: LogLevel",
+                message,
+                component,
+                server_id,
+                timestamp,
+                metadata as "metadata?: JsonValue",
+                stack_trace,
+                source_location,
+                correlation_id
+            FROM logs 
+            WHERE server_id = $1 
+            ORDER BY timestamp DESC 
+            LIMIT $2
+            "#,
+            server_id,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+    
+        Ok(rows.into_iter().map(|row| LogEntry {
+            id: row.id,
+            level: row.level,
+            message: row.message,
+            component: row.component,
+            server_id: row.server_id,
+            timestamp: row.timestamp,
+            metadata: row.metadata.into(), 
+            stack_trace: row.stack_trace,
+            source_location: row.source_location,
+            correlation_id: row.correlation_id,
+        }).collect())
+    }
+
+    pub async fn get_server_metrics(
+        &self,
+        server_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<Vec<MetricsSnapshot>> {
+        let metrics = sqlx::query_as!(
+            MetricsSnapshot,
+            r#"
+            SELECT 
+                id,
+                server_id,
+                cpu_usage,
+                memory_usage,
+                disk_usage,
+                network_rx,
+                network_tx,
+                processes,
+                timestamp,
+                cpu_per_core,
+                load_1,
+                load_5,
+                load_15,
+                cpu_user,
+                cpu_system,
+                cpu_iowait,
+                cpu_steal,
+                filesystems,
+                network_interfaces,
+                disk_io,
+                disk_io_util,
+                disk_read_bps,
+                disk_write_bps,
+                memory_total,
+                memory_used,
+                memory_available,
+                memory_cached,
+                memory_buffers,
+                swap_total,
+                swap_used
+            FROM metrics_snapshots
+            WHERE server_id = $1 
+            AND timestamp BETWEEN $2 AND $3
+            ORDER BY timestamp ASC
+            "#,
+            server_id,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(metrics)
+    }
+
+    pub async fn list_service_units(&self, server_id: &str) -> Result<Vec<String>> {
+        let units = sqlx::query_scalar!(
+            r#"
+            SELECT unit
+            FROM server_services
+            WHERE server_id = $1
+            ORDER BY unit ASC
+            "#,
+            server_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(units)
+    }
+
+    // 유닛 목록을 통째로 교체
+    pub async fn set_service_units(&self, server_id: &str, units: &[String]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM server_services
+            WHERE server_id = $1
+            "#,
+            server_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO server_services (server_id, unit)
+            SELECT $1, * FROM UNNEST($2::text[])
+            ON CONFLICT DO NOTHING
+            "#,
+            server_id,
+            units
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    // 컨테이너 목록을 갱신하고 메트릭을 한 번에 저장
+    pub async fn save_containers(
+        &self,
+        server_id: &str,
+        timestamp: DateTime<Utc>,
+        containers: &[ContainerMetrics],
+    ) -> Result<()> {
+        if containers.is_empty() {
+            return Ok(());
+        }
+
+        let ids: Vec<String> = containers.iter().map(|c| c.id.clone()).collect();
+        let names: Vec<String> = containers.iter().map(|c| c.name.clone()).collect();
+        let images: Vec<Option<String>> = containers.iter().map(|c| c.image.clone()).collect();
+        let states: Vec<String> = containers.iter().map(|c| c.state.clone()).collect();
+        let restarts: Vec<Option<i32>> = containers.iter().map(|c| c.restart_count.map(|n| n as i32)).collect();
+        let cpu: Vec<f64> = containers.iter().map(|c| c.cpu_usage as f64).collect();
+        let memory: Vec<i64> = containers.iter().map(|c| c.memory_usage as i64).collect();
+        let limits: Vec<Option<i64>> = containers.iter().map(|c| c.memory_limit.map(|l| l as i64)).collect();
+        let rx_rates: Vec<Option<f64>> = containers.iter().map(|c| c.network_rx).collect();
+        let tx_rates: Vec<Option<f64>> = containers.iter().map(|c| c.network_tx).collect();
+
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO containers
+            (server_id, container_id, name, image, state, restart_count, first_seen_at, last_seen_at)
+            SELECT $1, c.id, c.name, c.image, c.state, c.restart_count, $2, $2
+            FROM UNNEST($3::text[], $4::text[], $5::text[], $6::text[], $7::int4[])
+                AS c(id, name, image, state, restart_count)
+            ON CONFLICT (server_id, container_id) DO UPDATE SET
+                name = EXCLUDED.name,
+                image = EXCLUDED.image,
+                state = EXCLUDED.state,
+                restart_count = EXCLUDED.restart_count,
+                last_seen_at = EXCLUDED.last_seen_at
+            "#,
+            server_id,
+            timestamp,
+            &ids,
+            &names,
+            &images as &[Option<String>],
+            &states,
+            &restarts as &[Option<i32>]
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO container_metrics
+            (server_id, container_id, timestamp, cpu_usage, memory_usage, memory_limit, network_rx, network_tx)
+            SELECT $1, c.id, $2, c.cpu_usage, c.memory_usage, c.memory_limit, c.network_rx, c.network_tx
+            FROM UNNEST($3::text[], $4::float8[], $5::int8[], $6::int8[], $7::float8[], $8::float8[])
+                AS c(id, cpu_usage, memory_usage, memory_limit, network_rx, network_tx)
+            "#,
+            server_id,
+            timestamp,
+            &ids,
+            &cpu,
+            &memory,
+            &limits as &[Option<i64>],
+            &rx_rates as &[Option<f64>],
+            &tx_rates as &[Option<f64>]
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn list_containers(&self, server_id: &str) -> Result<Vec<Container>> {
+        let results = sqlx::query_as!(
+            Container,
+            r#"
+            SELECT
+                c.server_id, c.container_id, c.name, c.image, c.state, c.restart_count,
+                c.first_seen_at, c.last_seen_at,
+                m.cpu_usage as "cpu_usage?", m.memory_usage as "memory_usage?",
+                m.memory_limit, m.network_rx, m.network_tx
+            FROM containers c
+            LEFT JOIN LATERAL (
+                SELECT cpu_usage, memory_usage, memory_limit, network_rx, network_tx
+                FROM container_metrics
+                WHERE server_id = c.server_id AND container_id = c.container_id
+                ORDER BY timestamp DESC
+                LIMIT 1
+            ) m ON true
+            WHERE c.server_id = $1
+            ORDER BY c.name ASC
+            "#,
+            server_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    pub async fn get_container_metrics(
+        &self,
+        server_id: &str,
+        container_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ContainerMetricsSample>> {
+        let results = sqlx::query_as!(
+            ContainerMetricsSample,
+            r#"
+            SELECT timestamp, cpu_usage, memory_usage, memory_limit, network_rx, network_tx
+            FROM container_metrics
+            WHERE server_id = $1
+            AND container_id = $2
+            AND timestamp BETWEEN $3 AND $4
+            ORDER BY timestamp ASC
+            "#,
+            server_id,
+            container_id,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    // servers.metadata 의 facts 를 교체하고 ip_address, server_type 을 갱신.
+    // 달라진 항목이 있으면 이력을 남긴다
+    pub async fn save_host_facts(
+        &self,
+        server_id: &str,
+        facts: &HostFacts,
+        changed_fields: &[String],
+        collected_at: DateTime<Utc>,
+    ) -> Result<()> {
+        let facts_json = serde_json::to_value(facts)?;
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE servers
+            SET metadata = COALESCE(metadata, '{}'::jsonb)
+                    || jsonb_build_object('facts', $1::jsonb, 'facts_collected_at', $2::timestamptz),
+                ip_address = COALESCE($3, ip_address),
+                server_type = $4::text::server_type,
+                updated_at = $5
+            WHERE id = $6
+            "#,
+            facts_json,
+            collected_at,
+            facts.primary_ip(),
+            facts.server_type().to_string(),
+            Utc::now(),
+            server_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if !changed_fields.is_empty() {
+            sqlx::query!(
+                r#"
+                INSERT INTO server_inventory_history (server_id, collected_at, changed_fields, facts)
+                VALUES ($1, $2, $3, $4)
+                "#,
+                server_id,
+                collected_at,
+                changed_fields,
+                facts_json
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_inventory_history(&self, server_id: &str, limit: i64) -> Result<Vec<InventoryChange>> {
+        let results = sqlx::query_as!(
+            InventoryChange,
+            r#"
+            SELECT id, collected_at, changed_fields, facts
+            FROM server_inventory_history
+            WHERE server_id = $1
+            ORDER BY collected_at DESC
+            LIMIT $2
+            "#,
+            server_id,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    // 패키지 목록과 업데이트를 통째로 교체하고 요약을 갱신
+    pub async fn save_packages(
+        &self,
+        server_id: &str,
+        inventory: &PackageInventory,
+        collected_at: DateTime<Utc>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM server_packages
+            WHERE server_id = $1
+            "#,
+            server_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let names: Vec<String> = inventory.packages.iter().map(|p| p.name.clone()).collect();
+        let versions: Vec<String> = inventory.packages.iter().map(|p| p.version.clone()).collect();
+        let architectures: Vec<String> = inventory.packages.iter().map(|p| p.architecture.clone()).collect();
+        sqlx::query!(
+            r#"
+            INSERT INTO server_packages (server_id, name, version, architecture)
+            SELECT $1, * FROM UNNEST($2::text[], $3::text[], $4::text[])
+            ON CONFLICT DO NOTHING
+            "#,
+            server_id,
+            &names,
+            &versions,
+            &architectures
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM server_package_updates
+            WHERE server_id = $1
+            "#,
+            server_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let update_names: Vec<String> = inventory.updates.iter().map(|u| u.name.clone()).collect();
+        let current_versions: Vec<Option<String>> = inventory.updates.iter().map(|u| u.current_version.clone()).collect();
+        let available_versions: Vec<String> = inventory.updates.iter().map(|u| u.available_version.clone()).collect();
+        let security: Vec<bool> = inventory.updates.iter().map(|u| u.security).collect();
+        sqlx::query!(
+            r#"
+            INSERT INTO server_package_updates (server_id, name, current_version, available_version, security)
+            SELECT $1, * FROM UNNEST($2::text[], $3::text[], $4::text[], $5::bool[])
+            ON CONFLICT DO NOTHING
+            "#,
+            server_id,
+            &update_names,
+            &current_versions as &[Option<String>],
+            &available_versions,
+            &security
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO server_package_status
+                (server_id, manager, package_count, update_count, security_update_count, collected_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (server_id) DO UPDATE
+            SET manager = EXCLUDED.manager,
+                package_count = EXCLUDED.package_count,
+                update_count = EXCLUDED.update_count,
+                security_update_count = EXCLUDED.security_update_count,
+                collected_at = EXCLUDED.collected_at
+            "#,
+            server_id,
+            inventory.manager,
+            inventory.packages.len() as i32,
+            inventory.updates.len() as i32,
+            inventory.security_update_count() as i32,
+            collected_at
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_package_status(&self, server_id: &str) -> Result<Option<ServerPackageStatus>> {
+        let result = sqlx::query_as!(
+            ServerPackageStatus,
+            r#"
+            SELECT ps.server_id, s.name as server_name, ps.manager, ps.package_count,
+                   ps.update_count, ps.security_update_count, ps.collected_at
+            FROM server_package_status ps
+            JOIN servers s ON s.id = ps.server_id
+            WHERE ps.server_id = $1
+            "#,
+            server_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    // name 을 주면 이름에 포함된 패키지만
+    pub async fn get_server_packages(&self, server_id: &str, name: Option<&str>) -> Result<Vec<InstalledPackage>> {
+        let results = sqlx::query_as!(
+            InstalledPackage,
+            r#"
+            SELECT name, version, architecture
+            FROM server_packages
+            WHERE server_id = $1
+            AND ($2::text IS NULL OR name ILIKE '%' || $2 || '%')
+            ORDER BY name ASC, architecture ASC
+            "#,
+            server_id,
+            name
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    pub async fn get_package_updates(&self, server_id: &str, security_only: bool) -> Result<Vec<PackageUpdate>> {
+        let results = sqlx::query_as!(
+            PackageUpdate,
+            r#"
+            SELECT name, current_version, available_version, security
+            FROM server_package_updates
+            WHERE server_id = $1
+            AND (NOT $2 OR security)
+            ORDER BY security DESC, name ASC
+            "#,
+            server_id,
+            security_only
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    // 패키지가 설치된 서버. owner 를 주면 그 사용자가 만든 서버만
+    pub async fn find_package_installations(
+        &self,
+        name: &str,
+        owner: Option<&str>,
+    ) -> Result<Vec<PackageInstallation>> {
+        let results = sqlx::query_as!(
+            PackageInstallation,
+            r#"
+            SELECT p.server_id, s.name as server_name, p.version, p.architecture
+            FROM server_packages p
+            JOIN servers s ON s.id = p.server_id
+            WHERE p.name = $1
+            AND ($2::text IS NULL OR s.created_by = $2)
+            ORDER BY s.name ASC
+            "#,
+            name,
+            owner
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    // 보안 업데이트가 min_count 개 이상 대기 중인 서버 (많은 순)
+    pub async fn list_servers_with_security_updates(
+        &self,
+        min_count: i32,
+        owner: Option<&str>,
+    ) -> Result<Vec<ServerPackageStatus>> {
+        let results = sqlx::query_as!(
+            ServerPackageStatus,
+            r#"
+            SELECT ps.server_id, s.name as server_name, ps.manager, ps.package_count,
+                   ps.update_count, ps.security_update_count, ps.collected_at
+            FROM server_package_status ps
+            JOIN servers s ON s.id = ps.server_id
+            WHERE ps.security_update_count >= $1
+            AND ($2::text IS NULL OR s.created_by = $2)
+            ORDER BY ps.security_update_count DESC, s.name ASC
+            "#,
+            min_count,
+            owner
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    pub async fn list_listening_ports(&self, server_id: &str) -> Result<Vec<ServerPort>> {
+        let results = sqlx::query_as!(
+            ServerPort,
+            r#"
+            SELECT protocol, address, port, process, pid, first_seen_at, last_seen_at
+            FROM server_ports
+            WHERE server_id = $1
+            ORDER BY port ASC, protocol ASC, address ASC
+            "#,
+            server_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    // 현재 포트 목록으로 교체하고 열림/닫힘을 이력으로 남긴다
+    pub async fn save_listening_ports(
+        &self,
+        server_id: &str,
+        ports: &[ListeningPort],
+        opened: &[ListeningPort],
+        closed: &[ListeningPort],
+        seen_at: DateTime<Utc>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let protocols: Vec<String> = ports.iter().map(|p| p.protocol.clone()).collect();
+        let addresses: Vec<String> = ports.iter().map(|p| p.address.clone()).collect();
+        let numbers: Vec<i32> = ports.iter().map(|p| p.port as i32).collect();
+        let processes: Vec<Option<String>> = ports.iter().map(|p| p.process.clone()).collect();
+        let pids: Vec<Option<i32>> = ports.iter().map(|p| p.pid.map(|pid| pid as i32)).collect();
+        sqlx::query!(
+            r#"
+            INSERT INTO server_ports
+                (server_id, protocol, address, port, process, pid, first_seen_at, last_seen_at)
+            SELECT $1, u.*, $7, $7
+            FROM UNNEST($2::text[], $3::text[], $4::int[], $5::text[], $6::int[]) AS u
+            ON CONFLICT (server_id, protocol, address, port) DO UPDATE
+            SET process = EXCLUDED.process,
+                pid = EXCLUDED.pid,
+                last_seen_at = EXCLUDED.last_seen_at
+            "#,
+            server_id,
+            &protocols,
+            &addresses,
+            &numbers,
+            &processes as &[Option<String>],
+            &pids as &[Option<i32>],
+            seen_at
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM server_ports
+            WHERE server_id = $1 AND last_seen_at < $2
+            "#,
+            server_id,
+            seen_at
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let changes: Vec<(&ListeningPort, &str)> = opened
+            .iter()
+            .map(|p| (p, "opened"))
+            .chain(closed.iter().map(|p| (p, "closed")))
+            .collect();
+        if !changes.is_empty() {
+            let protocols: Vec<String> = changes.iter().map(|(p, _)| p.protocol.clone()).collect();
+            let addresses: Vec<String> = changes.iter().map(|(p, _)| p.address.clone()).collect();
+            let numbers: Vec<i32> = changes.iter().map(|(p, _)| p.port as i32).collect();
+            let processes: Vec<Option<String>> = changes.iter().map(|(p, _)| p.process.clone()).collect();
+            let kinds: Vec<String> = changes.iter().map(|(_, change)| change.to_string()).collect();
+            sqlx::query!(
+                r#"
+                INSERT INTO server_port_changes (server_id, protocol, address, port, process, change, detected_at)
+                SELECT $1, u.*, $7
+                FROM UNNEST($2::text[], $3::text[], $4::int[], $5::text[], $6::text[]) AS u
+                "#,
+                server_id,
+                &protocols,
+                &addresses,
+                &numbers,
+                &processes as &[Option<String>],
+                &kinds,
+                seen_at
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_port_changes(&self, server_id: &str, limit: i64) -> Result<Vec<PortChange>> {
+        let results = sqlx::query_as!(
+            PortChange,
+            r#"
+            SELECT id, protocol, address, port, process, change, detected_at
+            FROM server_port_changes
+            WHERE server_id = $1
+            ORDER BY detected_at DESC, id DESC
+            LIMIT $2
+            "#,
+            server_id,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    pub async fn create_check(&self, check: ServerCheck) -> Result<ServerCheck> {
+        let result = sqlx::query_as!(
+            ServerCheck,
+            r#"
+            INSERT INTO checks
+            (id, server_id, name, check_type, config, interval_seconds,
+             failure_threshold, enabled, created_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id, server_id, name, check_type, config, interval_seconds,
+                      failure_threshold, enabled, created_by, created_at
+            "#,
+            check.id,
+            check.server_id,
+            check.name,
+            check.check_type,
+            check.config,
+            check.interval_seconds,
+            check.failure_threshold,
+            check.enabled,
+            check.created_by,
+            check.created_at
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_check(&self, server_id: &str, id: &str) -> Result<Option<ServerCheck>> {
+        let result = sqlx::query_as!(
+            ServerCheck,
+            r#"
+            SELECT id, server_id, name, check_type, config, interval_seconds,
+                   failure_threshold, enabled, created_by, created_at
+            FROM checks
+            WHERE id = $1 AND server_id = $2
+            "#,
+            id,
+            server_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn list_checks(&self, server_id: &str) -> Result<Vec<ServerCheck>> {
+        let results = sqlx::query_as!(
+            ServerCheck,
+            r#"
+            SELECT id, server_id, name, check_type, config, interval_seconds,
+                   failure_threshold, enabled, created_by, created_at
+            FROM checks
+            WHERE server_id = $1
+            ORDER BY created_at ASC
+            "#,
+            server_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    // 시작 시 스케줄러에 올릴 검사
+    pub async fn list_enabled_checks(&self) -> Result<Vec<ServerCheck>> {
+        let results = sqlx::query_as!(
+            ServerCheck,
+            r#"
+            SELECT id, server_id, name, check_type, config, interval_seconds,
+                   failure_threshold, enabled, created_by, created_at
+            FROM checks
+            WHERE enabled = TRUE
+            ORDER BY created_at ASC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    // 서버의 검사별 가장 최근 결과
+    pub async fn list_latest_check_results(&self, server_id: &str) -> Result<Vec<LatestCheckResult>> {
+        let results = sqlx::query_as!(
+            LatestCheckResult,
+            r#"
+            SELECT DISTINCT ON (r.check_id)
+                r.check_id, r.timestamp, r.success, r.response_time_ms, r.message
+            FROM check_results r
+            JOIN checks c ON c.id = r.check_id
+            WHERE c.server_id = $1
+            ORDER BY r.check_id, r.timestamp DESC
+            "#,
+            server_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    pub async fn delete_check(&self, server_id: &str, id: &str) -> Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM checks
+            WHERE id = $1 AND server_id = $2
+            "#,
+            id,
+            server_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn save_check_result(
+        &self,
+        check_id: &str,
+        timestamp: DateTime<Utc>,
+        outcome: &CheckOutcome,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO check_results
+            (check_id, timestamp, success, response_time_ms, status_code, message)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            check_id,
+            timestamp,
+            outcome.success,
+            outcome.response_time_ms,
+            outcome.status_code.map(|code| code as i32),
+            outcome.message
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // 검사별 한 행만 유지한다
+    pub async fn save_check_certificate(
+        &self,
+        check_id: &str,
+        checked_at: DateTime<Utc>,
+        certificate: &CertificateInfo,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO check_certificates
+            (check_id, subject, issuer, sans, serial, not_before, not_after,
+             chain_valid, chain_error, checked_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (check_id) DO UPDATE SET
+                subject = EXCLUDED.subject,
+                issuer = EXCLUDED.issuer,
+                sans = EXCLUDED.sans,
+                serial = EXCLUDED.serial,
+                not_before = EXCLUDED.not_before,
+                not_after = EXCLUDED.not_after,
+                chain_valid = EXCLUDED.chain_valid,
+                chain_error = EXCLUDED.chain_error,
+                checked_at = EXCLUDED.checked_at
+            "#,
+            check_id,
+            certificate.subject,
+            certificate.issuer,
+            &certificate.sans,
+            certificate.serial,
+            certificate.not_before,
+            certificate.not_after,
+            certificate.chain_valid,
+            certificate.chain_error,
+            checked_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_check_certificate(&self, check_id: &str) -> Result<Option<CheckCertificate>> {
+        let result = sqlx::query_as!(
+            CheckCertificate,
+            r#"
+            SELECT check_id, subject, issuer, sans, serial, not_before, not_after,
+                   chain_valid, chain_error, checked_at
+            FROM check_certificates
+            WHERE check_id = $1
+            "#,
+            check_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn save_custom_metrics(
+        &self,
+        server_id: &str,
+        check_id: &str,
+        timestamp: DateTime<Utc>,
+        perfdata: &[PerfData],
+    ) -> Result<()> {
+        let labels: Vec<String> = perfdata.iter().map(|p| p.label.clone()).collect();
+        let values: Vec<f64> = perfdata.iter().map(|p| p.value).collect();
+        let units: Vec<Option<String>> = perfdata.iter().map(|p| p.unit.clone()).collect();
+        let warns: Vec<Option<String>> = perfdata.iter().map(|p| p.warn.clone()).collect();
+        let crits: Vec<Option<String>> = perfdata.iter().map(|p| p.crit.clone()).collect();
+        let mins: Vec<Option<f64>> = perfdata.iter().map(|p| p.min).collect();
+        let maxes: Vec<Option<f64>> = perfdata.iter().map(|p| p.max).collect();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO custom_metrics
+            (server_id, check_id, timestamp, label, value, unit, warn, crit, min, max)
+            SELECT $1, $2, $3, m.label, m.value, m.unit, m.warn, m.crit, m.min, m.max
+            FROM UNNEST($4::text[], $5::float8[], $6::text[], $7::text[], $8::text[], $9::float8[], $10::float8[])
+                AS m(label, value, unit, warn, crit, min, max)
             "#,
-            id
+            server_id,
+            check_id,
+            timestamp,
+            &labels,
+            &values,
+            &units as &[Option<String>],
+            &warns as &[Option<String>],
+            &crits as &[Option<String>],
+            &mins as &[Option<f64>],
+            &maxes as &[Option<f64>]
         )
         .execute(&self.pool)
         .await?;
@@ -159,168 +1417,255 @@ impl Repository {
         Ok(())
     }
 
-    pub async fn create_initial_metrics(&self, server_id: &str) -> Result<()> {
-        let initial_snapshot = MetricsSnapshot {
-            id: 0,  // Will be set by DB
-            server_id: server_id.to_string(),
-            cpu_usage: 0.0,
-            memory_usage: 0.0,
-            disk_usage: 0.0,
-            network_rx: 0,  // Changed to 0 (i64)
-            network_tx: 0,  // Changed to 0 (i64)
-            processes: serde_json::json!([]),
-            timestamp: Utc::now(),
-        };
-    
-        self.save_metrics(initial_snapshot).await?;
-        Ok(())
-    }
-    pub async fn save_metrics(&self, snapshot: MetricsSnapshot) -> Result<i64> {
-        let result = sqlx::query!(
+    pub async fn get_custom_metrics(
+        &self,
+        check_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<CustomMetric>> {
+        let results = sqlx::query_as!(
+            CustomMetric,
             r#"
-            INSERT INTO metrics_snapshots 
-            (server_id, cpu_usage, memory_usage, disk_usage, network_rx, network_tx, processes, timestamp)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-            RETURNING id
+            SELECT label, timestamp, value, unit, warn, crit, min, max
+            FROM custom_metrics
+            WHERE check_id = $1
+            AND timestamp BETWEEN $2 AND $3
+            ORDER BY label ASC, timestamp ASC
             "#,
-            snapshot.server_id,
-            snapshot.cpu_usage,
-            snapshot.memory_usage,
-            snapshot.disk_usage,
-            snapshot.network_rx,
-            snapshot.network_tx,
-            snapshot.processes as JsonValue,
-            snapshot.timestamp
+            check_id,
+            from,
+            to
         )
-        .fetch_one(&self.pool)
+        .fetch_all(&self.pool)
         .await?;
-    
-        Ok(result.id)
+
+        Ok(results)
     }
 
+    pub async fn get_check_results(
+        &self,
+        check_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<CheckResult>> {
+        let results = sqlx::query_as!(
+            CheckResult,
+            r#"
+            SELECT timestamp, success, response_time_ms, status_code, message
+            FROM check_results
+            WHERE check_id = $1
+            AND timestamp BETWEEN $2 AND $3
+            ORDER BY timestamp ASC
+            "#,
+            check_id,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
 
-    pub async fn get_server_metrics_history(
-        &self, 
-        server_id: &str,
+        Ok(results)
+    }
+
+    pub async fn get_check_availability(
+        &self,
+        check_id: &str,
         from: DateTime<Utc>,
-        to: DateTime<Utc>
-    ) -> Result<Vec<ResourceHistory>> {
-        let metrics = sqlx::query!(
+        to: DateTime<Utc>,
+    ) -> Result<CheckAvailability> {
+        let row = sqlx::query!(
             r#"
-            WITH time_series AS (
-                SELECT generate_series($2, $3, '1 minute'::interval) as ts
-            ),
-            metrics_with_intervals AS (
-                SELECT 
-                    ts,
-                    cpu_usage,
-                    memory_usage,
-                    disk_usage,
-                    network_rx + network_tx as network_total
-                FROM time_series
-                LEFT JOIN metrics_snapshots ms 
-                    ON ms.server_id = $1 
-                    AND ms.timestamp <= ts 
-                    AND ms.timestamp > ts - '1 minute'::interval
-                ORDER BY ts DESC
-            )
-            SELECT 
-                ts as "timestamp!",
-                COALESCE(cpu_usage, 0.0) as "cpu!: f64",
-                COALESCE(memory_usage, 0.0) as "memory!: f64",
-                COALESCE(disk_usage, 0.0) as "disk!: f64",
-                COALESCE(network_total, 0) as "network!: i64"
-            FROM metrics_with_intervals
-            WHERE ts >= $2 AND ts <= $3
-            ORDER BY ts ASC
+            SELECT
+                COUNT(*) as "total!",
+                COUNT(*) FILTER (WHERE success) as "successful!",
+                AVG(response_time_ms) as avg_response_time_ms
+            FROM check_results
+            WHERE check_id = $1
+            AND timestamp BETWEEN $2 AND $3
             "#,
-            server_id,
+            check_id,
             from,
             to
         )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(CheckAvailability::new(row.total, row.successful, row.avg_response_time_ms))
+    }
+
+    pub async fn create_process_watch(&self, watch: ProcessWatch) -> Result<ProcessWatch> {
+        let result = sqlx::query_as!(
+            ProcessWatch,
+            r#"
+            INSERT INTO process_watches
+            (id, server_id, name, pattern, match_command, min_instances,
+             max_cpu, max_memory, created_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id, server_id, name, pattern, match_command, min_instances,
+                      max_cpu, max_memory, created_by, created_at
+            "#,
+            watch.id,
+            watch.server_id,
+            watch.name,
+            watch.pattern,
+            watch.match_command,
+            watch.min_instances,
+            watch.max_cpu,
+            watch.max_memory,
+            watch.created_by,
+            watch.created_at
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn list_process_watches(&self, server_id: &str) -> Result<Vec<ProcessWatch>> {
+        let results = sqlx::query_as!(
+            ProcessWatch,
+            r#"
+            SELECT id, server_id, name, pattern, match_command, min_instances,
+                   max_cpu, max_memory, created_by, created_at
+            FROM process_watches
+            WHERE server_id = $1
+            ORDER BY created_at ASC
+            "#,
+            server_id
+        )
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(metrics.into_iter().map(|row| ResourceHistory {
-            timestamp: row.timestamp,
-            cpu: row.cpu,
-            memory: row.memory,
-            disk: row.disk,
-            network: format!("{} B/s", row.network),
-        }).collect())
+        Ok(results)
     }
 
-    pub async fn get_recent_server_logs(&self, server_id: &str, limit: i64) -> Result<Vec<LogEntry>> {
-        let rows = sqlx::query!(
+    pub async fn delete_process_watch(&self, server_id: &str, id: &str) -> Result<bool> {
+        let result = sqlx::query!(
             r#"
-            SELECT 
-                id,
-                level as "level!: LogLevel",
-                message,
-                component,
-                server_id,
-                timestamp,
-                metadata as "metadata?: JsonValue",
-                stack_trace,
-                source_location,
-                correlation_id
-            FROM logs 
-            WHERE server_id = $1 
-            ORDER BY timestamp DESC 
-            LIMIT $2
+            DELETE FROM process_watches
+            WHERE id = $1 AND server_id = $2
+            "#,
+            id,
+            server_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 스냅샷의 상위 프로세스를 한 번에 저장
+    pub async fn save_process_samples(
+        &self,
+        server_id: &str,
+        timestamp: DateTime<Utc>,
+        processes: &[ProcessMetrics],
+    ) -> Result<u64> {
+        if processes.is_empty() {
+            return Ok(0);
+        }
+
+        let pids: Vec<i32> = processes.iter().map(|p| p.pid as i32).collect();
+        let names: Vec<String> = processes.iter().map(|p| p.name.clone()).collect();
+        let cpu: Vec<f64> = processes.iter().map(|p| p.cpu_usage as f64).collect();
+        let memory: Vec<i64> = processes.iter().map(|p| p.memory_usage as i64).collect();
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO process_samples (server_id, timestamp, pid, name, cpu_usage, memory_usage)
+            SELECT $1, $2, * FROM UNNEST($3::int4[], $4::text[], $5::float8[], $6::int8[])
+            "#,
+            server_id,
+            timestamp,
+            &pids,
+            &names,
+            &cpu,
+            &memory
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    // 기간 내 상위 소비 프로세스. rank_by: cpu_avg | cpu_max | memory_avg | memory_max
+    pub async fn get_top_processes(
+        &self,
+        server_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        rank_by: &str,
+        limit: i64,
+    ) -> Result<Vec<ProcessUsage>> {
+        let rows = sqlx::query_as!(
+            ProcessUsage,
+            r#"
+            SELECT
+                pid,
+                name,
+                AVG(cpu_usage) as "avg_cpu!: f64",
+                MAX(cpu_usage) as "max_cpu!: f64",
+                AVG(memory_usage)::int8 as "avg_memory!: i64",
+                MAX(memory_usage) as "max_memory!: i64",
+                COUNT(*) as "samples!: i64",
+                MIN(timestamp) as "first_seen!: DateTime<Utc>",
+                MAX(timestamp) as "last_seen!: DateTime<Utc>"
+            FROM process_samples
+            WHERE server_id = $1
+            AND timestamp BETWEEN $2 AND $3
+            GROUP BY pid, name
+            ORDER BY
+                CASE $4
+                    WHEN 'cpu_avg' THEN AVG(cpu_usage)
+                    WHEN 'cpu_max' THEN MAX(cpu_usage)
+                    WHEN 'memory_avg' THEN AVG(memory_usage)::float8
+                    ELSE MAX(memory_usage)::float8
+                END DESC,
+                pid ASC
+            LIMIT $5
             "#,
             server_id,
+            from,
+            to,
+            rank_by,
             limit
         )
         .fetch_all(&self.pool)
         .await?;
-    
-        Ok(rows.into_iter().map(|row| LogEntry {
-            id: row.id,
-            level: row.level,
-            message: row.message,
-            component: row.component,
-            server_id: row.server_id,
-            timestamp: row.timestamp,
-            metadata: row.metadata.into(), 
-            stack_trace: row.stack_trace,
-            source_location: row.source_location,
-            correlation_id: row.correlation_id,
-        }).collect())
+
+        Ok(rows)
     }
 
-    pub async fn get_server_metrics(
+    // pid 는 재사용되므로 이름이 주어지면 함께 맞춘다
+    pub async fn get_process_history(
         &self,
         server_id: &str,
+        pid: i32,
+        name: Option<&str>,
         from: DateTime<Utc>,
-        to: DateTime<Utc>
-    ) -> Result<Vec<MetricsSnapshot>> {
-        let metrics = sqlx::query_as!(
-            MetricsSnapshot,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ProcessSample>> {
+        let rows = sqlx::query_as!(
+            ProcessSample,
             r#"
-            SELECT 
-                id,
-                server_id,
-                cpu_usage,
-                memory_usage,
-                disk_usage,
-                network_rx,
-                network_tx,
-                processes,
-                timestamp
-            FROM metrics_snapshots
-            WHERE server_id = $1 
-            AND timestamp BETWEEN $2 AND $3
+            SELECT timestamp, pid, name, cpu_usage, memory_usage
+            FROM process_samples
+            WHERE server_id = $1
+            AND pid = $2
+            AND ($3::text IS NULL OR name = $3)
+            AND timestamp BETWEEN $4 AND $5
             ORDER BY timestamp ASC
             "#,
             server_id,
+            pid,
+            name,
             from,
             to
         )
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(metrics)
+        Ok(rows)
     }
 
     pub async fn create_alert(&self, alert: Alert) -> Result<Alert> {
@@ -543,6 +1888,139 @@ impl Repository {
         })
     }
 
+    pub async fn create_log_source(&self, source: LogSource) -> Result<LogSource> {
+        let result = sqlx::query_as!(
+            LogSource,
+            r#"
+            INSERT INTO log_sources
+            (id, server_id, path, component, level_rules, default_level, enabled,
+             inode, position, created_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING id, server_id, path, component, level_rules,
+                      default_level as "default_level: LogLevel", enabled,
+                      inode, position, created_by, created_at
+            "#,
+            source.id,
+            source.server_id,
+            source.path,
+            source.component,
+            source.level_rules,
+            source.default_level as LogLevel,
+            source.enabled,
+            source.inode,
+            source.position,
+            source.created_by,
+            source.created_at
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_log_source(&self, server_id: &str, id: &str) -> Result<Option<LogSource>> {
+        let result = sqlx::query_as!(
+            LogSource,
+            r#"
+            SELECT id, server_id, path, component, level_rules,
+                   default_level as "default_level: LogLevel", enabled,
+                   inode, position, created_by, created_at
+            FROM log_sources
+            WHERE id = $1 AND server_id = $2
+            "#,
+            id,
+            server_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn list_log_sources(&self, server_id: &str) -> Result<Vec<LogSource>> {
+        let results = sqlx::query_as!(
+            LogSource,
+            r#"
+            SELECT id, server_id, path, component, level_rules,
+                   default_level as "default_level: LogLevel", enabled,
+                   inode, position, created_by, created_at
+            FROM log_sources
+            WHERE server_id = $1
+            ORDER BY created_at ASC
+            "#,
+            server_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    pub async fn list_enabled_log_sources(&self) -> Result<Vec<LogSource>> {
+        let results = sqlx::query_as!(
+            LogSource,
+            r#"
+            SELECT id, server_id, path, component, level_rules,
+                   default_level as "default_level: LogLevel", enabled,
+                   inode, position, created_by, created_at
+            FROM log_sources
+            WHERE enabled
+            ORDER BY created_at ASC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    pub async fn delete_log_source(&self, server_id: &str, id: &str) -> Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM log_sources
+            WHERE id = $1 AND server_id = $2
+            "#,
+            id,
+            server_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 읽은 줄과 다음 읽을 위치를 한 트랜잭션으로 저장한다
+    pub async fn save_tailed_logs(
+        &self,
+        source_id: &str,
+        inode: i64,
+        position: i64,
+        logs: &[LogEntry],
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        insert_logs(&mut *tx, logs).await?;
+        sqlx::query!(
+            r#"
+            UPDATE log_sources
+            SET inode = $2, position = $3
+            WHERE id = $1
+            "#,
+            source_id,
+            inode,
+            position
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn create_logs(&self, logs: &[LogEntry]) -> Result<u64> {
+        insert_logs(&self.pool, logs).await
+    }
+
     pub async fn get_log(&self, id: &str) -> Result<Option<LogEntry>> {
         sqlx::query_as!(
             LogEntry,
@@ -715,3 +2193,40 @@ impl Repository {
         Ok(())
     }
 }
+
+// logs 에 여러 줄을 한 번에 넣는다
+async fn insert_logs<'e, E: sqlx::PgExecutor<'e>>(executor: E, logs: &[LogEntry]) -> Result<u64> {
+    if logs.is_empty() {
+        return Ok(0);
+    }
+    let ids: Vec<String> = logs.iter().map(|l| l.id.clone()).collect();
+    let levels: Vec<String> = logs.iter().map(|l| l.level.to_string()).collect();
+    let messages: Vec<String> = logs.iter().map(|l| l.message.clone()).collect();
+    let components: Vec<String> = logs.iter().map(|l| l.component.clone()).collect();
+    let server_ids: Vec<Option<String>> = logs.iter().map(|l| l.server_id.clone()).collect();
+    let timestamps: Vec<DateTime<Utc>> = logs.iter().map(|l| l.timestamp).collect();
+    let metadata: Vec<JsonValue> = logs
+        .iter()
+        .map(|l| serde_json::to_value(&l.metadata).unwrap_or(JsonValue::Null))
+        .collect();
+
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO logs (id, level, message, component, server_id, timestamp, metadata)
+        SELECT l.id, l.level::log_level, l.message, l.component, l.server_id, l.timestamp, l.metadata
+        FROM UNNEST($1::text[], $2::text[], $3::text[], $4::text[], $5::text[], $6::timestamptz[], $7::jsonb[])
+            AS l(id, level, message, component, server_id, timestamp, metadata)
+        "#,
+        &ids,
+        &levels,
+        &messages,
+        &components,
+        &server_ids as &[Option<String>],
+        &timestamps,
+        &metadata
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected())
+}
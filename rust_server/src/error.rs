@@ -15,6 +15,9 @@ pub enum AppError {
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     #[error("Not found: {0}")]
     NotFound(String),
 
@@ -70,6 +73,12 @@ impl ResponseError for AppError {
                     "message": msg
                 }))
             }
+            AppError::Forbidden(msg) => {
+                HttpResponse::Forbidden().json(json!({
+                    "error": "forbidden",
+                    "message": msg
+                }))
+            }
             AppError::NotFound(msg) => {
                 HttpResponse::NotFound().json(json!({
                     "error": "not_found",
@@ -1,10 +1,13 @@
 // src/lib.rs
+pub mod agent;
 pub mod api;
 pub mod auth;
+pub mod checks;
 pub mod config;
 pub mod db;
 pub mod models;
 pub mod error;
+pub mod logs;
 pub mod monitoring;
 pub mod websocket;
 pub mod utils;
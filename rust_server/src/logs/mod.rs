@@ -0,0 +1,186 @@
+// src/logs/mod.rs
+use std::collections::HashMap;
+use std::sync::Arc;
+use anyhow::anyhow;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+use crate::config::{EncryptionConfig, ServerConfig};
+use crate::db::models::LogSource;
+use crate::db::repository::Repository;
+use crate::models::log_source::LevelParser;
+use crate::models::logs::LogEntry;
+use crate::monitoring::ssh::{SshSession, SshTarget};
+use crate::utils::encryption::Encryptor;
+
+pub mod syslog;
+pub mod tail;
+
+use tail::TailPlan;
+
+pub const POLL_INTERVAL_SECONDS: u64 = 10;
+
+// 서버의 로그 파일을 SSH 로 따라 읽어 logs 테이블에 넣는다.
+// 읽은 위치(inode, offset)는 DB 에 남겨 회전, 재연결, 서버 재시작 뒤에도 이어서 읽는다
+#[derive(Clone)]
+pub struct LogTailService {
+    repo: Repository,
+    encryption: EncryptionConfig,
+    tasks: Arc<RwLock<HashMap<String, JoinHandle<()>>>>,
+}
+
+impl LogTailService {
+    pub fn new(repo_data: actix_web::web::Data<Repository>) -> Self {
+        Self {
+            repo: repo_data.get_ref().clone(),
+            encryption: EncryptionConfig::default(),
+            tasks: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    // SSH 비밀번호 복호화에 쓴다
+    pub fn with_config(mut self, config: &ServerConfig) -> Self {
+        self.encryption = config.encryption.clone();
+        self
+    }
+
+    // 활성화된 로그 소스를 모두 따라가기 시작한다
+    pub async fn start(&self) {
+        match self.repo.list_enabled_log_sources().await {
+            Ok(sources) => {
+                info!("Tailing {} log files", sources.len());
+                for source in sources {
+                    self.schedule(source).await;
+                }
+            }
+            Err(e) => error!("Failed to load log sources: {}", e),
+        }
+    }
+
+    // 이미 돌고 있는 소스라면 교체한다
+    pub async fn schedule(&self, source: LogSource) {
+        self.unschedule(&source.id).await;
+        if !source.enabled {
+            return;
+        }
+
+        let service = self.clone();
+        let source_id = source.id.clone();
+        let handle = tokio::spawn(async move {
+            // 소스마다 연결 하나를 유지한다
+            let session = match service.ssh_target(&source.server_id).await {
+                Ok(target) => Arc::new(SshSession::new(target)),
+                Err(e) => {
+                    warn!("Cannot tail {} on {}: {:#}", source.path, source.server_id, e);
+                    return;
+                }
+            };
+            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(POLL_INTERVAL_SECONDS));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut failing = false;
+            loop {
+                interval.tick().await;
+                // 소스나 서버가 삭제되면 종료
+                let source = match service.repo.get_log_source(&source.server_id, &source.id).await {
+                    Ok(Some(source)) if source.enabled => source,
+                    Ok(_) => break,
+                    Err(e) => {
+                        error!("Failed to load log source {}: {}", source.id, e);
+                        continue;
+                    }
+                };
+                let parser = match source
+                    .level_rules()
+                    .map_err(|e| e.to_string())
+                    .and_then(|rules| LevelParser::new(&rules, source.default_level.clone()))
+                {
+                    Ok(parser) => parser,
+                    Err(e) => {
+                        warn!("Invalid level rules for log source {}: {}", source.id, e);
+                        break;
+                    }
+                };
+                // 파일이 없는 동안 매 주기 경고하지 않도록 상태가 바뀔 때만 남긴다
+                match service.poll(&session, &source, &parser).await {
+                    Ok(()) => failing = false,
+                    Err(e) if !failing => {
+                        warn!("Failed to tail {} on {}: {:#}", source.path, source.server_id, e);
+                        failing = true;
+                    }
+                    Err(_) => {}
+                }
+            }
+        });
+        self.tasks.write().await.insert(source_id, handle);
+    }
+
+    pub async fn unschedule(&self, source_id: &str) {
+        if let Some(handle) = self.tasks.write().await.remove(source_id) {
+            handle.abort();
+        }
+    }
+
+    // 새로 쓰인 줄을 읽어 저장하고 다음 읽을 위치를 남긴다
+    async fn poll(&self, session: &Arc<SshSession>, source: &LogSource, parser: &LevelParser) -> anyhow::Result<()> {
+        let (status, output) = session.exec_status(&tail::stat_command(&source.path)).await?;
+        let current = tail::parse_stat(&output)
+            .filter(|_| status == 0)
+            .ok_or_else(|| anyhow!("Cannot stat {}: {}", source.path, output.trim()))?;
+        let saved = source.inode.map(|inode| (inode as u64, source.position as u64));
+
+        let (lines, inode, position) = match tail::plan(saved, current) {
+            TailPlan::Start { inode, position } => (Vec::new(), inode, position),
+            TailPlan::Read { inode, from } if current.size <= from => (Vec::new(), inode, from),
+            TailPlan::Read { inode, from } => {
+                let (_, bytes) = session.exec_raw(&tail::read_command(&source.path, from)).await?;
+                let (lines, consumed) = tail::split_lines(&bytes, false);
+                (lines, inode, from + consumed as u64)
+            }
+            TailPlan::Rotated { old_inode, from, inode } => {
+                let command = tail::read_rotated_command(&source.path, old_inode, from);
+                let (_, bytes) = session.exec_raw(&command).await?;
+                // 한도까지 찼으면 이전 파일이 남았으므로 다음 주기에 이어서 읽는다
+                if bytes.len() >= tail::MAX_READ_BYTES {
+                    let (lines, consumed) = tail::split_lines(&bytes, false);
+                    (lines, old_inode, from + consumed as u64)
+                } else {
+                    (tail::split_lines(&bytes, true).0, inode, 0)
+                }
+            }
+        };
+
+        if lines.is_empty() && saved == Some((inode, position)) {
+            return Ok(());
+        }
+        let entries = self.entries(source, parser, lines);
+        self.repo
+            .save_tailed_logs(&source.id, inode as i64, position as i64, &entries)
+            .await?;
+        Ok(())
+    }
+
+    fn entries(&self, source: &LogSource, parser: &LevelParser, lines: Vec<String>) -> Vec<LogEntry> {
+        let metadata: HashMap<String, serde_json::Value> = HashMap::from([
+            ("path".to_string(), serde_json::json!(source.path)),
+            ("log_source_id".to_string(), serde_json::json!(source.id)),
+        ]);
+        lines
+            .into_iter()
+            .map(|line| {
+                LogEntry::new(parser.level(&line), line, source.component.clone(), Some(source.server_id.clone()))
+                    .with_metadata(metadata.clone())
+            })
+            .collect()
+    }
+
+    async fn ssh_target(&self, server_id: &str) -> anyhow::Result<SshTarget> {
+        let server = self
+            .repo
+            .get_server(server_id)
+            .await?
+            .ok_or_else(|| anyhow!("Server {} not found", server_id))?;
+        let encryptor = Encryptor::new(&self.encryption.key, &self.encryption.nonce)
+            .map_err(|e| anyhow!(e.to_string()))?;
+        SshTarget::from_server(&server, &encryptor)
+    }
+}
@@ -0,0 +1,261 @@
+// src/logs/syslog.rs
+use std::collections::HashMap;
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant};
+use chrono::{DateTime, Utc};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::net::{TcpListener, TcpStream, UdpSocket};
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+use crate::config::{ServerConfig, SyslogConfig};
+use crate::db::repository::Repository;
+use crate::models::logs::LogEntry;
+use crate::models::syslog::SyslogMessage;
+
+pub const MAX_MESSAGE_BYTES: usize = 64 * 1024;
+const CHANNEL_CAPACITY: usize = 10_000;
+// 보낸 호스트 → 서버 매핑을 다시 찾기까지의 시간
+const SERVER_CACHE_TTL: Duration = Duration::from_secs(300);
+
+struct Received {
+    message: SyslogMessage,
+    peer: IpAddr,
+    received_at: DateTime<Utc>,
+}
+
+// UDP/TCP 로 syslog 를 받아 logs 에 모아서 저장한다. 보낸 호스트가 등록된 서버면 server_id 를 붙인다
+#[derive(Clone)]
+pub struct SyslogReceiver {
+    repo: Repository,
+    config: SyslogConfig,
+}
+
+impl SyslogReceiver {
+    pub fn new(repo_data: actix_web::web::Data<Repository>, config: &ServerConfig) -> Self {
+        Self {
+            repo: repo_data.get_ref().clone(),
+            config: config.syslog.clone(),
+        }
+    }
+
+    // 설정된 주소에서 수신을 시작한다. 바인드에 실패한 프로토콜은 건너뛴다
+    pub async fn start(&self) {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        if let Some(addr) = &self.config.udp_bind {
+            match UdpSocket::bind(addr).await {
+                Ok(socket) => {
+                    info!("Receiving syslog on udp://{}", addr);
+                    tokio::spawn(receive_udp(socket, tx.clone()));
+                }
+                Err(e) => error!("Failed to bind syslog UDP {}: {}", addr, e),
+            }
+        }
+        if let Some(addr) = &self.config.tcp_bind {
+            match TcpListener::bind(addr).await {
+                Ok(listener) => {
+                    info!("Receiving syslog on tcp://{}", addr);
+                    tokio::spawn(receive_tcp(listener, tx.clone()));
+                }
+                Err(e) => error!("Failed to bind syslog TCP {}: {}", addr, e),
+            }
+        }
+        tokio::spawn(self.clone().write_batches(rx));
+    }
+
+    // batch_size 만큼 모이거나 flush_interval_ms 가 지나면 한 번에 저장한다
+    async fn write_batches(self, mut rx: mpsc::Receiver<Received>) {
+        let batch_size = self.config.batch_size.max(1);
+        let mut batch = Vec::with_capacity(batch_size);
+        let mut servers = HashMap::new();
+        let mut ticker = tokio::time::interval(Duration::from_millis(self.config.flush_interval_ms.max(1)));
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                received = rx.recv() => match received {
+                    Some(received) => {
+                        let server_id = self.resolve_server(&mut servers, &received).await;
+                        batch.push(log_entry(&received.message, server_id, received.peer, received.received_at));
+                        if batch.len() >= batch_size {
+                            self.flush(&mut batch).await;
+                        }
+                    }
+                    None => {
+                        self.flush(&mut batch).await;
+                        break;
+                    }
+                },
+                _ = ticker.tick() => self.flush(&mut batch).await,
+            }
+        }
+    }
+
+    async fn flush(&self, batch: &mut Vec<LogEntry>) {
+        if batch.is_empty() {
+            return;
+        }
+        if let Err(e) = self.repo.create_logs(batch).await {
+            error!("Failed to save {} syslog messages: {}", batch.len(), e);
+        }
+        batch.clear();
+    }
+
+    // 보낸 주소를 서버의 hostname 또는 ip_address 와 맞춰 본다.
+    // HOSTNAME 은 보내는 쪽이 마음대로 쓸 수 있으므로 trusted_relays 가 보낸 경우에만 쓴다
+    async fn resolve_server(
+        &self,
+        cache: &mut HashMap<String, (Option<String>, Instant)>,
+        received: &Received,
+    ) -> Option<String> {
+        for key in lookup_keys(&received.message, received.peer, &self.config.trusted_relays) {
+            let server_id = match cache.get(&key) {
+                Some((server_id, cached_at)) if cached_at.elapsed() < SERVER_CACHE_TTL => server_id.clone(),
+                _ => match self.find_server(&key).await {
+                    Ok(server_id) => {
+                        cache.insert(key.clone(), (server_id.clone(), Instant::now()));
+                        server_id
+                    }
+                    Err(e) => {
+                        debug!("Failed to look up syslog host {}: {}", key, e);
+                        None
+                    }
+                },
+            };
+            if server_id.is_some() {
+                return server_id;
+            }
+        }
+        None
+    }
+
+    async fn find_server(&self, host: &str) -> anyhow::Result<Option<String>> {
+        if let Some(server) = self.repo.get_server_by_hostname(host).await? {
+            return Ok(Some(server.id));
+        }
+        Ok(self.repo.get_server_by_ip_address(host).await?.map(|server| server.id))
+    }
+}
+
+// 서버를 찾을 때 맞춰 볼 값. 중계 서버가 보낸 메시지는 원래 장비의 HOSTNAME 이 먼저고,
+// 그 밖에는 보낸 주소만 쓴다
+pub fn lookup_keys(message: &SyslogMessage, peer: IpAddr, trusted_relays: &[IpAddr]) -> Vec<String> {
+    let mut keys = Vec::new();
+    if trusted_relays.contains(&peer) {
+        keys.extend(message.hostname.iter().cloned());
+    }
+    keys.push(peer.to_string());
+    keys
+}
+
+// 받은 메시지 하나를 로그 한 줄로. 메시지에 시각이 없으면 받은 시각
+pub fn log_entry(message: &SyslogMessage, server_id: Option<String>, peer: IpAddr, received_at: DateTime<Utc>) -> LogEntry {
+    let mut metadata = HashMap::from([
+        ("source".to_string(), serde_json::json!("syslog")),
+        ("format".to_string(), serde_json::json!(message.format)),
+        ("facility".to_string(), serde_json::json!(message.facility_name())),
+        ("severity".to_string(), serde_json::json!(message.severity)),
+        ("remote_addr".to_string(), serde_json::json!(peer.to_string())),
+    ]);
+    let optional = [
+        ("hostname", &message.hostname),
+        ("proc_id", &message.proc_id),
+        ("msg_id", &message.msg_id),
+        ("structured_data", &message.structured_data),
+    ];
+    for (key, value) in optional {
+        if let Some(value) = value {
+            metadata.insert(key.to_string(), serde_json::json!(value));
+        }
+    }
+
+    let component = message.app_name.clone().unwrap_or_else(|| message.facility_name().to_string());
+    let mut entry = LogEntry::new(message.level(), message.message.clone(), component, server_id).with_metadata(metadata);
+    entry.timestamp = message.timestamp.unwrap_or(received_at);
+    entry
+}
+
+fn received(frame: &[u8], peer: SocketAddr) -> Received {
+    let received_at = Utc::now();
+    Received {
+        message: SyslogMessage::parse(&String::from_utf8_lossy(frame), received_at),
+        peer: peer.ip().to_canonical(),
+        received_at,
+    }
+}
+
+// 데이터그램 하나가 메시지 하나
+async fn receive_udp(socket: UdpSocket, tx: mpsc::Sender<Received>) {
+    let mut buf = vec![0u8; MAX_MESSAGE_BYTES];
+    loop {
+        match socket.recv_from(&mut buf).await {
+            Ok((len, peer)) => {
+                if tx.send(received(&buf[..len], peer)).await.is_err() {
+                    break;
+                }
+            }
+            Err(e) => warn!("Failed to receive syslog datagram: {}", e),
+        }
+    }
+}
+
+async fn receive_tcp(listener: TcpListener, tx: mpsc::Sender<Received>) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, peer)) => {
+                tokio::spawn(handle_connection(stream, peer, tx.clone()));
+            }
+            Err(e) => warn!("Failed to accept syslog connection: {}", e),
+        }
+    }
+}
+
+async fn handle_connection(stream: TcpStream, peer: SocketAddr, tx: mpsc::Sender<Received>) {
+    let mut reader = BufReader::new(stream);
+    loop {
+        match read_frame(&mut reader).await {
+            Ok(Some(frame)) => {
+                if tx.send(received(&frame, peer)).await.is_err() {
+                    break;
+                }
+            }
+            Ok(None) => break,
+            Err(e) => {
+                debug!("Closing syslog connection from {}: {}", peer, e);
+                break;
+            }
+        }
+    }
+}
+
+// RFC 6587 프레이밍: "길이 SP 메시지"(octet counting) 또는 LF 로 끝나는 메시지.
+// 스트림이 끝나면 None
+pub async fn read_frame<R: AsyncBufRead + Unpin>(reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+    loop {
+        let first = match reader.fill_buf().await?.first() {
+            Some(first) => *first,
+            None => return Ok(None),
+        };
+        if first == b'\n' || first == b'\r' {
+            reader.consume(1);
+            continue;
+        }
+
+        if first.is_ascii_digit() {
+            let mut length = Vec::new();
+            (&mut *reader).take(8).read_until(b' ', &mut length).await?;
+            let length: usize = std::str::from_utf8(&length)
+                .ok()
+                .and_then(|length| length.trim_end().parse().ok())
+                .filter(|length| *length <= MAX_MESSAGE_BYTES)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid syslog frame length"))?;
+            let mut frame = vec![0u8; length];
+            reader.read_exact(&mut frame).await?;
+            return Ok(Some(frame));
+        }
+
+        // 한도를 넘는 줄은 잘라서 나머지를 다음 메시지로 읽는다
+        let mut frame = Vec::new();
+        (&mut *reader).take(MAX_MESSAGE_BYTES as u64).read_until(b'\n', &mut frame).await?;
+        return Ok(Some(frame));
+    }
+}
@@ -0,0 +1,78 @@
+// src/logs/tail.rs
+// 원격 파일을 어디서부터 읽을지 정하고 읽은 바이트를 줄로 나누는 부분. SSH 와 무관하게 테스트한다
+use crate::models::log_source::file_name;
+
+// 한 번에 읽는 최대 바이트. 밀린 양이 많으면 여러 번에 나눠 읽는다
+pub const MAX_READ_BYTES: usize = 256 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileStat {
+    pub inode: u64,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TailPlan {
+    // 처음 보는 파일. 기존 내용은 건너뛰고 끝에서부터 따라간다
+    Start { inode: u64, position: u64 },
+    // 같은 파일을 from 부터 (잘렸으면 0 부터)
+    Read { inode: u64, from: u64 },
+    // 회전됨. 이전 파일(old_inode)을 from 부터 마저 읽은 뒤 새 파일로 넘어간다
+    Rotated { old_inode: u64, from: u64, inode: u64 },
+}
+
+// saved 는 마지막으로 저장한 (inode, 위치)
+pub fn plan(saved: Option<(u64, u64)>, current: FileStat) -> TailPlan {
+    match saved {
+        None => TailPlan::Start { inode: current.inode, position: current.size },
+        Some((inode, position)) if inode == current.inode => {
+            let from = if current.size < position { 0 } else { position };
+            TailPlan::Read { inode, from }
+        }
+        Some((old_inode, from)) => TailPlan::Rotated { old_inode, from, inode: current.inode },
+    }
+}
+
+// 심볼릭 링크는 따라간다. 출력: "<inode> <size>"
+pub fn stat_command(path: &str) -> String {
+    format!("stat -L -c '%i %s' -- '{}'", path)
+}
+
+pub fn parse_stat(output: &str) -> Option<FileStat> {
+    let mut fields = output.split_whitespace();
+    let inode = fields.next()?.parse().ok()?;
+    let size = fields.next()?.parse().ok()?;
+    Some(FileStat { inode, size })
+}
+
+pub fn read_command(path: &str, from: u64) -> String {
+    format!("tail -c +{} -- '{}' | head -c {}", from + 1, path, MAX_READ_BYTES)
+}
+
+// 회전된 파일은 같은 디렉터리에서 inode 로 찾는다. 이미 지워졌거나 압축됐으면 빈 출력
+pub fn read_rotated_command(path: &str, inode: u64, from: u64) -> String {
+    let name = file_name(path);
+    let dir = &path[..path.len() - name.len()];
+    format!(
+        "f=$(find '{}' -maxdepth 1 -inum {} -type f 2>/dev/null | head -n 1); \
+         [ -n \"$f\" ] && tail -c +{} -- \"$f\" | head -c {}",
+        dir, inode, from + 1, MAX_READ_BYTES
+    )
+}
+
+// 완성된 줄(개행으로 끝나는 줄)만 꺼내고 소비한 바이트 수를 돌려준다.
+// eof 이면 마지막 미완성 줄도 꺼낸다. 개행 없이 한도까지 찼으면 통째로 한 줄로 본다
+pub fn split_lines(bytes: &[u8], eof: bool) -> (Vec<String>, usize) {
+    let consumed = match bytes.iter().rposition(|b| *b == b'\n') {
+        _ if eof => bytes.len(),
+        Some(last) => last + 1,
+        None if bytes.len() >= MAX_READ_BYTES => bytes.len(),
+        None => 0,
+    };
+    let lines = bytes[..consumed]
+        .split(|b| *b == b'\n')
+        .map(|line| String::from_utf8_lossy(line.strip_suffix(b"\r").unwrap_or(line)).into_owned())
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    (lines, consumed)
+}
@@ -8,7 +8,9 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use rust_server::{
     api::configure_routes,
     auth::middleware::AuthMiddleware, 
+    checks::CheckService,
     db::{self, repository::Repository},
+    logs::{syslog::SyslogReceiver, LogTailService},
     monitoring::MonitoringService,
     error::AppError,
     config::ServerConfig,
@@ -34,7 +36,10 @@ async fn main() -> Result<(), std::io::Error> {
 
     // 서비스 초기화
     let repository = web::Data::new(Repository::new(db_pool));
-    let monitoring_service = web::Data::new(MonitoringService::new(repository.clone()));
+    let monitoring_service = web::Data::new(MonitoringService::new(repository.clone()).with_config(&config));
+    let http_client = web::Data::new(reqwest::Client::new());
+    let check_service = web::Data::new(CheckService::new(repository.clone(), http_client.get_ref().clone()).with_config(&config));
+    let log_tail_service = web::Data::new(LogTailService::new(repository.clone()).with_config(&config));
     
     // 모니터링 서비스의 클론을 만들어 백그라운드 태스크에서 사용
     let monitoring_service_for_task = monitoring_service.clone();
@@ -49,7 +54,25 @@ async fn main() -> Result<(), std::io::Error> {
         }
     });
 
-    let http_client = web::Data::new(reqwest::Client::new());
+    // 합성 검사 스케줄 시작
+    let check_service_for_task = check_service.clone();
+    tokio::spawn(async move {
+        check_service_for_task.start().await;
+    });
+
+    // 로그 파일 따라 읽기 시작
+    let log_tail_service_for_task = log_tail_service.clone();
+    tokio::spawn(async move {
+        log_tail_service_for_task.start().await;
+    });
+
+    // syslog 수신 (주소를 설정한 경우만)
+    if config.syslog.enabled() {
+        let syslog_receiver = SyslogReceiver::new(repository.clone(), &config);
+        tokio::spawn(async move {
+            syslog_receiver.start().await;
+        });
+    }
 
     let server_address = format!("{}:{}", 
         config.server.host, 
@@ -66,6 +89,8 @@ async fn main() -> Result<(), std::io::Error> {
             .app_data(repository.clone())
             .app_data(monitoring_service.clone())
             .app_data(http_client.clone())
+            .app_data(check_service.clone())
+            .app_data(log_tail_service.clone())
             .configure(configure_routes)
             .default_service(web::route().to(|| async { HttpResponse::NotFound().finish() }))
     })
@@ -0,0 +1,113 @@
+// src/models/agent.rs
+use std::net::IpAddr;
+use serde::{Deserialize, Serialize};
+use crate::agent::config::AgentConfig;
+use crate::db::models::ServerType;
+
+// 호스트 정보 (에이전트 등록 시 전송, 이후 SSH/에이전트로 매일 갱신)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct HostFacts {
+    pub hostname: String,
+    #[serde(default)]
+    pub os_name: Option<String>,
+    #[serde(default)]
+    pub os_version: Option<String>,
+    #[serde(default)]
+    pub kernel_version: Option<String>,
+    #[serde(default)]
+    pub architecture: Option<String>,
+    #[serde(default)]
+    pub cpu_cores: Option<usize>,
+    #[serde(default)]
+    pub total_memory: Option<u64>,   // bytes
+    #[serde(default)]
+    pub ip_address: Option<String>,
+    #[serde(default)]
+    pub cpu_model: Option<String>,
+    #[serde(default)]
+    pub virtualization: Option<String>,   // systemd-detect-virt 값 (none, kvm, docker ...)
+    #[serde(default)]
+    pub interfaces: Vec<InterfaceFacts>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct InterfaceFacts {
+    pub name: String,
+    #[serde(default)]
+    pub mac_address: Option<String>,
+    #[serde(default)]
+    pub addresses: Vec<String>,   // CIDR 표기 (10.0.0.5/24)
+}
+
+impl InterfaceFacts {
+    // 컨테이너/VM 브리지와 veth 는 서버 주소로 쓰지 않는다
+    pub fn is_virtual(&self) -> bool {
+        ["docker", "br-", "veth", "virbr", "cni", "flannel", "podman"]
+            .iter()
+            .any(|prefix| self.name.starts_with(prefix))
+    }
+}
+
+impl HostFacts {
+    pub fn server_type(&self) -> ServerType {
+        let os = self.os_name.as_deref().unwrap_or_default().to_lowercase();
+        if os.contains("windows") {
+            ServerType::Windows
+        } else if os.contains("mac") || os.contains("darwin") {
+            ServerType::MacOS
+        } else {
+            ServerType::Linux
+        }
+    }
+
+    // 지정된 주소가 없으면 loopback/link-local 이 아닌 주소 중 물리 인터페이스, IPv4 순으로 고른다
+    pub fn primary_ip(&self) -> Option<String> {
+        if let Some(ip) = self.ip_address.as_deref().filter(|ip| !ip.is_empty()) {
+            return Some(ip.to_string());
+        }
+        self.interfaces
+            .iter()
+            .flat_map(|iface| iface.addresses.iter().map(move |addr| (iface, addr)))
+            .filter_map(|(iface, addr)| Some((iface, addr.split('/').next()?.parse::<IpAddr>().ok()?)))
+            .filter(|(_, ip)| match ip {
+                IpAddr::V4(v4) => !v4.is_loopback() && !v4.is_link_local() && !v4.is_unspecified(),
+                IpAddr::V6(v6) => !v6.is_loopback() && !v6.is_unspecified() && (v6.segments()[0] & 0xffc0) != 0xfe80,
+            })
+            .min_by_key(|(iface, ip)| (iface.is_virtual(), !ip.is_ipv4()))
+            .map(|(_, ip)| ip.to_string())
+    }
+
+    // 이전 값과 달라진 항목 이름. 이전 값이 없으면 값이 있는 항목 전부
+    pub fn changed_fields(&self, previous: Option<&HostFacts>) -> Vec<String> {
+        let current = serde_json::to_value(self).unwrap_or_default();
+        let previous = previous.and_then(|p| serde_json::to_value(p).ok()).unwrap_or_default();
+        let empty = |v: &serde_json::Value| v.is_null() || v.as_array().is_some_and(|a| a.is_empty());
+
+        let mut changed: Vec<String> = current
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(key, value)| match previous.get(key.as_str()) {
+                Some(old) => old != *value,
+                None => !empty(value),
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        changed.sort();
+        changed
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnrollRequest {
+    pub token: String,
+    #[serde(default)]
+    pub name: Option<String>,   // 없으면 hostname 사용
+    pub facts: HostFacts,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnrollResponse {
+    pub server_id: String,
+    pub config: AgentConfig,
+}
@@ -0,0 +1,331 @@
+// src/models/check.rs
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_CHECK_TIMEOUT_SECONDS: u64 = 60;
+
+// 검사 종류별 설정. checks.config 에 JSON 으로 저장된다
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CheckConfig {
+    Http(HttpCheckConfig),
+    Tcp(TcpCheckConfig),
+    Dns(DnsCheckConfig),
+    Tls(TlsCheckConfig),
+    Command(CommandCheckConfig),
+}
+
+impl CheckConfig {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CheckConfig::Http(_) => "http",
+            CheckConfig::Tcp(_) => "tcp",
+            CheckConfig::Dns(_) => "dns",
+            CheckConfig::Tls(_) => "tls",
+            CheckConfig::Command(_) => "command",
+        }
+    }
+
+    // 호스트를 비워 두면 검사가 속한 서버의 호스트를 쓴다
+    pub fn with_default_host(mut self, hostname: &str) -> Self {
+        let host = match &mut self {
+            CheckConfig::Tcp(config) => &mut config.host,
+            CheckConfig::Tls(config) => &mut config.host,
+            _ => return self,
+        };
+        if host.trim().is_empty() {
+            *host = hostname.to_string();
+        }
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            CheckConfig::Http(config) => config.validate(),
+            CheckConfig::Tcp(config) => config.validate(),
+            CheckConfig::Dns(config) => config.validate(),
+            CheckConfig::Tls(config) => config.validate(),
+            CheckConfig::Command(config) => config.validate(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HttpCheckConfig {
+    pub url: String,
+    #[serde(default = "default_method")]
+    pub method: String,
+    #[serde(default)]
+    pub expected_status: Vec<u16>,      // 비어 있으면 2xx/3xx
+    pub body_contains: Option<String>,
+    pub body_regex: Option<String>,
+    pub max_response_ms: Option<u64>,   // 응답 시간 예산
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    #[serde(default = "default_timeout_seconds")]
+    pub timeout_seconds: u64,
+}
+
+fn default_method() -> String { "GET".to_string() }
+fn default_timeout_seconds() -> u64 { 10 }
+
+impl HttpCheckConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        let url = reqwest::Url::parse(&self.url).map_err(|e| format!("Invalid url: {}", e))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err("url must use http or https".into());
+        }
+        reqwest::Method::from_bytes(self.method.as_bytes())
+            .map_err(|_| format!("Invalid method: {}", self.method))?;
+        if let Some(status) = self.expected_status.iter().find(|s| !(100..=599).contains(*s)) {
+            return Err(format!("Invalid expected status: {}", status));
+        }
+        if self.body_contains.as_deref().is_some_and(str::is_empty) {
+            return Err("body_contains must not be empty".into());
+        }
+        if let Some(pattern) = &self.body_regex {
+            Regex::new(pattern).map_err(|e| format!("Invalid body_regex: {}", e))?;
+        }
+        if self.max_response_ms == Some(0) {
+            return Err("max_response_ms must be greater than 0".into());
+        }
+        for (name, value) in &self.headers {
+            reqwest::header::HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| format!("Invalid header name: {}", name))?;
+            reqwest::header::HeaderValue::from_str(value)
+                .map_err(|_| format!("Invalid value for header {}", name))?;
+        }
+        validate_timeout(self.timeout_seconds)
+    }
+
+    pub fn status_ok(&self, status: u16) -> bool {
+        if self.expected_status.is_empty() {
+            (200..400).contains(&status)
+        } else {
+            self.expected_status.contains(&status)
+        }
+    }
+
+    // 본문 검사가 없으면 본문을 읽지 않는다
+    pub fn needs_body(&self) -> bool {
+        self.body_contains.is_some() || self.body_regex.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TcpCheckConfig {
+    #[serde(default)]
+    pub host: String,                   // 비어 있으면 서버 호스트
+    pub port: u16,
+    pub banner: Option<String>,         // 연결 직후 받은 데이터에 포함돼야 하는 문자열
+    #[serde(default = "default_timeout_seconds")]
+    pub timeout_seconds: u64,
+}
+
+impl TcpCheckConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.host.len() > 255 || self.host.contains(char::is_whitespace) {
+            return Err(format!("Invalid host: {}", self.host));
+        }
+        if self.port == 0 {
+            return Err("port must be 1-65535".into());
+        }
+        if self.banner.as_deref().is_some_and(str::is_empty) {
+            return Err("banner must not be empty".into());
+        }
+        validate_timeout(self.timeout_seconds)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DnsRecordType {
+    A,
+    Aaaa,
+    Cname,
+    Mx,
+    Ns,
+    Txt,
+}
+
+impl DnsRecordType {
+    pub fn code(&self) -> u16 {
+        match self {
+            DnsRecordType::A => 1,
+            DnsRecordType::Ns => 2,
+            DnsRecordType::Cname => 5,
+            DnsRecordType::Mx => 15,
+            DnsRecordType::Txt => 16,
+            DnsRecordType::Aaaa => 28,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DnsRecordType::A => "A",
+            DnsRecordType::Aaaa => "AAAA",
+            DnsRecordType::Cname => "CNAME",
+            DnsRecordType::Mx => "MX",
+            DnsRecordType::Ns => "NS",
+            DnsRecordType::Txt => "TXT",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DnsCheckConfig {
+    pub name: String,                   // 조회할 도메인
+    pub record_type: DnsRecordType,
+    #[serde(default)]
+    pub expected: Vec<String>,          // 응답에 모두 있어야 하는 값. 비어 있으면 응답이 하나라도 있으면 성공
+    pub resolver: Option<String>,       // "1.1.1.1" 또는 "10.0.0.2:5353". 없으면 /etc/resolv.conf
+    #[serde(default = "default_dns_timeout_seconds")]
+    pub timeout_seconds: u64,
+}
+
+fn default_dns_timeout_seconds() -> u64 { 5 }
+
+impl DnsCheckConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        let name = self.name.trim_end_matches('.');
+        if name.is_empty()
+            || name.len() > 253
+            || name.split('.').any(|label| label.is_empty() || label.len() > 63)
+        {
+            return Err(format!("Invalid name: {}", self.name));
+        }
+        if let Some(resolver) = &self.resolver {
+            self.resolver_addr()
+                .ok_or_else(|| format!("Invalid resolver: {}", resolver))?;
+        }
+        validate_timeout(self.timeout_seconds)
+    }
+
+    pub fn resolver_addr(&self) -> Option<std::net::SocketAddr> {
+        let resolver = self.resolver.as_deref()?.trim();
+        resolver
+            .parse()
+            .ok()
+            .or_else(|| resolver.parse::<std::net::IpAddr>().ok().map(|ip| (ip, 53).into()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TlsCheckConfig {
+    #[serde(default)]
+    pub host: String,                   // 비어 있으면 서버 호스트
+    #[serde(default = "default_tls_port")]
+    pub port: u16,
+    pub server_name: Option<String>,    // SNI 와 이름 검증에 쓸 이름. 없으면 host
+    pub ca_pem: Option<String>,         // 사설 CA (PEM). 있으면 공개 루트 대신 이것만 신뢰
+    #[serde(default = "default_warning_days")]
+    pub warning_days: u32,              // 만료 며칠 전부터 경고
+    #[serde(default = "default_critical_days")]
+    pub critical_days: u32,             // 만료 며칠 전부터 위험
+    #[serde(default = "default_timeout_seconds")]
+    pub timeout_seconds: u64,
+}
+
+fn default_tls_port() -> u16 { 443 }
+fn default_warning_days() -> u32 { 30 }
+fn default_critical_days() -> u32 { 7 }
+
+impl TlsCheckConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.host.len() > 255 || self.host.contains(char::is_whitespace) {
+            return Err(format!("Invalid host: {}", self.host));
+        }
+        if self.port == 0 {
+            return Err("port must be 1-65535".into());
+        }
+        if let Some(name) = &self.server_name {
+            rustls::ServerName::try_from(name.as_str())
+                .map_err(|_| format!("Invalid server_name: {}", name))?;
+        }
+        if let Some(pem) = &self.ca_pem {
+            let certs = rustls_pemfile::certs(&mut pem.as_bytes())
+                .map_err(|e| format!("Invalid ca_pem: {}", e))?;
+            if certs.is_empty() {
+                return Err("ca_pem must contain at least one certificate".into());
+            }
+        }
+        if !(1..=365).contains(&self.warning_days) {
+            return Err("warning_days must be 1-365".into());
+        }
+        if self.critical_days > self.warning_days {
+            return Err("critical_days must not exceed warning_days".into());
+        }
+        validate_timeout(self.timeout_seconds)
+    }
+
+    pub fn server_name(&self) -> &str {
+        self.server_name.as_deref().unwrap_or(&self.host)
+    }
+}
+
+pub const MAX_COMMAND_LENGTH: usize = 4096;
+
+// 서버에 SSH 로 실행하는 Nagios 플러그인 호환 명령
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommandCheckConfig {
+    pub command: String,                // 예: "/usr/lib/nagios/plugins/check_disk -w 20% -c 10% -p /"
+    #[serde(default = "default_timeout_seconds")]
+    pub timeout_seconds: u64,           // 넘기면 CRITICAL
+}
+
+impl CommandCheckConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.command.trim().is_empty() || self.command.len() > MAX_COMMAND_LENGTH {
+            return Err(format!("command must be 1-{} characters", MAX_COMMAND_LENGTH));
+        }
+        validate_timeout(self.timeout_seconds)
+    }
+}
+
+fn validate_timeout(timeout_seconds: u64) -> Result<(), String> {
+    if !(1..=MAX_CHECK_TIMEOUT_SECONDS).contains(&timeout_seconds) {
+        return Err(format!("timeout_seconds must be 1-{}", MAX_CHECK_TIMEOUT_SECONDS));
+    }
+    Ok(())
+}
+
+// TLS 검사가 받은 leaf 인증서와 체인 검증 결과
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CertificateInfo {
+    pub subject: String,
+    pub issuer: String,
+    pub sans: Vec<String>,              // DNS 이름과 IP
+    pub serial: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub chain_valid: bool,              // 신뢰 루트까지 이어지고 이름이 맞는지
+    pub chain_error: Option<String>,
+}
+
+impl CertificateInfo {
+    // 만료까지 남은 일수. 이미 만료됐으면 음수
+    pub fn days_left(&self, now: DateTime<Utc>) -> i64 {
+        (self.not_after - now).num_days()
+    }
+}
+
+// 검사 한 번의 결과
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CheckOutcome {
+    pub success: bool,
+    pub response_time_ms: Option<f64>,  // 연결조차 못 하면 None
+    pub status_code: Option<u16>,       // HTTP 상태 코드, DNS 는 RCODE
+    pub message: Option<String>,        // 실패 사유
+}
+
+impl CheckOutcome {
+    pub fn up(response_time_ms: f64, status_code: Option<u16>) -> Self {
+        Self { success: true, response_time_ms: Some(response_time_ms), status_code, message: None }
+    }
+
+    pub fn down(response_time_ms: Option<f64>, status_code: Option<u16>, message: impl Into<String>) -> Self {
+        Self { success: false, response_time_ms, status_code, message: Some(message.into()) }
+    }
+}
@@ -0,0 +1,18 @@
+// src/models/container.rs
+use serde::{Deserialize, Serialize};
+
+// 컨테이너 하나의 수집 결과. 비율 값은 직전 샘플과의 차이로 계산한다
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContainerMetrics {
+    pub id: String,
+    pub name: String,
+    pub image: Option<String>,          // cgroup 으로 수집하면 None
+    pub state: String,                  // running | exited | paused ...
+    pub cpu_usage: f32,                 // 코어 하나 기준 % (여러 코어를 쓰면 100 을 넘는다)
+    pub memory_usage: u64,              // bytes (page cache 제외)
+    pub memory_limit: Option<u64>,
+    pub network_rx: Option<f64>,        // bytes/s
+    pub network_tx: Option<f64>,
+    pub restart_count: Option<u32>,     // 엔진 API 로 수집할 때만 제공
+}
+
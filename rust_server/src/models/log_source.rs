@@ -0,0 +1,76 @@
+// src/models/log_source.rs
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use crate::models::logs::LogLevel;
+
+pub const MAX_LOG_PATH_LENGTH: usize = 4096;
+
+// 줄이 pattern(정규식)에 맞으면 level. 위에서부터 처음 맞는 규칙을 쓴다
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LevelRule {
+    pub pattern: String,
+    pub level: LogLevel,
+}
+
+// 규칙을 주지 않았을 때 쓰는 흔한 키워드
+pub fn default_level_rules() -> Vec<LevelRule> {
+    [
+        (r"(?i)\b(emerg|emergency|panic|fatal|crit|critical)\b", LogLevel::Critical),
+        (r"(?i)\b(alert|error|err)\b", LogLevel::Alert),
+        (r"(?i)\b(warn|warning)\b", LogLevel::Warning),
+        (r"(?i)\b(debug|trace)\b", LogLevel::Debug),
+    ]
+    .into_iter()
+    .map(|(pattern, level)| LevelRule { pattern: pattern.to_string(), level })
+    .collect()
+}
+
+pub struct LevelParser {
+    rules: Vec<(Regex, LogLevel)>,
+    default: LogLevel,
+}
+
+impl LevelParser {
+    // rules 가 비어 있으면 기본 규칙
+    pub fn new(rules: &[LevelRule], default: LogLevel) -> Result<Self, String> {
+        let defaults;
+        let rules = if rules.is_empty() {
+            defaults = default_level_rules();
+            &defaults
+        } else {
+            rules
+        };
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                Regex::new(&rule.pattern)
+                    .map(|regex| (regex, rule.level.clone()))
+                    .map_err(|e| format!("Invalid level pattern '{}': {}", rule.pattern, e))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { rules, default })
+    }
+
+    pub fn level(&self, line: &str) -> LogLevel {
+        self.rules
+            .iter()
+            .find(|(regex, _)| regex.is_match(line))
+            .map_or_else(|| self.default.clone(), |(_, level)| level.clone())
+    }
+}
+
+// 원격 명령에 작은따옴표로 감싸 넣으므로 따옴표와 제어 문자는 받지 않는다
+pub fn validate_log_path(path: &str) -> Result<(), String> {
+    if !path.starts_with('/') || path.len() > MAX_LOG_PATH_LENGTH || path.ends_with('/') {
+        return Err(format!("path must be an absolute file path (max {} characters)", MAX_LOG_PATH_LENGTH));
+    }
+    if path.contains('\'') || path.chars().any(char::is_control) {
+        return Err("path must not contain quotes or control characters".into());
+    }
+    Ok(())
+}
+
+// 경로의 파일 이름 - component 기본값
+pub fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
@@ -1,6 +1,6 @@
 // src/models/metrics.rs
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -8,11 +8,168 @@ pub struct ServerMetrics {
     pub cpu_usage: f32,
     pub memory_usage: f32,
     pub disk_usage: f32,
-    pub network_rx: u64,
-    pub network_tx: u64,
+    pub network_rx: u64,                     // 수신 bytes/s (제외 대상 인터페이스 빼고 합산)
+    pub network_tx: u64,                     // 송신 bytes/s
     pub timestamp: DateTime<Utc>,
     #[serde(default)]
     pub processes: Vec<ProcessMetrics>,
+    #[serde(default)]
+    pub cpu_per_core: Vec<f32>,              // 코어별 사용률(%)
+    #[serde(default)]
+    pub load_average: Option<LoadAverage>,
+    #[serde(default)]
+    pub cpu_breakdown: Option<CpuBreakdown>,
+    #[serde(default)]
+    pub filesystems: Vec<FilesystemMetrics>,
+    #[serde(default)]
+    pub network_interfaces: Vec<NetworkInterfaceMetrics>,
+    #[serde(default)]
+    pub disk_io: Vec<DiskIoMetrics>,
+    #[serde(default)]
+    pub memory: Option<MemoryMetrics>,
+    #[serde(default)]
+    pub host: Option<HostInfo>,
+}
+
+// 부팅 시각, 커널, OS. 재부팅을 바로 알 수 있도록 매 샘플에 함께 싣는다
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HostInfo {
+    pub boot_time: DateTime<Utc>,
+    pub uptime_seconds: u64,
+    pub kernel: Option<String>,
+    pub os: Option<String>,
+}
+
+impl HostInfo {
+    // 초 단위로 잘라 샘플마다 부팅 시각이 흔들리지 않게 한다
+    pub fn from_uptime(uptime_seconds: f64, now: DateTime<Utc>, kernel: Option<String>, os: Option<String>) -> Self {
+        let uptime_seconds = uptime_seconds.max(0.0) as u64;
+        let boot_time = Utc
+            .timestamp_opt(now.timestamp() - uptime_seconds as i64, 0)
+            .single()
+            .unwrap_or(now);
+        Self { boot_time, uptime_seconds, kernel, os }
+    }
+
+    // "3d 4h 12m" 형식
+    pub fn uptime_display(&self) -> String {
+        let days = self.uptime_seconds / 86_400;
+        let hours = self.uptime_seconds % 86_400 / 3_600;
+        let minutes = self.uptime_seconds % 3_600 / 60;
+        match (days, hours) {
+            (0, 0) => format!("{}m", minutes),
+            (0, _) => format!("{}h {}m", hours, minutes),
+            _ => format!("{}d {}h {}m", days, hours, minutes),
+        }
+    }
+}
+
+// 1/5/15분 load average
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+// CPU 시간 비율(%) - user 는 nice, system 은 irq/softirq 를 포함
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct CpuBreakdown {
+    pub user: f32,
+    pub system: f32,
+    pub iowait: f32,
+    pub steal: f32,
+}
+
+// 메모리 상세 (bytes). used 는 total - available 로, 회수 가능한 캐시는 사용량에 넣지 않는다
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct MemoryMetrics {
+    pub total: u64,
+    pub used: u64,
+    pub available: u64,
+    pub cached: u64,
+    pub buffers: u64,
+    pub swap_total: u64,
+    pub swap_used: u64,
+}
+
+impl MemoryMetrics {
+    pub fn usage_percent(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        (self.used as f64 / self.total as f64 * 100.0) as f32
+    }
+
+    // 스왑이 없으면 None
+    pub fn swap_usage_percent(&self) -> Option<f32> {
+        if self.swap_total == 0 {
+            return None;
+        }
+        Some((self.swap_used as f64 / self.swap_total as f64 * 100.0) as f32)
+    }
+}
+
+// 마운트 포인트별 사용량 (bytes)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct FilesystemMetrics {
+    pub mount_point: String,
+    pub device: String,
+    pub fs_type: String,
+    pub total: u64,
+    pub used: u64,
+    pub free: u64,           // 일반 사용자가 쓸 수 있는 공간 (예약 블록 제외)
+    #[serde(default)]
+    pub inodes_total: Option<u64>,
+    #[serde(default)]
+    pub inodes_used: Option<u64>,
+    #[serde(default)]
+    pub inodes_free: Option<u64>,
+}
+
+// 인터페이스별 처리량 - 이전 샘플과의 카운터 차이를 실제 경과 시간으로 나눈 값
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct NetworkInterfaceMetrics {
+    pub interface: String,
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+    pub rx_packets_per_sec: f64,
+    pub tx_packets_per_sec: f64,
+    // 오류/드롭은 구간 동안 늘어난 개수
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_drops: u64,
+    pub tx_drops: u64,
+}
+
+// 블록 장치별 I/O - 이전 샘플과의 /proc/diskstats 차이로 계산
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct DiskIoMetrics {
+    pub device: String,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    pub read_iops: f64,
+    pub write_iops: f64,
+    pub await_ms: f64,            // I/O 당 평균 대기 시간
+    pub utilization: f32,         // 장치가 바빴던 시간 비율(%)
+}
+
+impl FilesystemMetrics {
+    // df 의 Capacity 와 같은 기준 (used / (used + free))
+    pub fn usage_percent(&self) -> f32 {
+        let usable = self.used + self.free;
+        if usable == 0 {
+            return 0.0;
+        }
+        (self.used as f64 / usable as f64 * 100.0) as f32
+    }
+
+    pub fn inode_usage_percent(&self) -> Option<f32> {
+        match (self.inodes_total, self.inodes_used) {
+            (Some(total), Some(used)) if total > 0 => Some((used as f64 / total as f64 * 100.0) as f32),
+            _ => None,
+        }
+    }
 }
 
 impl ServerMetrics {
@@ -32,6 +189,14 @@ impl ServerMetrics {
             network_tx,
             timestamp: Utc::now(),
             processes,
+            cpu_per_core: Vec::new(),
+            load_average: None,
+            cpu_breakdown: None,
+            filesystems: Vec::new(),
+            network_interfaces: Vec::new(),
+            disk_io: Vec::new(),
+            memory: None,
+            host: None,
         }
     }
 
@@ -46,7 +211,29 @@ impl ServerMetrics {
     pub fn total_network_usage(&self) -> u64 {
         self.network_rx + self.network_tx
     }
+
+    // 가장 바쁜 장치의 사용률 - 하나만 포화돼도 전체가 느려진다
+    pub fn max_disk_io_utilization(&self) -> Option<f32> {
+        self.disk_io.iter().map(|d| d.utilization).reduce(f32::max)
+    }
+}
+// 에이전트가 한 번에 전송하는 메트릭 묶음
+// 서버는 MAX_BATCH_SIZE 보다 큰 배치를 거절한다
+pub const MAX_BATCH_SIZE: usize = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsBatch {
+    pub metrics: Vec<ServerMetrics>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct IngestResult {
+    pub accepted: usize,
+    pub rejected: usize,
+    #[serde(default)]
+    pub errors: Vec<String>,
 }
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessMetrics {
     pub pid: u32,
@@ -68,7 +255,7 @@ impl ProcessMetrics {
 
     // 프로세스가 높은 CPU 사용률을 보이는지 확인
     pub fn is_high_cpu(&self) -> bool {
-        self.cpu_usage > 80.0
+        self.exceeds_cpu(80.0)
     }
 
     // 프로세스가 높은 메모리 사용률을 보이는지 확인
@@ -76,6 +263,15 @@ impl ProcessMetrics {
         let memory_percentage = (self.memory_usage as f64 / total_memory as f64) * 100.0;
         memory_percentage > 80.0
     }
+
+    // 감시 대상별 상한 비교 (CPU %, RSS bytes)
+    pub fn exceeds_cpu(&self, limit: f32) -> bool {
+        self.cpu_usage > limit
+    }
+
+    pub fn exceeds_memory(&self, limit: u64) -> bool {
+        self.memory_usage > limit
+    }
 }
 
 // 테스트 코드 추가
@@ -117,4 +313,16 @@ mod tests {
         assert!(!process_low.is_high_cpu());
         assert!(!process_low.is_high_memory(1000)); // 10% 사용
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_host_info_uptime() {
+        let now = Utc.timestamp_opt(1_700_000_000, 500_000_000).single().unwrap();
+        let host = HostInfo::from_uptime(273_125.7, now, None, None);
+        assert_eq!(host.uptime_seconds, 273_125);
+        assert_eq!(host.boot_time.timestamp(), 1_700_000_000 - 273_125);
+        assert_eq!(host.uptime_display(), "3d 3h 52m");
+
+        assert_eq!(HostInfo::from_uptime(4_000.0, now, None, None).uptime_display(), "1h 6m");
+        assert_eq!(HostInfo::from_uptime(59.0, now, None, None).uptime_display(), "0m");
+    }
+}
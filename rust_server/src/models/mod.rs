@@ -1,4 +1,14 @@
 // src/models/mod.rs
+pub mod agent;
+pub mod check;
+pub mod container;
 pub mod metrics;
+pub mod log_source;
 pub mod logs;
+pub mod package;
+pub mod plugin;
+pub mod port;
+pub mod process;
+pub mod service;
+pub mod syslog;
 // pub use metrics::*;
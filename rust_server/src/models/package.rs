@@ -0,0 +1,101 @@
+// src/models/package.rs
+use std::cmp::Ordering;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InstalledPackage {
+    pub name: String,
+    pub version: String,          // [epoch:]version[-release]
+    pub architecture: String,     // 모르면 빈 문자열
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PackageUpdate {
+    pub name: String,
+    pub current_version: Option<String>,
+    pub available_version: String,
+    pub security: bool,
+}
+
+// 패키지 관리자 한 번 조회한 결과
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct PackageInventory {
+    pub manager: String,          // dpkg, rpm
+    pub packages: Vec<InstalledPackage>,
+    pub updates: Vec<PackageUpdate>,
+}
+
+impl PackageInventory {
+    pub fn security_update_count(&self) -> usize {
+        self.updates.iter().filter(|u| u.security).count()
+    }
+}
+
+// dpkg 방식 버전 비교 (epoch, upstream, revision 순). rpm 버전도 같은 규칙으로 충분히 비교된다
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (epoch_a, rest_a) = split_epoch(a);
+    let (epoch_b, rest_b) = split_epoch(b);
+    epoch_a.cmp(&epoch_b).then_with(|| {
+        let (upstream_a, revision_a) = rest_a.rsplit_once('-').unwrap_or((rest_a, ""));
+        let (upstream_b, revision_b) = rest_b.rsplit_once('-').unwrap_or((rest_b, ""));
+        compare_part(upstream_a, upstream_b).then_with(|| compare_part(revision_a, revision_b))
+    })
+}
+
+fn split_epoch(version: &str) -> (u64, &str) {
+    match version.split_once(':') {
+        Some((epoch, rest)) if epoch.chars().all(|c| c.is_ascii_digit()) => {
+            (epoch.parse().unwrap_or(0), rest)
+        }
+        _ => (0, version),
+    }
+}
+
+// 문자 부분과 숫자 부분을 번갈아 비교한다. '~' 는 빈 문자열보다도 앞선다
+fn compare_part(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a.as_bytes(), b.as_bytes());
+    while !a.is_empty() || !b.is_empty() {
+        let (alpha_a, rest_a) = split_while(a, |c| !c.is_ascii_digit());
+        let (alpha_b, rest_b) = split_while(b, |c| !c.is_ascii_digit());
+        let ordering = compare_alpha(alpha_a, alpha_b);
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        let (digits_a, rest_a) = split_while(rest_a, |c| c.is_ascii_digit());
+        let (digits_b, rest_b) = split_while(rest_b, |c| c.is_ascii_digit());
+        let ordering = compare_digits(digits_a, digits_b);
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        a = rest_a;
+        b = rest_b;
+    }
+    Ordering::Equal
+}
+
+fn split_while(input: &[u8], predicate: impl Fn(u8) -> bool) -> (&[u8], &[u8]) {
+    let end = input.iter().position(|&c| !predicate(c)).unwrap_or(input.len());
+    input.split_at(end)
+}
+
+fn compare_alpha(a: &[u8], b: &[u8]) -> Ordering {
+    // '~' < 끝 < 문자 < 그 외 기호
+    let weight = |c: Option<&u8>| match c {
+        Some(b'~') => -1,
+        None => 0,
+        Some(c) if c.is_ascii_alphabetic() => *c as i32,
+        Some(c) => *c as i32 + 256,
+    };
+    let len = a.len().max(b.len());
+    (0..len)
+        .map(|i| weight(a.get(i)).cmp(&weight(b.get(i))))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+fn compare_digits(a: &[u8], b: &[u8]) -> Ordering {
+    let trim = |s: &[u8]| -> Vec<u8> { s.iter().copied().skip_while(|&c| c == b'0').collect() };
+    let (a, b) = (trim(a), trim(b));
+    a.len().cmp(&b.len()).then_with(|| a.cmp(&b))
+}
@@ -0,0 +1,176 @@
+// src/models/plugin.rs
+use serde::{Deserialize, Serialize};
+
+const NO_OUTPUT: &str = "(No output returned from plugin)";
+
+// Nagios 플러그인 종료 코드 규약
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PluginStatus {
+    Ok,
+    Warning,
+    Critical,
+    Unknown,
+}
+
+impl PluginStatus {
+    // 0~3 밖의 코드는 UNKNOWN
+    pub fn from_exit_code(code: i32) -> Self {
+        match code {
+            0 => PluginStatus::Ok,
+            1 => PluginStatus::Warning,
+            2 => PluginStatus::Critical,
+            _ => PluginStatus::Unknown,
+        }
+    }
+
+    pub fn code(&self) -> u16 {
+        match self {
+            PluginStatus::Ok => 0,
+            PluginStatus::Warning => 1,
+            PluginStatus::Critical => 2,
+            PluginStatus::Unknown => 3,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluginStatus::Ok => "OK",
+            PluginStatus::Warning => "WARNING",
+            PluginStatus::Critical => "CRITICAL",
+            PluginStatus::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+// 성능 데이터 한 항목: 'label'=value[UOM];[warn];[crit];[min];[max]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PerfData {
+    pub label: String,
+    pub value: f64,
+    pub unit: Option<String>,
+    pub warn: Option<String>,           // 범위 문법 그대로 ("10", "@5:20" 등)
+    pub crit: Option<String>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+// 플러그인 실행 결과
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PluginOutput {
+    pub status: PluginStatus,
+    pub summary: String,                // 첫 줄의 '|' 앞부분
+    pub perfdata: Vec<PerfData>,
+}
+
+impl PluginOutput {
+    pub fn unknown(summary: impl Into<String>) -> Self {
+        Self { status: PluginStatus::Unknown, summary: summary.into(), perfdata: Vec::new() }
+    }
+
+    // 첫 줄은 "TEXT | PERFDATA", 이후 줄은 긴 출력이고 그 안의 첫 '|' 뒤는 모두 성능 데이터
+    pub fn parse(exit_code: i32, output: &str) -> Self {
+        let mut lines = output.lines();
+        let (summary, first_perf) = match lines.next() {
+            Some(line) => split_perf(line),
+            None => ("", None),
+        };
+
+        let mut perf_text: Vec<&str> = first_perf.into_iter().collect();
+        let mut in_perf = false;
+        for line in lines {
+            if in_perf {
+                perf_text.push(line);
+            } else if let (_, Some(perf)) = split_perf(line) {
+                perf_text.push(perf);
+                in_perf = true;
+            }
+        }
+
+        let mut summary = if summary.is_empty() { NO_OUTPUT.to_string() } else { summary.to_string() };
+        if !(0..=3).contains(&exit_code) {
+            summary = format!("Return code of {} is out of bounds: {}", exit_code, summary);
+        }
+        Self {
+            status: PluginStatus::from_exit_code(exit_code),
+            summary,
+            perfdata: perf_text.iter().flat_map(|text| parse_perfdata(text)).collect(),
+        }
+    }
+}
+
+fn split_perf(line: &str) -> (&str, Option<&str>) {
+    match line.split_once('|') {
+        Some((text, perf)) => (text.trim(), Some(perf)),
+        None => (line.trim(), None),
+    }
+}
+
+// 공백으로 구분된 항목들. 라벨은 작은따옴표로 감쌀 수 있다 ('' 는 따옴표 하나).
+// 값이 U 이거나 읽을 수 없는 항목은 건너뛴다
+pub fn parse_perfdata(text: &str) -> Vec<PerfData> {
+    let mut items = Vec::new();
+    let mut chars = text.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut label = String::new();
+        if chars.next_if_eq(&'\'').is_some() {
+            while let Some(c) = chars.next() {
+                if c == '\'' && chars.next_if_eq(&'\'').is_none() {
+                    break;
+                }
+                label.push(c);
+            }
+        }
+        while let Some(c) = chars.next_if(|c| *c != '=' && !c.is_whitespace()) {
+            label.push(c);
+        }
+        if chars.next_if_eq(&'=').is_none() {
+            // '=' 없는 토큰은 버린다
+            while chars.next_if(|c| !c.is_whitespace()).is_some() {}
+            continue;
+        }
+
+        let mut value = String::new();
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+            value.push(c);
+        }
+        if let Some(item) = parse_item(label, &value) {
+            items.push(item);
+        }
+    }
+    items
+}
+
+fn parse_item(label: String, text: &str) -> Option<PerfData> {
+    if label.is_empty() {
+        return None;
+    }
+    let mut fields = text.split(';');
+    let raw = fields.next()?;
+    let split = raw
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+        .unwrap_or(raw.len());
+    let value: f64 = raw[..split].parse().ok()?;
+    let unit = &raw[split..];
+
+    let mut field = || fields.next().map(str::trim).filter(|f| !f.is_empty()).map(str::to_string);
+    let warn = field();
+    let crit = field();
+    let min = field().and_then(|f| f.parse().ok());
+    let max = field().and_then(|f| f.parse().ok());
+
+    Some(PerfData {
+        label,
+        value,
+        unit: (!unit.is_empty()).then(|| unit.to_string()),
+        warn,
+        crit,
+        min,
+        max,
+    })
+}
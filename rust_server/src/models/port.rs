@@ -0,0 +1,19 @@
+// src/models/port.rs
+use serde::{Deserialize, Serialize};
+
+// 대기 중인 소켓 하나 (`ss -tulpn` 또는 /proc/net/{tcp,udp}{,6} 기준)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ListeningPort {
+    pub protocol: String,           // tcp | udp
+    pub address: String,            // 0.0.0.0, ::, 127.0.0.1 ...
+    pub port: u16,
+    pub process: Option<String>,    // 권한이 없거나 /proc 로 읽으면 None
+    pub pid: Option<u32>,
+}
+
+impl ListeningPort {
+    // 같은 소켓인지 비교할 때 쓰는 키 - 프로세스가 바뀌어도 같은 포트로 본다
+    pub fn key(&self) -> (&str, &str, u16) {
+        (&self.protocol, &self.address, self.port)
+    }
+}
@@ -0,0 +1,211 @@
+// src/models/process.rs
+use std::cmp::Ordering;
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use crate::models::metrics::ProcessMetrics;
+
+pub const DEFAULT_PROCESS_PAGE_SIZE: usize = 50;
+pub const MAX_PROCESS_PAGE_SIZE: usize = 1000;
+
+// 요청 시점에 수집하는 프로세스 상세 정보. 수집원이 제공하지 못하는 항목은 None
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct ProcessDetail {
+    pub pid: u32,
+    pub ppid: Option<u32>,
+    pub name: String,
+    pub user: Option<String>,
+    pub command: String,
+    pub state: String,
+    pub threads: Option<u32>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub cpu_usage: f32,
+    pub memory_usage: u64,          // RSS (bytes)
+    pub open_files: Option<u32>,    // 권한이 없으면 None
+}
+
+impl From<&ProcessDetail> for ProcessMetrics {
+    fn from(detail: &ProcessDetail) -> Self {
+        ProcessMetrics::new(detail.pid, detail.name.clone(), detail.cpu_usage, detail.memory_usage)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProcessSortKey {
+    Pid,
+    Ppid,
+    Name,
+    User,
+    Command,
+    State,
+    Threads,
+    StartTime,
+    Cpu,
+    Memory,
+    OpenFiles,
+}
+
+impl std::str::FromStr for ProcessSortKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pid" => Ok(Self::Pid),
+            "ppid" => Ok(Self::Ppid),
+            "name" => Ok(Self::Name),
+            "user" => Ok(Self::User),
+            "command" => Ok(Self::Command),
+            "state" => Ok(Self::State),
+            "threads" => Ok(Self::Threads),
+            "start_time" => Ok(Self::StartTime),
+            "cpu" | "cpu_usage" => Ok(Self::Cpu),
+            "memory" | "memory_usage" => Ok(Self::Memory),
+            "open_files" => Ok(Self::OpenFiles),
+            _ => Err(format!("Unknown sort column '{}'", s)),
+        }
+    }
+}
+
+impl ProcessSortKey {
+    fn compare(&self, a: &ProcessDetail, b: &ProcessDetail) -> Ordering {
+        match self {
+            Self::Pid => a.pid.cmp(&b.pid),
+            Self::Ppid => a.ppid.cmp(&b.ppid),
+            Self::Name => a.name.cmp(&b.name),
+            Self::User => a.user.cmp(&b.user),
+            Self::Command => a.command.cmp(&b.command),
+            Self::State => a.state.cmp(&b.state),
+            Self::Threads => a.threads.cmp(&b.threads),
+            Self::StartTime => a.start_time.cmp(&b.start_time),
+            Self::Cpu => a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(Ordering::Equal),
+            Self::Memory => a.memory_usage.cmp(&b.memory_usage),
+            Self::OpenFiles => a.open_files.cmp(&b.open_files),
+        }
+    }
+}
+
+// GET /servers/{id}/processes 쿼리
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ProcessQuery {
+    pub sort: Option<String>,
+    pub order: Option<String>,      // asc | desc
+    pub name: Option<String>,       // 이름/명령줄 정규식
+    pub user: Option<String>,       // 사용자 정규식
+    pub page: Option<usize>,        // 1부터
+    pub per_page: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessPage {
+    pub total: usize,
+    pub page: usize,
+    pub per_page: usize,
+    pub processes: Vec<ProcessDetail>,
+}
+
+impl ProcessQuery {
+    // 필터 -> 정렬 -> 페이지 순으로 적용. 잘못된 파라미터는 Err 로 돌려준다
+    pub fn apply(&self, mut processes: Vec<ProcessDetail>) -> Result<ProcessPage, String> {
+        let sort: ProcessSortKey = self.sort.as_deref().unwrap_or("cpu").parse()?;
+        let descending = match self.order.as_deref() {
+            None => matches!(sort, ProcessSortKey::Cpu | ProcessSortKey::Memory),
+            Some("desc") => true,
+            Some("asc") => false,
+            Some(other) => return Err(format!("order must be 'asc' or 'desc' (got '{}')", other)),
+        };
+        let page = self.page.unwrap_or(1);
+        if page == 0 {
+            return Err("page starts at 1".into());
+        }
+        let per_page = self.per_page.unwrap_or(DEFAULT_PROCESS_PAGE_SIZE);
+        if per_page == 0 || per_page > MAX_PROCESS_PAGE_SIZE {
+            return Err(format!("per_page must be between 1 and {}", MAX_PROCESS_PAGE_SIZE));
+        }
+
+        let name = compile(self.name.as_deref(), "name")?;
+        let user = compile(self.user.as_deref(), "user")?;
+        processes.retain(|p| {
+            name.as_ref().is_none_or(|re| re.is_match(&p.name) || re.is_match(&p.command))
+                && user.as_ref().is_none_or(|re| p.user.as_deref().is_some_and(|u| re.is_match(u)))
+        });
+
+        // 같은 값이면 pid 순으로 고정해 페이지 사이에 순서가 흔들리지 않게 한다
+        processes.sort_by(|a, b| {
+            let ordering = sort.compare(a, b);
+            let ordering = if descending { ordering.reverse() } else { ordering };
+            ordering.then(a.pid.cmp(&b.pid))
+        });
+
+        let total = processes.len();
+        // 넘칠 만큼 큰 page 는 빈 페이지
+        let processes = match (page - 1).checked_mul(per_page) {
+            Some(offset) => processes.into_iter().skip(offset).take(per_page).collect(),
+            None => Vec::new(),
+        };
+
+        Ok(ProcessPage { total, page, per_page, processes })
+    }
+}
+
+fn compile(pattern: Option<&str>, field: &str) -> Result<Option<Regex>, String> {
+    pattern
+        .filter(|p| !p.is_empty())
+        .map(|p| Regex::new(p).map_err(|e| format!("Invalid {} pattern: {}", field, e)))
+        .transpose()
+}
+
+pub const DEFAULT_TOP_PROCESS_LIMIT: i64 = 10;
+pub const MAX_TOP_PROCESS_LIMIT: i64 = 100;
+
+// GET /servers/{id}/processes/top 쿼리
+#[derive(Debug, Clone, Deserialize)]
+pub struct TopProcessQuery {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub by: Option<String>,         // cpu | memory
+    pub aggregate: Option<String>,  // avg | max
+    pub limit: Option<i64>,
+}
+
+impl TopProcessQuery {
+    // 저장소 정렬 키(cpu_avg 등)와 limit 를 돌려준다
+    pub fn ranking(&self) -> Result<(&'static str, i64), String> {
+        validate_range(self.from, self.to)?;
+        let rank_by = match (self.by.as_deref().unwrap_or("cpu"), self.aggregate.as_deref().unwrap_or("avg")) {
+            ("cpu", "avg") => "cpu_avg",
+            ("cpu", "max") => "cpu_max",
+            ("memory", "avg") => "memory_avg",
+            ("memory", "max") => "memory_max",
+            ("cpu" | "memory", other) => {
+                return Err(format!("aggregate must be 'avg' or 'max' (got '{}')", other))
+            }
+            (other, _) => return Err(format!("by must be 'cpu' or 'memory' (got '{}')", other)),
+        };
+        let limit = self.limit.unwrap_or(DEFAULT_TOP_PROCESS_LIMIT);
+        if !(1..=MAX_TOP_PROCESS_LIMIT).contains(&limit) {
+            return Err(format!("limit must be between 1 and {}", MAX_TOP_PROCESS_LIMIT));
+        }
+        Ok((rank_by, limit))
+    }
+}
+
+// GET /servers/{id}/processes/{pid}/history 쿼리
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProcessHistoryQuery {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub name: Option<String>,       // pid 재사용 구분용 정확한 프로세스 이름
+}
+
+impl ProcessHistoryQuery {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_range(self.from, self.to)
+    }
+}
+
+fn validate_range(from: DateTime<Utc>, to: DateTime<Utc>) -> Result<(), String> {
+    if from >= to {
+        return Err("from must be earlier than to".into());
+    }
+    Ok(())
+}
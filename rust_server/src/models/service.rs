@@ -0,0 +1,30 @@
+// src/models/service.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// systemd 유닛 상태 (`systemctl show` 기준)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ServiceStatus {
+    pub unit: String,
+    pub load_state: String,             // loaded | not-found | masked ...
+    pub active_state: String,           // active | inactive | failed | activating ...
+    pub sub_state: String,              // running | exited | dead ...
+    pub restarts: Option<u32>,          // NRestarts (systemd 235+)
+    pub since: Option<DateTime<Utc>>,   // 마지막 상태 변경 시각
+}
+
+impl ServiceStatus {
+    pub fn is_failed(&self) -> bool {
+        self.active_state == "failed"
+    }
+}
+
+// 유닛 이름으로 허용하는 문자 (셸 명령에 그대로 넣으므로 엄격하게 제한)
+pub fn is_valid_unit_name(unit: &str) -> bool {
+    !unit.is_empty()
+        && unit.len() <= 255
+        && !unit.starts_with('-')
+        && unit
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '@' | ':' | '.' | '_' | '-' | '\\'))
+}
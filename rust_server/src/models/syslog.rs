@@ -0,0 +1,206 @@
+// src/models/syslog.rs
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::logs::LogLevel;
+
+// PRI 가 없는 메시지는 user.notice 로 본다 (RFC 3164 4.3.3)
+const DEFAULT_PRI: u8 = 13;
+
+const FACILITIES: [&str; 24] = [
+    "kern", "user", "mail", "daemon", "auth", "syslog", "lpr", "news", "uucp", "cron", "authpriv", "ftp",
+    "ntp", "security", "console", "solaris-cron", "local0", "local1", "local2", "local3", "local4", "local5",
+    "local6", "local7",
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SyslogFormat {
+    Rfc5424,
+    Rfc3164,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SyslogMessage {
+    pub format: SyslogFormat,
+    pub facility: u8,
+    pub severity: u8,
+    pub timestamp: Option<DateTime<Utc>>,   // 없거나 읽을 수 없으면 None
+    pub hostname: Option<String>,
+    pub app_name: Option<String>,
+    pub proc_id: Option<String>,
+    pub msg_id: Option<String>,             // RFC 5424 만
+    pub structured_data: Option<String>,    // RFC 5424 만, 원문 그대로
+    pub message: String,
+}
+
+impl SyslogMessage {
+    // 5424 는 "<PRI>1 " 로 시작하고, 그 밖은 BSD 형식으로 최대한 읽는다.
+    // now 는 연도가 없는 BSD 타임스탬프의 연도를 정하는 데 쓴다
+    pub fn parse(input: &str, now: DateTime<Utc>) -> Self {
+        let input = input.trim_end_matches(['\r', '\n', '\0']);
+        let (pri, rest) = parse_pri(input).unwrap_or((DEFAULT_PRI, input));
+        match rest.strip_prefix("1 ") {
+            Some(rest) => parse_5424(pri, rest),
+            None => parse_3164(pri, rest, now),
+        }
+    }
+
+    // 0-2 (emerg, alert, crit) critical, 3 (err) alert, 4 warning, 5-6 (notice, info) info, 7 debug
+    pub fn level(&self) -> LogLevel {
+        match self.severity {
+            0..=2 => LogLevel::Critical,
+            3 => LogLevel::Alert,
+            4 => LogLevel::Warning,
+            5 | 6 => LogLevel::Info,
+            _ => LogLevel::Debug,
+        }
+    }
+
+    pub fn facility_name(&self) -> &'static str {
+        FACILITIES.get(self.facility as usize).copied().unwrap_or("unknown")
+    }
+}
+
+fn parse_pri(input: &str) -> Option<(u8, &str)> {
+    let rest = input.strip_prefix('<')?;
+    let end = rest.find('>')?;
+    let digits = &rest[..end];
+    if digits.is_empty() || digits.len() > 3 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let pri: u8 = digits.parse().ok().filter(|pri| *pri <= 191)?;
+    Some((pri, &rest[end + 1..]))
+}
+
+// "-" 는 값 없음
+fn nil(field: &str) -> Option<String> {
+    (field != "-" && !field.is_empty()).then(|| field.to_string())
+}
+
+// TIMESTAMP HOSTNAME APP-NAME PROCID MSGID STRUCTURED-DATA [MSG]
+fn parse_5424(pri: u8, rest: &str) -> SyslogMessage {
+    let mut fields = rest.splitn(6, ' ');
+    let mut next = || fields.next().unwrap_or("-");
+    let timestamp = DateTime::parse_from_rfc3339(next()).ok().map(|t| t.with_timezone(&Utc));
+    let hostname = nil(next());
+    let app_name = nil(next());
+    let proc_id = nil(next());
+    let msg_id = nil(next());
+    let (structured_data, message) = split_structured_data(next());
+
+    SyslogMessage {
+        format: SyslogFormat::Rfc5424,
+        facility: pri / 8,
+        severity: pri % 8,
+        timestamp,
+        hostname,
+        app_name,
+        proc_id,
+        msg_id,
+        structured_data: nil(structured_data),
+        message: message.trim_start_matches('\u{feff}').to_string(),
+    }
+}
+
+// "-" 이거나 [id k="v" ...] 요소들. 따옴표 안의 \" \] \\ 는 건너뛴다
+fn split_structured_data(rest: &str) -> (&str, &str) {
+    if !rest.starts_with('[') {
+        return match rest.split_once(' ') {
+            Some((sd, message)) => (sd, message),
+            None => (rest, ""),
+        };
+    }
+    let mut end = 0;
+    let (mut in_quotes, mut escaped, mut in_element) = (false, false, false);
+    for (i, c) in rest.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if in_quotes {
+            match c {
+                '\\' => escaped = true,
+                '"' => in_quotes = false,
+                _ => {}
+            }
+        } else if in_element {
+            match c {
+                '"' => in_quotes = true,
+                ']' => {
+                    in_element = false;
+                    end = i + 1;
+                }
+                _ => {}
+            }
+        } else if c == '[' {
+            in_element = true;
+        } else {
+            break;
+        }
+    }
+    if end == 0 {
+        // 닫히지 않은 SD 는 통째로 본문
+        return ("-", rest);
+    }
+    (&rest[..end], rest[end..].strip_prefix(' ').unwrap_or(&rest[end..]))
+}
+
+// Mmm dd hh:mm:ss HOSTNAME TAG[PID]: MSG. 타임스탬프와 호스트는 장비에 따라 빠지기도 한다
+fn parse_3164(pri: u8, rest: &str, now: DateTime<Utc>) -> SyslogMessage {
+    let (timestamp, rest) = match rest.get(..15).and_then(|stamp| bsd_timestamp(stamp, now)) {
+        Some(timestamp) => (Some(timestamp), rest[15..].trim_start()),
+        None => (None, rest),
+    };
+
+    // 첫 단어가 태그처럼 생기지 않았으면 호스트 이름
+    let (hostname, rest) = match rest.split_once(' ') {
+        Some((word, after)) if timestamp.is_some() && parse_tag(word).is_none() => {
+            (Some(word.to_string()), after)
+        }
+        _ => (None, rest),
+    };
+    let (word, after) = rest.split_once(' ').unwrap_or((rest, ""));
+    let (app_name, proc_id, message) = match parse_tag(word) {
+        Some((tag, pid)) => (Some(tag.to_string()), pid.map(str::to_string), after),
+        None => (None, None, rest),
+    };
+
+    SyslogMessage {
+        format: SyslogFormat::Rfc3164,
+        facility: pri / 8,
+        severity: pri % 8,
+        timestamp,
+        hostname,
+        app_name,
+        proc_id,
+        msg_id: None,
+        structured_data: None,
+        message: message.to_string(),
+    }
+}
+
+// "sshd[1234]:", "kernel:", "%LINK-3-UPDOWN:" 처럼 ':' 로 끝나는 단어
+fn parse_tag(word: &str) -> Option<(&str, Option<&str>)> {
+    let word = word.strip_suffix(':')?;
+    let (tag, pid) = match word.split_once('[') {
+        Some((tag, pid)) => (tag, Some(pid.strip_suffix(']')?)),
+        None => (word, None),
+    };
+    if tag.is_empty() || tag.len() > 48 || tag.contains(':') {
+        return None;
+    }
+    Some((tag, pid))
+}
+
+// 연도와 시간대가 없다. UTC 로 보고, 하루 넘게 미래가 되면 지난해로 본다
+fn bsd_timestamp(stamp: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let stamp = stamp.split_whitespace().collect::<Vec<_>>().join(" ");
+    let parse = |year: i32| {
+        NaiveDateTime::parse_from_str(&format!("{} {}", year, stamp), "%Y %b %d %H:%M:%S")
+            .ok()
+            .map(|t| t.and_utc())
+    };
+    let timestamp = parse(now.year())?;
+    if timestamp > now + Duration::days(1) {
+        return parse(now.year() - 1);
+    }
+    Some(timestamp)
+}
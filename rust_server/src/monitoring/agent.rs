@@ -0,0 +1,60 @@
+// src/monitoring/agent.rs
+use anyhow::anyhow;
+use futures::future::BoxFuture;
+use tokio::sync::RwLock;
+use crate::db::models::CollectionMethod;
+use crate::models::metrics::ServerMetrics;
+use crate::monitoring::traits::{MetricSource, SourceCapabilities};
+
+// 에이전트가 push 한 최신 메트릭을 보관하는 수집원
+#[derive(Default)]
+pub struct AgentSource {
+    latest: RwLock<Option<ServerMetrics>>,
+}
+
+impl AgentSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl MetricSource for AgentSource {
+    fn method(&self) -> CollectionMethod {
+        CollectionMethod::Agent
+    }
+
+    fn capabilities(&self) -> SourceCapabilities {
+        SourceCapabilities {
+            polled: false,
+            remote: true,
+            processes: true,
+            process_table: false,
+            services: false,
+            containers: false,
+            inventory: false,
+            packages: false,
+            ports: false,
+        }
+    }
+
+    fn collect(&self) -> BoxFuture<'_, anyhow::Result<ServerMetrics>> {
+        Box::pin(async move {
+            self.latest
+                .read()
+                .await
+                .clone()
+                .ok_or_else(|| anyhow!("No metrics pushed by agent yet"))
+        })
+    }
+
+    fn push(&self, metrics: ServerMetrics) -> BoxFuture<'_, bool> {
+        Box::pin(async move {
+            let mut latest = self.latest.write().await;
+            // 늦게 도착한 샘플로 최신 값을 덮어쓰지 않음
+            if latest.as_ref().is_none_or(|current| current.timestamp <= metrics.timestamp) {
+                *latest = Some(metrics);
+            }
+            true
+        })
+    }
+}
@@ -0,0 +1,201 @@
+// src/monitoring/alerts.rs
+use std::collections::HashMap;
+use std::sync::Mutex;
+use chrono::Utc;
+use crate::config::AlertThresholds;
+use crate::db::models::{Alert, AlertSeverity};
+use crate::models::metrics::ServerMetrics;
+
+// 임계값을 넘은 샘플로부터 알림을 만든다.
+// 같은 상태가 계속되는 동안에는 한 번만 알리고, 심각도가 올라가거나 해소 후 재발하면 다시 알린다.
+#[derive(Default)]
+pub struct AlertEvaluator {
+    thresholds: AlertThresholds,
+    active: Mutex<HashMap<(String, String), AlertSeverity>>,
+}
+
+impl AlertEvaluator {
+    pub fn new(thresholds: AlertThresholds) -> Self {
+        Self {
+            thresholds,
+            active: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn evaluate(&self, server_id: &str, metrics: &ServerMetrics) -> Vec<Alert> {
+        let mut checks = Vec::new();
+
+        // 메모리 사용량과 스왑은 따로 알린다 - 캐시가 많아도 스왑이 조용하면 문제가 아니다
+        checks.push(Check {
+            key: "memory_usage".to_string(),
+            alert_type: "memory_usage",
+            value: metrics.memory_usage,
+            warning: self.thresholds.memory_warning,
+            critical: self.thresholds.memory_critical,
+            unit: "%",
+            precision: 1,
+            message: "Memory usage".to_string(),
+        });
+        if let Some(swap_usage) = metrics.memory.and_then(|m| m.swap_usage_percent()) {
+            checks.push(Check {
+                key: "swap_usage".to_string(),
+                alert_type: "swap_usage",
+                value: swap_usage,
+                warning: self.thresholds.swap_warning,
+                critical: self.thresholds.swap_critical,
+                unit: "%",
+                precision: 1,
+                message: "Swap usage".to_string(),
+            });
+        }
+
+        for fs in &metrics.filesystems {
+            checks.push(Check {
+                key: format!("disk_usage:{}", fs.mount_point),
+                alert_type: "disk_usage",
+                value: fs.usage_percent(),
+                warning: self.thresholds.disk_warning,
+                critical: self.thresholds.disk_critical,
+                unit: "%",
+                precision: 1,
+                message: format!("Disk usage on {} ({})", fs.mount_point, fs.device),
+            });
+            if let Some(inode_usage) = fs.inode_usage_percent() {
+                checks.push(Check {
+                    key: format!("inode_usage:{}", fs.mount_point),
+                    alert_type: "inode_usage",
+                    value: inode_usage,
+                    warning: self.thresholds.inode_warning,
+                    critical: self.thresholds.inode_critical,
+                    unit: "%",
+                    precision: 1,
+                    message: format!("Inode usage on {} ({})", fs.mount_point, fs.device),
+                });
+            }
+        }
+
+        for disk in &metrics.disk_io {
+            checks.push(Check {
+                key: format!("disk_io_util:{}", disk.device),
+                alert_type: "disk_io_util",
+                value: disk.utilization,
+                warning: self.thresholds.disk_io_warning,
+                critical: self.thresholds.disk_io_critical,
+                unit: "%",
+                precision: 1,
+                message: format!("I/O utilization on {}", disk.device),
+            });
+            checks.push(Check {
+                key: format!("disk_await:{}", disk.device),
+                alert_type: "disk_await",
+                value: disk.await_ms as f32,
+                warning: self.thresholds.disk_await_warning_ms,
+                critical: self.thresholds.disk_await_critical_ms,
+                unit: "ms",
+                precision: 1,
+                message: format!("Average I/O wait on {}", disk.device),
+            });
+        }
+
+        self.raise(server_id, checks)
+    }
+
+    // 대기 중인 보안 업데이트 수
+    pub fn evaluate_security_updates(&self, server_id: &str, pending: usize) -> Vec<Alert> {
+        self.raise(server_id, vec![Check {
+            key: "security_updates".to_string(),
+            alert_type: "security_updates",
+            value: pending as f32,
+            warning: self.thresholds.security_updates_warning as f32,
+            critical: self.thresholds.security_updates_critical as f32,
+            unit: "",
+            precision: 0,
+            message: "Pending security updates".to_string(),
+        }])
+    }
+
+    fn raise(&self, server_id: &str, checks: Vec<Check>) -> Vec<Alert> {
+        let mut active = match self.active.lock() {
+            Ok(active) => active,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        checks
+            .into_iter()
+            .filter_map(|check| {
+                let key = (server_id.to_string(), check.key.clone());
+                match check.severity() {
+                    Some(severity) => {
+                        let previous = active.insert(key, severity.clone());
+                        if previous.is_none_or(|p| rank(&p) < rank(&severity)) {
+                            Some(check.into_alert(server_id, severity))
+                        } else {
+                            None
+                        }
+                    }
+                    None => {
+                        active.remove(&key);
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
+    // 서버 모니터링이 중지되면 상태를 비운다
+    pub fn clear(&self, server_id: &str) {
+        if let Ok(mut active) = self.active.lock() {
+            active.retain(|(id, _), _| id != server_id);
+        }
+    }
+}
+
+struct Check {
+    key: String,
+    alert_type: &'static str,
+    value: f32,
+    warning: f32,
+    critical: f32,
+    unit: &'static str,
+    precision: usize,
+    message: String,
+}
+
+impl Check {
+    fn severity(&self) -> Option<AlertSeverity> {
+        if self.value >= self.critical {
+            Some(AlertSeverity::Critical)
+        } else if self.value >= self.warning {
+            Some(AlertSeverity::Warning)
+        } else {
+            None
+        }
+    }
+
+    fn into_alert(self, server_id: &str, severity: AlertSeverity) -> Alert {
+        let threshold = match severity {
+            AlertSeverity::Critical => self.critical,
+            _ => self.warning,
+        };
+        Alert {
+            id: 0,
+            server_id: server_id.to_string(),
+            alert_type: self.alert_type.to_string(),
+            severity,
+            message: format!(
+                "{} is {:.*}{} (threshold {:.*}{})",
+                self.message, self.precision, self.value, self.unit, self.precision, threshold, self.unit
+            ),
+            created_at: Utc::now(),
+            acknowledged_at: None,
+            acknowledged_by: None,
+        }
+    }
+}
+
+pub(crate) fn rank(severity: &AlertSeverity) -> u8 {
+    match severity {
+        AlertSeverity::Info => 0,
+        AlertSeverity::Warning => 1,
+        AlertSeverity::Critical => 2,
+    }
+}
@@ -1,11 +1,29 @@
 // server/src/monitoring/collector.rs
 
-use sysinfo::{CpuExt, NetworkExt, PidExt, ProcessExt, System, SystemExt};
+use sysinfo::{CpuExt, NetworkExt, PidExt, ProcessExt, ProcessStatus, System, SystemExt, UserExt};
 use tokio::time::{self, Duration};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use std::iter::Iterator;
+use std::time::Instant;
 use sysinfo::DiskExt;
+use chrono::{TimeZone, Utc};
+use futures::future::BoxFuture;
+use crate::db::models::CollectionMethod;
+use crate::config::{DiskIoConfig, FilesystemConfig, NetworkConfig, DEFAULT_PROCESS_SNAPSHOT_LIMIT};
+use crate::models::agent::HostFacts;
+use crate::models::container::ContainerMetrics;
+use crate::models::metrics::{
+    CpuBreakdown, DiskIoMetrics, FilesystemMetrics, HostInfo, LoadAverage, MemoryMetrics,
+    NetworkInterfaceMetrics, ProcessMetrics, ServerMetrics,
+};
+use crate::models::package::PackageInventory;
+use crate::models::port::ListeningPort;
+use crate::models::process::ProcessDetail;
+use crate::monitoring::containers::ContainerCollector;
+use crate::monitoring::{inventory, packages, ports};
+use crate::monitoring::parsers::{self, CpuTimes, DfEntry, DfInodeEntry, DiskStats, NetDevStats};
+use crate::monitoring::traits::{MetricSource, SourceCapabilities};
 
 #[derive(Debug, Clone, serde::Serialize, Default)]
 pub struct SystemMetrics {
@@ -70,15 +88,8 @@ impl MetricsCollector {
     }
 
     fn calculate_disk_usage(system: &System) -> f32 {
-        let disks = system.disks();
-        if disks.is_empty() {
-            return 0.0;
-        }
-
-        let total: u64 = disks.iter().map(|disk| disk.total_space()).sum();
-        let used: u64 = disks.iter().map(|disk| disk.total_space() - disk.available_space()).sum();
-        
-        (used as f32 / total as f32) * 100.0
+        let filesystems = local_filesystems(system, &FilesystemConfig::default(), &[]);
+        parsers::filesystems_usage_percent(&filesystems)
     }
 
     fn collect_processes(system: &System) -> Vec<ProcessInfo> {
@@ -97,4 +108,425 @@ impl MetricsCollector {
     pub async fn get_current_metrics(&self) -> Option<SystemMetrics> {
         self.last_metrics.lock().await.clone()
     }
+}
+
+// 모니터링 서버 자신을 sysinfo 로 수집
+pub struct LocalSource {
+    system: Mutex<System>,
+    // 온디맨드 프로세스 표 전용. 주기 수집의 프로세스 CPU 기준점을 건드리지 않는다
+    process_table: Mutex<System>,
+    previous_cpu: Mutex<Option<CpuTimes>>,
+    previous_net: Mutex<Option<(Instant, Vec<NetDevStats>)>>,
+    previous_disks: Mutex<Option<(Instant, Vec<DiskStats>)>>,
+    filesystems: FilesystemConfig,
+    network: NetworkConfig,
+    disk_io: DiskIoConfig,
+    process_limit: usize,
+    containers: ContainerCollector,
+}
+
+impl LocalSource {
+    pub fn new() -> Self {
+        Self {
+            system: Mutex::new(System::new_all()),
+            process_table: Mutex::new(System::new()),
+            previous_cpu: Mutex::new(None),
+            previous_net: Mutex::new(None),
+            previous_disks: Mutex::new(None),
+            filesystems: FilesystemConfig::default(),
+            network: NetworkConfig::default(),
+            disk_io: DiskIoConfig::default(),
+            process_limit: DEFAULT_PROCESS_SNAPSHOT_LIMIT,
+            containers: ContainerCollector::new(),
+        }
+    }
+
+    pub fn with_filesystem_config(mut self, config: FilesystemConfig) -> Self {
+        self.filesystems = config;
+        self
+    }
+
+    pub fn with_network_config(mut self, config: NetworkConfig) -> Self {
+        self.network = config;
+        self
+    }
+
+    pub fn with_disk_io_config(mut self, config: DiskIoConfig) -> Self {
+        self.disk_io = config;
+        self
+    }
+
+    // 주기적 스냅샷에 담을 상위 프로세스 수
+    pub fn with_process_limit(mut self, limit: usize) -> Self {
+        self.process_limit = limit;
+        self
+    }
+
+    // sysinfo 는 iowait/steal 을 제공하지 않으므로 /proc/stat 이 있으면 직접 읽는다
+    async fn cpu_breakdown(&self) -> Option<CpuBreakdown> {
+        let content = tokio::fs::read_to_string("/proc/stat").await.ok()?;
+        let current = parsers::parse_proc_stat(&content).ok()?.total;
+
+        let mut previous = self.previous_cpu.lock().await;
+        let breakdown = current.breakdown_since(&previous.unwrap_or_default());
+        *previous = Some(current);
+        Some(breakdown)
+    }
+
+    // sysinfo 는 드롭 수를 제공하지 않으므로 /proc/net/dev 가 있으면 그쪽 카운터를 쓴다
+    async fn network_interfaces(&self, fallback: Vec<NetDevStats>) -> Vec<NetworkInterfaceMetrics> {
+        let current = match tokio::fs::read_to_string("/proc/net/dev").await {
+            Ok(content) => parsers::parse_net_dev(&content).unwrap_or(fallback),
+            Err(_) => fallback,
+        };
+        let now = Instant::now();
+
+        let mut previous = self.previous_net.lock().await;
+        let interfaces = match previous.as_ref() {
+            Some((at, prev)) => {
+                parsers::interface_rates(prev, &current, now.duration_since(*at).as_secs_f64(), &self.network)
+            }
+            None => Vec::new(),
+        };
+        *previous = Some((now, current));
+        interfaces
+    }
+
+    // sysinfo 에는 블록 장치 I/O 가 없으므로 Linux 에서만 제공
+    async fn disk_io(&self) -> Vec<DiskIoMetrics> {
+        let current = match tokio::fs::read_to_string("/proc/diskstats").await {
+            Ok(content) => match parsers::parse_diskstats(&content) {
+                Ok(disks) => disks,
+                Err(_) => return Vec::new(),
+            },
+            Err(_) => return Vec::new(),
+        };
+        let now = Instant::now();
+
+        let mut previous = self.previous_disks.lock().await;
+        let disk_io = match previous.as_ref() {
+            Some((at, prev)) => {
+                parsers::disk_io_rates(prev, &current, now.duration_since(*at).as_secs_f64(), &self.disk_io)
+            }
+            None => Vec::new(),
+        };
+        *previous = Some((now, current));
+        disk_io
+    }
+}
+
+impl Default for LocalSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricSource for LocalSource {
+    fn method(&self) -> CollectionMethod {
+        CollectionMethod::Local
+    }
+
+    fn capabilities(&self) -> SourceCapabilities {
+        SourceCapabilities {
+            polled: true,
+            remote: false,
+            processes: true,
+            process_table: true,
+            services: false,
+            containers: true,
+            inventory: true,
+            packages: true,
+            ports: true,
+        }
+    }
+
+    fn collect(&self) -> BoxFuture<'_, anyhow::Result<ServerMetrics>> {
+        Box::pin(async move {
+            let inodes = local_inodes().await;
+            let (mut metrics, counters) = {
+                let mut system = self.system.lock().await;
+                system.refresh_all();
+                (
+                    collect_server_metrics(&system, &self.filesystems, &inodes, self.process_limit),
+                    network_counters(&system),
+                )
+            };
+            metrics.cpu_breakdown = self.cpu_breakdown().await;
+            metrics.network_interfaces = self.network_interfaces(counters).await;
+            metrics.disk_io = self.disk_io().await;
+            if let Some(memory) = proc_meminfo().await {
+                metrics.memory_usage = memory.usage_percent();
+                metrics.memory = Some(memory);
+            }
+            let (rx, tx) = parsers::total_rates(&metrics.network_interfaces);
+            metrics.network_rx = rx;
+            metrics.network_tx = tx;
+            Ok(metrics)
+        })
+    }
+
+    fn processes(&self) -> BoxFuture<'_, anyhow::Result<Vec<ProcessDetail>>> {
+        Box::pin(async move {
+            let mut processes = {
+                let mut system = self.process_table.lock().await;
+                // 첫 조회는 CPU 기준점이 없으므로 최소 간격을 두고 한 번 더 갱신한다
+                if system.processes().is_empty() {
+                    system.refresh_processes();
+                    tokio::time::sleep(System::MINIMUM_CPU_UPDATE_INTERVAL).await;
+                }
+                system.refresh_processes();
+                system.refresh_users_list();
+                process_details(&system)
+            };
+            tokio::task::spawn_blocking(move || {
+                fill_proc_details(&mut processes);
+                processes
+            })
+            .await
+            .map_err(|e| anyhow::anyhow!("Task join error: {}", e))
+        })
+    }
+
+    fn containers(&self) -> BoxFuture<'_, anyhow::Result<Vec<ContainerMetrics>>> {
+        Box::pin(async move {
+            let output = tokio::process::Command::new("sh")
+                .args(["-c", ContainerCollector::command()])
+                .output()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to run container collection: {}", e))?;
+            self.containers.parse_output(&String::from_utf8_lossy(&output.stdout))
+        })
+    }
+
+    fn facts(&self) -> BoxFuture<'_, anyhow::Result<HostFacts>> {
+        Box::pin(async move {
+            let output = tokio::process::Command::new("sh")
+                .args(["-c", inventory::command()])
+                .output()
+                .await;
+            let parsed = output
+                .map_err(|e| anyhow::anyhow!("Failed to run host facts collection: {}", e))
+                .and_then(|output| inventory::parse_facts_output(&String::from_utf8_lossy(&output.stdout)));
+            match parsed {
+                Ok(facts) => Ok(facts),
+                // /proc 가 없는 환경(macOS, Windows)은 sysinfo 값만 쓴다
+                Err(_) => Ok(system_host_facts(&*self.system.lock().await)),
+            }
+        })
+    }
+
+    fn packages(&self) -> BoxFuture<'_, anyhow::Result<PackageInventory>> {
+        Box::pin(async move {
+            let output = tokio::process::Command::new("sh")
+                .args(["-c", packages::command()])
+                .output()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to run package listing: {}", e))?;
+            packages::parse_package_output(&String::from_utf8_lossy(&output.stdout))
+        })
+    }
+
+    fn listening_ports(&self) -> BoxFuture<'_, anyhow::Result<Vec<ListeningPort>>> {
+        Box::pin(async move {
+            let output = tokio::process::Command::new("sh")
+                .args(["-c", ports::command()])
+                .output()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to run port listing: {}", e))?;
+            ports::parse_port_output(&String::from_utf8_lossy(&output.stdout))
+        })
+    }
+}
+
+async fn proc_meminfo() -> Option<MemoryMetrics> {
+    let content = tokio::fs::read_to_string("/proc/meminfo").await.ok()?;
+    parsers::parse_meminfo(&content).ok().map(|info| info.metrics())
+}
+
+// sysinfo 는 inode 정보를 제공하지 않으므로 df 가 있으면 사용
+async fn local_inodes() -> Vec<DfInodeEntry> {
+    let output = match tokio::process::Command::new("df").args(["-P", "-i"]).output().await {
+        Ok(output) => output,
+        Err(_) => return Vec::new(),
+    };
+    parsers::parse_df_inodes(&String::from_utf8_lossy(&output.stdout)).unwrap_or_default()
+}
+
+fn network_counters(system: &System) -> Vec<NetDevStats> {
+    system
+        .networks()
+        .into_iter()
+        .map(|(name, data)| NetDevStats {
+            interface: name.clone(),
+            rx_bytes: data.total_received(),
+            rx_packets: data.total_packets_received(),
+            rx_errors: data.total_errors_on_received(),
+            tx_bytes: data.total_transmitted(),
+            tx_packets: data.total_packets_transmitted(),
+            tx_errors: data.total_errors_on_transmitted(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+// sysinfo 는 cached/buffers 를 제공하지 않는다 (Linux 에서는 /proc/meminfo 값으로 대체)
+fn local_memory(system: &System) -> MemoryMetrics {
+    let total = system.total_memory();
+    let available = system.available_memory();
+    MemoryMetrics {
+        total,
+        used: total.saturating_sub(available),
+        available,
+        swap_total: system.total_swap(),
+        swap_used: system.used_swap(),
+        ..Default::default()
+    }
+}
+
+fn local_filesystems(
+    system: &System,
+    filter: &FilesystemConfig,
+    inodes: &[DfInodeEntry],
+) -> Vec<FilesystemMetrics> {
+    let entries: Vec<DfEntry> = system
+        .disks()
+        .iter()
+        .map(|disk| DfEntry {
+            filesystem: disk.name().to_string_lossy().to_string(),
+            fs_type: Some(String::from_utf8_lossy(disk.file_system()).to_string()),
+            total: disk.total_space(),
+            used: disk.total_space().saturating_sub(disk.available_space()),
+            available: disk.available_space(),
+            mount_point: disk.mount_point().to_string_lossy().to_string(),
+        })
+        .collect();
+
+    parsers::build_filesystems(&entries, inodes, filter)
+}
+
+pub fn collect_server_metrics(
+    system: &System,
+    filter: &FilesystemConfig,
+    inodes: &[DfInodeEntry],
+    process_limit: usize,
+) -> ServerMetrics {
+    let filesystems = local_filesystems(system, filter, inodes);
+    let memory = local_memory(system);
+    let mut metrics = ServerMetrics::new(
+        system.global_cpu_info().cpu_usage(),
+        memory.usage_percent(),
+        parsers::filesystems_usage_percent(&filesystems),
+        // 네트워크 처리량은 수집 간격을 아는 LocalSource 가 채운다
+        0,
+        0,
+        top_processes(system, process_limit),
+    );
+    metrics.cpu_per_core = system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+    metrics.host = Some(HostInfo {
+        boot_time: Utc.timestamp_opt(system.boot_time() as i64, 0).single().unwrap_or(metrics.timestamp),
+        uptime_seconds: system.uptime(),
+        kernel: system.kernel_version(),
+        os: system.long_os_version(),
+    });
+    metrics.filesystems = filesystems;
+    metrics.memory = Some(memory);
+
+    // Windows 에서는 load average 가 없다
+    let load = system.load_average();
+    if !cfg!(windows) {
+        metrics.load_average = Some(LoadAverage {
+            one: load.one,
+            five: load.five,
+            fifteen: load.fifteen,
+        });
+    }
+    metrics
+}
+
+pub fn system_host_facts(system: &System) -> HostFacts {
+    HostFacts {
+        hostname: system.host_name().unwrap_or_default(),
+        os_name: system.name(),
+        os_version: system.os_version(),
+        kernel_version: system.kernel_version(),
+        architecture: Some(std::env::consts::ARCH.to_string()),
+        cpu_cores: Some(system.cpus().len()),
+        total_memory: Some(system.total_memory()),
+        cpu_model: system
+            .cpus()
+            .first()
+            .map(|cpu| cpu.brand().trim().to_string())
+            .filter(|brand| !brand.is_empty()),
+        ..Default::default()
+    }
+}
+
+fn top_processes(system: &System, limit: usize) -> Vec<ProcessMetrics> {
+    let processes: Vec<ProcessMetrics> = system
+        .processes()
+        .iter()
+        .map(|(pid, process)| ProcessMetrics::new(
+            pid.as_u32(),
+            process.name().to_string(),
+            process.cpu_usage(),
+            process.memory(),
+        ))
+        .collect();
+
+    parsers::snapshot_processes(processes, limit)
+}
+
+fn process_state(status: ProcessStatus) -> &'static str {
+    match status {
+        ProcessStatus::Run => "running",
+        ProcessStatus::Sleep => "sleeping",
+        ProcessStatus::UninterruptibleDiskSleep => "disk_sleep",
+        ProcessStatus::Stop => "stopped",
+        ProcessStatus::Tracing => "tracing",
+        ProcessStatus::Zombie => "zombie",
+        ProcessStatus::Dead => "dead",
+        ProcessStatus::Idle => "idle",
+        _ => "unknown",
+    }
+}
+
+fn process_details(system: &System) -> Vec<ProcessDetail> {
+    system
+        .processes()
+        .iter()
+        .map(|(pid, process)| ProcessDetail {
+            pid: pid.as_u32(),
+            ppid: process.parent().map(|p| p.as_u32()),
+            name: process.name().to_string(),
+            user: process
+                .user_id()
+                .and_then(|uid| system.get_user_by_id(uid))
+                .map(|user| user.name().to_string()),
+            command: process.cmd().join(" "),
+            state: process_state(process.status()).to_string(),
+            threads: None,
+            start_time: Utc.timestamp_opt(process.start_time() as i64, 0).single(),
+            cpu_usage: process.cpu_usage(),
+            memory_usage: process.memory(),
+            open_files: None,
+        })
+        .collect()
+}
+
+// sysinfo 가 주지 않는 스레드 수와 열린 파일 수를 /proc 에서 채운다 (Linux 외에는 None)
+fn fill_proc_details(processes: &mut [ProcessDetail]) {
+    for process in processes {
+        let base = format!("/proc/{}", process.pid);
+        process.threads = std::fs::read_to_string(format!("{}/status", base))
+            .ok()
+            .and_then(|status| {
+                status
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Threads:"))
+                    .and_then(|v| v.trim().parse().ok())
+            });
+        process.open_files = std::fs::read_dir(format!("{}/fd", base))
+            .ok()
+            .map(|entries| entries.count() as u32);
+    }
 }
\ No newline at end of file
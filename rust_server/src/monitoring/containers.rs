@@ -0,0 +1,226 @@
+// src/monitoring/containers.rs
+use std::sync::Mutex;
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+use crate::models::container::ContainerMetrics;
+use crate::monitoring::parsers;
+
+pub const CONTAINER_SECTION_MARKER: &str = "__FLICK_CONTAINER__";
+
+// 엔진 소켓이 있고 curl 을 쓸 수 있으면 Docker/Podman API, 아니면 cgroup v2 파일을 읽는다.
+// 로컬에서는 sh 로, 원격에서는 SSH 세션으로 같은 스크립트를 실행한다
+const CONTAINER_SCRIPT: &str = r#"cat /proc/uptime; echo __FLICK_CONTAINER__
+S=""
+for s in /var/run/docker.sock /run/podman/podman.sock; do [ -S "$s" ] && S="$s" && break; done
+if [ -n "$S" ] && command -v curl >/dev/null 2>&1; then
+  echo "engine $S"; echo __FLICK_CONTAINER__
+  L=$(curl -sf --unix-socket "$S" 'http://localhost/containers/json?all=true'); echo "$L"; echo __FLICK_CONTAINER__
+  for id in $(echo "$L" | grep -o '"Id":"[0-9a-f]*"' | cut -d'"' -f4); do
+    curl -sf --unix-socket "$S" "http://localhost/containers/$id/json"; echo; echo __FLICK_CONTAINER__
+    curl -sf --unix-socket "$S" "http://localhost/containers/$id/stats?stream=false&one-shot=true"; echo; echo __FLICK_CONTAINER__
+  done
+else
+  echo cgroup; echo __FLICK_CONTAINER__
+  for d in /sys/fs/cgroup/system.slice/docker-*.scope /sys/fs/cgroup/machine.slice/libpod-*.scope; do
+    [ -f "$d/cpu.stat" ] || continue
+    p=$(head -n1 "$d/cgroup.procs" 2>/dev/null)
+    n=$([ -n "$p" ] && awk -F'[: ]+' 'NR>2 && $2!="lo" {r+=$3; t+=$11} END {print r+0, t+0}' "/proc/$p/net/dev" 2>/dev/null || echo "- -")
+    echo "$(basename "$d") $(awk '/^usage_usec/ {print $2}' "$d/cpu.stat") $(cat "$d/memory.current") $(cat "$d/memory.max") $n"
+  done
+fi"#;
+
+// 누적 카운터가 포함된 컨테이너 한 개의 원시 샘플
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerSample {
+    pub id: String,
+    pub name: String,
+    pub image: Option<String>,
+    pub state: String,
+    pub cpu_usec: u64,
+    pub memory_usage: u64,
+    pub memory_limit: Option<u64>,
+    pub network: Option<(u64, u64)>,    // (rx bytes, tx bytes)
+    pub restart_count: Option<u32>,
+}
+
+// 직전 샘플을 보관하고 비율을 계산한다. 수집원마다 하나씩 둔다
+#[derive(Default)]
+pub struct ContainerCollector {
+    previous: Mutex<Option<(f64, Vec<ContainerSample>)>>,
+}
+
+impl ContainerCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn command() -> &'static str {
+        CONTAINER_SCRIPT
+    }
+
+    pub fn parse_output(&self, output: &str) -> Result<Vec<ContainerMetrics>> {
+        let (uptime, samples) = parse_container_output(output)?;
+
+        let mut previous = match self.previous.lock() {
+            Ok(previous) => previous,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        // uptime 이 줄었으면 재부팅 - 기준값을 다시 잡는다
+        let baseline = previous
+            .as_ref()
+            .filter(|(prev_uptime, _)| uptime > *prev_uptime)
+            .map(|(prev_uptime, prev)| (uptime - prev_uptime, prev.as_slice()));
+        let metrics = container_rates(baseline, &samples);
+        *previous = Some((uptime, samples));
+        Ok(metrics)
+    }
+}
+
+pub fn parse_container_output(output: &str) -> Result<(f64, Vec<ContainerSample>)> {
+    let sections: Vec<&str> = output
+        .split(CONTAINER_SECTION_MARKER)
+        .map(|s| s.trim_matches('\n'))
+        .collect();
+    if sections.len() < 3 {
+        return Err(anyhow!("Unexpected container output ({} sections)", sections.len()));
+    }
+
+    let uptime = parsers::parse_uptime(sections[0])?;
+    let samples = if sections[1].starts_with("engine") {
+        parse_engine_sections(&sections[2..])?
+    } else {
+        parse_cgroup_lines(sections[2])
+    };
+    Ok((uptime, samples))
+}
+
+// [목록, (inspect, stats)*] - 실행 도중 사라진 컨테이너는 응답이 비어 있을 수 있다
+pub fn parse_engine_sections(sections: &[&str]) -> Result<Vec<ContainerSample>> {
+    let list: Vec<Value> = serde_json::from_str(sections[0]).context("Invalid container list")?;
+    let details: Vec<(Value, Value)> = sections[1..]
+        .chunks(2)
+        .filter_map(|pair| match pair {
+            [inspect, stats] => Some((
+                serde_json::from_str(inspect).ok()?,
+                serde_json::from_str(stats).unwrap_or(Value::Null),
+            )),
+            _ => None,
+        })
+        .collect();
+
+    let mut samples = Vec::new();
+    for entry in &list {
+        let id = match entry["Id"].as_str() {
+            Some(id) => id.to_string(),
+            None => continue,
+        };
+        let name = entry["Names"][0]
+            .as_str()
+            .map(|n| n.trim_start_matches('/').to_string())
+            .unwrap_or_else(|| short_id(&id));
+        let mut sample = ContainerSample {
+            name,
+            image: entry["Image"].as_str().map(str::to_string),
+            state: entry["State"].as_str().unwrap_or("unknown").to_string(),
+            id,
+            ..Default::default()
+        };
+
+        if let Some((inspect, stats)) = details.iter().find(|(inspect, _)| inspect["Id"].as_str() == Some(&sample.id)) {
+            sample.restart_count = inspect["RestartCount"].as_u64().map(|n| n as u32);
+            sample.cpu_usec = stats["cpu_stats"]["cpu_usage"]["total_usage"].as_u64().unwrap_or(0) / 1000;
+
+            // docker stats 와 같이 page cache(inactive_file) 는 빼고 본다
+            let memory = &stats["memory_stats"];
+            let cache = ["inactive_file", "total_inactive_file", "cache"]
+                .iter()
+                .find_map(|key| memory["stats"][key].as_u64())
+                .unwrap_or(0);
+            sample.memory_usage = memory["usage"].as_u64().unwrap_or(0).saturating_sub(cache);
+            sample.memory_limit = memory["limit"].as_u64().filter(|&limit| limit > 0);
+
+            sample.network = stats["networks"].as_object().map(|networks| {
+                networks.values().fold((0, 0), |(rx, tx), n| {
+                    (rx + n["rx_bytes"].as_u64().unwrap_or(0), tx + n["tx_bytes"].as_u64().unwrap_or(0))
+                })
+            });
+        }
+        samples.push(sample);
+    }
+    Ok(samples)
+}
+
+// "<scope> <usage_usec> <memory.current> <memory.max> <rx> <tx>" - 네트워크를 못 읽으면 "-"
+pub fn parse_cgroup_lines(input: &str) -> Vec<ContainerSample> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 6 {
+                return None;
+            }
+            let id = fields[0]
+                .trim_end_matches(".scope")
+                .trim_start_matches("docker-")
+                .trim_start_matches("libpod-")
+                .to_string();
+            let network = match (fields[4].parse().ok(), fields[5].parse().ok()) {
+                (Some(rx), Some(tx)) => Some((rx, tx)),
+                _ => None,
+            };
+            Some(ContainerSample {
+                name: short_id(&id),
+                image: None,
+                state: "running".to_string(),
+                cpu_usec: fields[1].parse().ok()?,
+                memory_usage: fields[2].parse().ok()?,
+                memory_limit: fields[3].parse().ok(),    // "max" 면 제한 없음
+                network,
+                restart_count: None,
+                id,
+            })
+        })
+        .collect()
+}
+
+// 이전 샘플에 없던 컨테이너와 첫 샘플은 비율이 0 (네트워크는 None)
+pub fn container_rates(
+    previous: Option<(f64, &[ContainerSample])>,
+    current: &[ContainerSample],
+) -> Vec<ContainerMetrics> {
+    current
+        .iter()
+        .map(|sample| {
+            let baseline = previous.and_then(|(elapsed, prev)| {
+                prev.iter().find(|p| p.id == sample.id).map(|p| (elapsed, p))
+            });
+            // 컨테이너가 재시작되면 카운터가 줄어드므로 그 구간은 0 으로 본다
+            let rate = |prev: u64, cur: u64, elapsed: f64| cur.saturating_sub(prev) as f64 / elapsed;
+
+            let cpu_usage = baseline.map_or(0.0, |(elapsed, prev)| {
+                (rate(prev.cpu_usec, sample.cpu_usec, elapsed) / 1_000_000.0 * 100.0) as f32
+            });
+            let network = baseline.and_then(|(elapsed, prev)| {
+                let ((prev_rx, prev_tx), (rx, tx)) = (prev.network?, sample.network?);
+                Some((rate(prev_rx, rx, elapsed), rate(prev_tx, tx, elapsed)))
+            });
+
+            ContainerMetrics {
+                id: sample.id.clone(),
+                name: sample.name.clone(),
+                image: sample.image.clone(),
+                state: sample.state.clone(),
+                cpu_usage,
+                memory_usage: sample.memory_usage,
+                memory_limit: sample.memory_limit,
+                network_rx: network.map(|(rx, _)| rx),
+                network_tx: network.map(|(_, tx)| tx),
+                restart_count: sample.restart_count,
+            }
+        })
+        .collect()
+}
+
+fn short_id(id: &str) -> String {
+    id.chars().take(12).collect()
+}
@@ -0,0 +1,119 @@
+// src/monitoring/inventory.rs
+use std::collections::BTreeMap;
+use anyhow::{anyhow, Result};
+use crate::models::agent::{HostFacts, InterfaceFacts};
+
+pub const FACTS_SECTION_MARKER: &str = "__FLICK_FACTS__";
+
+// 호스트 정보는 하루 한 번 (그리고 모니터링 시작 시) 수집한다
+pub const FACTS_INTERVAL: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+// 로컬에서는 sh 로, 원격에서는 SSH 세션으로 같은 스크립트를 실행한다
+const FACTS_SCRIPT: &str = r#"hostname; echo __FLICK_FACTS__
+grep -m1 -E '^(model name|Hardware|Model)[[:space:]]*:' /proc/cpuinfo; echo __FLICK_FACTS__
+nproc; echo __FLICK_FACTS__
+grep -m1 MemTotal /proc/meminfo; echo __FLICK_FACTS__
+cat /etc/os-release 2>/dev/null; echo __FLICK_FACTS__
+uname -r; uname -m; echo __FLICK_FACTS__
+V=$(systemd-detect-virt 2>/dev/null); echo "${V:-unknown}"; echo __FLICK_FACTS__
+for i in /sys/class/net/*; do echo "$(basename "$i") $(cat "$i/address" 2>/dev/null)"; done; echo __FLICK_FACTS__
+ip -o addr show 2>/dev/null"#;
+
+pub fn command() -> &'static str {
+    FACTS_SCRIPT
+}
+
+pub fn parse_facts_output(output: &str) -> Result<HostFacts> {
+    let sections: Vec<&str> = output
+        .split(FACTS_SECTION_MARKER)
+        .map(|s| s.trim_matches('\n'))
+        .collect();
+    if sections.len() < 9 {
+        return Err(anyhow!("Unexpected host facts output ({} sections)", sections.len()));
+    }
+
+    let hostname = sections[0].trim().to_string();
+    if hostname.is_empty() {
+        return Err(anyhow!("Host facts output has no hostname"));
+    }
+    let (os_name, os_version) = parse_os_release(sections[4]);
+    let mut uname = sections[5].lines().map(str::trim).filter(|l| !l.is_empty());
+
+    Ok(HostFacts {
+        hostname,
+        os_name,
+        os_version,
+        kernel_version: uname.next().map(str::to_string),
+        architecture: uname.next().map(str::to_string),
+        cpu_cores: sections[2].trim().parse().ok(),
+        total_memory: parse_mem_total(sections[3]),
+        ip_address: None,
+        cpu_model: sections[1]
+            .split_once(':')
+            .map(|(_, model)| model.trim().to_string())
+            .filter(|model| !model.is_empty()),
+        virtualization: Some(sections[6].trim())
+            .filter(|v| !v.is_empty() && *v != "unknown")
+            .map(str::to_string),
+        interfaces: parse_interfaces(sections[7], sections[8]),
+    })
+}
+
+// /etc/os-release 의 NAME, VERSION_ID (없으면 VERSION)
+pub fn parse_os_release(input: &str) -> (Option<String>, Option<String>) {
+    let values: BTreeMap<&str, String> = input
+        .lines()
+        .filter_map(|line| line.trim().split_once('='))
+        .map(|(key, value)| (key, value.trim_matches(|c| c == '"' || c == '\'').to_string()))
+        .filter(|(_, value)| !value.is_empty())
+        .collect();
+    let version = values.get("VERSION_ID").or_else(|| values.get("VERSION")).cloned();
+    (values.get("NAME").cloned(), version)
+}
+
+// "MemTotal:  16318976 kB" -> bytes
+fn parse_mem_total(input: &str) -> Option<u64> {
+    let kb: u64 = input.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+// "<iface> <mac>" 목록과 `ip -o addr show` 출력을 합친다. loopback 은 뺀다
+pub fn parse_interfaces(links: &str, addrs: &str) -> Vec<InterfaceFacts> {
+    let mut interfaces: BTreeMap<String, InterfaceFacts> = BTreeMap::new();
+
+    for line in links.lines() {
+        let mut fields = line.split_whitespace();
+        let name = match fields.next() {
+            Some(name) if name != "lo" => name,
+            _ => continue,
+        };
+        let mac_address = fields
+            .next()
+            .filter(|mac| mac.contains(':') && *mac != "00:00:00:00:00:00")
+            .map(str::to_string);
+        interfaces.insert(name.to_string(), InterfaceFacts {
+            name: name.to_string(),
+            mac_address,
+            addresses: Vec::new(),
+        });
+    }
+
+    // "2: eth0    inet 10.0.0.5/24 brd 10.0.0.255 scope global eth0\ ..."
+    for line in addrs.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 4 || !matches!(fields[2], "inet" | "inet6") {
+            continue;
+        }
+        let name = fields[1].split('@').next().unwrap_or(fields[1]);
+        if name == "lo" {
+            continue;
+        }
+        interfaces
+            .entry(name.to_string())
+            .or_insert_with(|| InterfaceFacts { name: name.to_string(), ..Default::default() })
+            .addresses
+            .push(fields[3].to_string());
+    }
+
+    interfaces.into_values().collect()
+}
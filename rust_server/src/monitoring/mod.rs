@@ -2,193 +2,473 @@
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use crate::db::repository::Repository;
+use crate::models::agent::HostFacts;
 use crate::models::metrics::{ServerMetrics, ProcessMetrics};
+use crate::models::port::ListeningPort;
+use crate::models::process::ProcessDetail;
+use crate::models::service::ServiceStatus;
 use std::collections::HashMap;
-use sysinfo::{System, SystemExt, ProcessExt, CpuExt, DiskExt, NetworkExt, NetworksExt, PidExt};
-use crate::db::models::MetricsSnapshot;
+use crate::db::models::{CollectionMethod, MetricsSnapshot, Server, ServerCategory};
+use crate::config::{EncryptionConfig, MonitoringConfig, ServerConfig};
+use crate::utils::encryption::Encryptor;
+use tracing::{info, warn, error};
 
+pub mod agent;
+pub mod alerts;
 pub mod collector;
+pub mod containers;
+pub mod inventory;
+pub mod packages;
+pub mod parsers;
+pub mod ports;
+pub mod ssh;
+pub mod systemd;
 mod traits;
-pub use traits::MonitoringServiceTrait;
+pub mod watchlist;
+use agent::AgentSource;
+use alerts::AlertEvaluator;
+use collector::LocalSource;
+use ssh::{SshSource, SshTarget};
+use systemd::ServiceEvaluator;
+use watchlist::WatchlistEvaluator;
+pub use traits::{MetricSource, MonitoringServiceTrait, SourceCapabilities};
 
 #[cfg(test)]
 pub use traits::MockMonitoringServiceTrait;
 
-#[derive(Clone)]
-pub struct MetricsCollector {
-    system: Arc<RwLock<System>>,
-    metrics: Arc<RwLock<Option<ServerMetrics>>>,
-}
-
-impl MetricsCollector {
-    pub fn new() -> Self {
-        Self {
-            system: Arc::new(RwLock::new(System::new_all())),
-            metrics: Arc::new(RwLock::new(None)),
-        }
-    }
-
-    pub async fn start_collection(&self) {
-        let system = self.system.clone();
-        let metrics = self.metrics.clone();
-
-        tokio::spawn(async move {
-            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
-            
-            loop {
-                interval.tick().await;
-                let mut sys = system.write().await;
-                sys.refresh_all();
-
-                let processes = collect_process_metrics(&sys);
-                let current_metrics = ServerMetrics::new(
-                    sys.global_cpu_info().cpu_usage(),
-                    (sys.used_memory() as f32 / sys.total_memory() as f32) * 100.0,
-                    calculate_disk_usage(&sys),
-                    sys.networks().iter().map(|(_, data)| data.received()).sum(),
-                    sys.networks().iter().map(|(_, data)| data.transmitted()).sum(),
-                    processes,
-                );
-
-                *metrics.write().await = Some(current_metrics);
-            }
-        });
-    }
-
-    pub async fn get_current_metrics(&self) -> Option<ServerMetrics> {
-        self.metrics.read().await.clone()
-    }
-}
+const COLLECTION_INTERVAL: tokio::time::Duration = tokio::time::Duration::from_secs(5);
 
 #[derive(Clone)]
 pub struct MonitoringService {
     repo: Repository,
-    collectors: Arc<RwLock<HashMap<String, Arc<MetricsCollector>>>>,
+    encryption: EncryptionConfig,
+    config: MonitoringConfig,
+    alerts: Arc<AlertEvaluator>,
+    watchlist: Arc<WatchlistEvaluator>,
+    service_alerts: Arc<ServiceEvaluator>,
+    sources: Arc<RwLock<HashMap<String, Arc<dyn MetricSource>>>>,
+    latest: Arc<RwLock<HashMap<String, ServerMetrics>>>,
 }
 
 impl MonitoringService {
     pub fn new(repo_data: actix_web::web::Data<Repository>) -> Self {
         Self {
             repo: repo_data.get_ref().clone(),
-            collectors: Arc::new(RwLock::new(HashMap::new())),
+            encryption: EncryptionConfig::default(),
+            config: MonitoringConfig::default(),
+            alerts: Arc::new(AlertEvaluator::default()),
+            watchlist: Arc::new(WatchlistEvaluator::new()),
+            service_alerts: Arc::new(ServiceEvaluator::new()),
+            sources: Arc::new(RwLock::new(HashMap::new())),
+            latest: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    pub fn with_config(mut self, config: &ServerConfig) -> Self {
+        self.encryption = config.encryption.clone();
+        self.config = config.monitoring.clone();
+        self.alerts = Arc::new(AlertEvaluator::new(config.monitoring.alert_thresholds.clone()));
+        self
+    }
+
+    // 서버의 collection_method 에 맞는 수집원 생성
+    fn create_source(&self, server: &Server) -> anyhow::Result<Arc<dyn MetricSource>> {
+        let source: Arc<dyn MetricSource> = match server.collection_method {
+            CollectionMethod::Local => Arc::new(
+                LocalSource::new()
+                    .with_filesystem_config(self.config.filesystems.clone())
+                    .with_network_config(self.config.network.clone())
+                    .with_disk_io_config(self.config.disk_io.clone())
+                    .with_process_limit(self.config.process_snapshot_limit),
+            ),
+            CollectionMethod::Ssh => {
+                let encryptor = Encryptor::new(&self.encryption.key, &self.encryption.nonce)
+                    .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+                let target = SshTarget::from_server(server, &encryptor)?;
+                Arc::new(
+                    SshSource::new(target)
+                        .with_filesystem_config(self.config.filesystems.clone())
+                        .with_network_config(self.config.network.clone())
+                        .with_disk_io_config(self.config.disk_io.clone())
+                        .with_process_limit(self.config.process_snapshot_limit),
+                )
+            }
+            CollectionMethod::Agent => Arc::new(AgentSource::new()),
+        };
+        Ok(source)
+    }
+
     pub async fn start_monitoring(&self, server_id: &str) {
-        let collector = Arc::new(MetricsCollector::new());
-        
-        // 서버 존재 여부만 확인하고 변수는 사용하지 않으므로 _로 시작
-        if let Ok(Some(_server)) = self.repo.get_server(server_id).await {
-            // 기존 컬렉터가 있다면 제거
-            self.collectors.write().await.remove(server_id);
-            
-            // 새 컬렉터 시작
-            collector.start_collection().await;
-            
+        if let Ok(Some(server)) = self.repo.get_server(server_id).await {
+            let source = match self.create_source(&server) {
+                Ok(source) => source,
+                Err(e) => {
+                    error!("Failed to create metric source for {}: {:#}", server_id, e);
+                    return;
+                }
+            };
+            info!(
+                "Starting monitoring for {} ({}) via {}",
+                server.name, server.hostname, source.method()
+            );
+
+            // 기존 수집원이 있다면 교체
+            let server_id = server_id.to_string();
+            self.sources.write().await.insert(server_id.clone(), source.clone());
+
+            // push 방식은 에이전트가 보내올 때 기록된다
+            if !source.capabilities().polled {
+                return;
+            }
+
             // 서버 상태 업데이트
-            let _ = self.repo.update_server_status(server_id, true).await;
-            
-            // 메트릭 저장을 위한 백그라운드 태스크 시작
-            let repo = self.repo.clone();
-            let collector_clone = collector.clone();
-            let server_id = server_id.to_string();  // 먼저 String으로 변환
-            
-            // 클로저에서 사용할 server_id 복제
-            let server_id_clone = server_id.clone();
-            
+            let _ = self.repo.update_server_status(&server_id, true).await;
+
+            // 컨테이너 호스트만 컨테이너 메트릭을 수집
+            let collect_containers = server.category == ServerCategory::Container
+                && source.capabilities().containers;
+
+            // 메트릭 수집/저장을 위한 백그라운드 태스크 시작
+            let service = self.clone();
+            let source_ref = Arc::downgrade(&source);
+            drop(source);
+
             tokio::spawn(async move {
-                let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(5));
-                
+                let mut interval = tokio::time::interval(COLLECTION_INTERVAL);
+                // 첫 수집 때, 이후 하루에 한 번 호스트 정보를 갱신
+                let mut facts_collected_at: Option<std::time::Instant> = None;
+                let mut packages_collected_at: Option<std::time::Instant> = None;
+                let mut ports_collected_at: Option<std::time::Instant> = None;
+
                 loop {
                     interval.tick().await;
-                    if let Some(metrics) = collector_clone.get_current_metrics().await {
-                        let snapshot = MetricsSnapshot {
-                            id: 0,
-                            server_id: server_id_clone.clone(), // 복제된 server_id 사용
-                            cpu_usage: metrics.cpu_usage as f64,
-                            memory_usage: metrics.memory_usage as f64,
-                            disk_usage: metrics.disk_usage as f64,
-                            network_rx: metrics.network_rx as i64,
-                            network_tx: metrics.network_tx as i64,
-                            processes: serde_json::to_value(&metrics.processes).unwrap_or_default(),
-                            timestamp: chrono::Utc::now(),
-                        };
-                        
-                        if let Err(e) = repo.save_metrics(snapshot).await {
-                            eprintln!("Failed to save metrics: {}", e);
+                    // 모니터링이 중지되거나 수집원이 교체되면 종료
+                    let source = match source_ref.upgrade() {
+                        Some(source) => source,
+                        None => break,
+                    };
+
+                    match source.collect().await {
+                        Ok(metrics) => {
+                            if let Err(e) = service.record_metrics(&server_id, metrics).await {
+                                eprintln!("Failed to save metrics: {}", e);
+                            }
+                            service.check_watchlist(&server_id, source.as_ref()).await;
+                            service.check_services(&server_id, source.as_ref()).await;
+                            if collect_containers {
+                                service.record_containers(&server_id, source.as_ref()).await;
+                            }
+                            let facts_due = facts_collected_at
+                                .is_none_or(|at| at.elapsed() >= inventory::FACTS_INTERVAL);
+                            if facts_due && service.collect_facts(&server_id, source.as_ref()).await {
+                                facts_collected_at = Some(std::time::Instant::now());
+                            }
+                            let packages_due = packages_collected_at
+                                .is_none_or(|at| at.elapsed() >= packages::PACKAGES_INTERVAL);
+                            // 패키지 관리자가 없는 호스트도 있으므로 실패해도 다음 주기까지 기다린다
+                            if packages_due {
+                                service.record_packages(&server_id, source.as_ref()).await;
+                                packages_collected_at = Some(std::time::Instant::now());
+                            }
+                            let ports_due = ports_collected_at
+                                .is_none_or(|at| at.elapsed() >= ports::PORTS_INTERVAL);
+                            if ports_due {
+                                service.record_ports(&server_id, source.as_ref()).await;
+                                ports_collected_at = Some(std::time::Instant::now());
+                            }
                         }
+                        Err(e) => warn!("Metric collection for {} failed: {:#}", server_id, e),
                     }
                 }
             });
-            
-            // 컬렉터 저장 - 원본 server_id 사용
-            self.collectors.write().await.insert(server_id, collector);
         }
     }
-    
-    // 글로벌 메트릭스 조회
-    pub async fn get_current_metrics(&self) -> Option<ServerMetrics> {
-        // 모든 컬렉터의 첫 번째 메트릭스 반환
-        if let Some(collector) = self.collectors.read().await.values().next() {
-            collector.get_current_metrics().await
+
+    // 수집된 메트릭을 최신 값으로 보관하고 스냅샷으로 저장
+    pub async fn record_metrics(&self, server_id: &str, metrics: ServerMetrics) -> anyhow::Result<()> {
+        let snapshot = MetricsSnapshot::from_metrics(server_id, &metrics);
+        let (timestamp, processes) = (metrics.timestamp, metrics.processes.clone());
+        let alerts = self.alerts.evaluate(server_id, &metrics);
+
+        if let Some(source) = self.sources.read().await.get(server_id).cloned() {
+            source.push(metrics.clone()).await;
+        }
+
+        {
+            let mut latest = self.latest.write().await;
+            let is_newer = latest
+                .get(server_id)
+                .is_none_or(|current| current.timestamp <= metrics.timestamp);
+            if is_newer {
+                latest.insert(server_id.to_string(), metrics);
+            }
+        }
+
+        self.repo.save_metrics(snapshot).await?;
+        if let Err(e) = self.repo.save_process_samples(server_id, timestamp, &processes).await {
+            error!("Failed to save process samples for {}: {}", server_id, e);
+        }
+
+        for alert in alerts {
+            if let Err(e) = self.repo.create_alert(alert).await {
+                error!("Failed to create alert for {}: {}", server_id, e);
+            }
+        }
+        Ok(())
+    }
+
+    // 감시 대상 프로세스가 있으면 전체 프로세스 목록을 받아 비교한다
+    async fn check_watchlist(&self, server_id: &str, source: &dyn MetricSource) {
+        if !source.capabilities().process_table {
+            return;
+        }
+        let watches = match self.repo.list_process_watches(server_id).await {
+            Ok(watches) if !watches.is_empty() => watches,
+            Ok(_) => return,
+            Err(e) => {
+                error!("Failed to load process watches for {}: {}", server_id, e);
+                return;
+            }
+        };
+        let processes = match source.processes().await {
+            Ok(processes) => processes,
+            Err(e) => {
+                warn!("Process listing for {} failed: {:#}", server_id, e);
+                return;
+            }
+        };
+
+        for alert in self.watchlist.evaluate(server_id, &watches, &processes) {
+            if let Err(e) = self.repo.create_alert(alert).await {
+                error!("Failed to create alert for {}: {}", server_id, e);
+            }
+        }
+    }
+
+    // 설정된 systemd 유닛 상태를 확인해 failed 로 바뀐 유닛을 알린다
+    async fn check_services(&self, server_id: &str, source: &dyn MetricSource) {
+        if !source.capabilities().services {
+            return;
+        }
+        let units = match self.repo.list_service_units(server_id).await {
+            Ok(units) if !units.is_empty() => units,
+            Ok(_) => return,
+            Err(e) => {
+                error!("Failed to load service units for {}: {}", server_id, e);
+                return;
+            }
+        };
+        let services = match source.services(units).await {
+            Ok(services) => services,
+            Err(e) => {
+                warn!("Service status for {} failed: {:#}", server_id, e);
+                return;
+            }
+        };
+
+        for alert in self.service_alerts.evaluate(server_id, &services) {
+            if let Err(e) = self.repo.create_alert(alert).await {
+                error!("Failed to create alert for {}: {}", server_id, e);
+            }
+        }
+    }
+
+    async fn record_containers(&self, server_id: &str, source: &dyn MetricSource) {
+        let containers = match source.containers().await {
+            Ok(containers) => containers,
+            Err(e) => {
+                warn!("Container collection for {} failed: {:#}", server_id, e);
+                return;
+            }
+        };
+        if let Err(e) = self.repo.save_containers(server_id, chrono::Utc::now(), &containers).await {
+            error!("Failed to save container metrics for {}: {}", server_id, e);
+        }
+    }
+
+    async fn collect_facts(&self, server_id: &str, source: &dyn MetricSource) -> bool {
+        if !source.capabilities().inventory {
+            return true;
+        }
+        let facts = match source.facts().await {
+            Ok(facts) => facts,
+            Err(e) => {
+                warn!("Host facts collection for {} failed: {:#}", server_id, e);
+                return false;
+            }
+        };
+        match self.record_facts(server_id, facts).await {
+            Ok(_) => true,
+            Err(e) => {
+                error!("Failed to save host facts for {}: {}", server_id, e);
+                false
+            }
+        }
+    }
+
+    // 호스트 정보를 저장하고 달라진 항목은 이력으로 남긴다.
+    // ip_address, server_type 은 사용자 입력 대신 수집한 값으로 채운다
+    pub async fn record_facts(&self, server_id: &str, facts: HostFacts) -> anyhow::Result<Vec<String>> {
+        let server = self
+            .repo
+            .get_server(server_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Server {} not found", server_id))?;
+        let previous: Option<HostFacts> = server
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("facts"))
+            .and_then(|facts| serde_json::from_value(facts.clone()).ok());
+
+        let changed = facts.changed_fields(previous.as_ref());
+        if !changed.is_empty() {
+            info!("Host facts for {} changed: {}", server_id, changed.join(", "));
+        }
+        self.repo
+            .save_host_facts(server_id, &facts, &changed, chrono::Utc::now())
+            .await?;
+        Ok(changed)
+    }
+
+    // 설치된 패키지와 대기 중인 업데이트를 저장하고, 보안 업데이트 수가 임계값을 넘으면 알린다
+    async fn record_packages(&self, server_id: &str, source: &dyn MetricSource) {
+        if !source.capabilities().packages {
+            return;
+        }
+        let inventory = match source.packages().await {
+            Ok(inventory) => inventory,
+            Err(e) => {
+                warn!("Package listing for {} failed: {:#}", server_id, e);
+                return;
+            }
+        };
+        if let Err(e) = self.repo.save_packages(server_id, &inventory, chrono::Utc::now()).await {
+            error!("Failed to save packages for {}: {}", server_id, e);
+            return;
+        }
+
+        for alert in self.alerts.evaluate_security_updates(server_id, inventory.security_update_count()) {
+            if let Err(e) = self.repo.create_alert(alert).await {
+                error!("Failed to create alert for {}: {}", server_id, e);
+            }
+        }
+    }
+
+    // 대기 포트를 저장하고 새로 열린 포트를 알린다. 처음 수집할 때는 기준값만 저장
+    async fn record_ports(&self, server_id: &str, source: &dyn MetricSource) {
+        if !source.capabilities().ports {
+            return;
+        }
+        let current = match source.listening_ports().await {
+            Ok(current) => current,
+            Err(e) => {
+                warn!("Port listing for {} failed: {:#}", server_id, e);
+                return;
+            }
+        };
+        let previous: Vec<ListeningPort> = match self.repo.list_listening_ports(server_id).await {
+            Ok(rows) => rows.iter().map(ListeningPort::from).collect(),
+            Err(e) => {
+                error!("Failed to load listening ports for {}: {}", server_id, e);
+                return;
+            }
+        };
+
+        let (opened, closed) = if previous.is_empty() {
+            (Vec::new(), Vec::new())
         } else {
-            None
+            ports::diff_ports(&previous, &current)
+        };
+        if let Err(e) = self
+            .repo
+            .save_listening_ports(server_id, &current, &opened, &closed, chrono::Utc::now())
+            .await
+        {
+            error!("Failed to save listening ports for {}: {}", server_id, e);
+            return;
         }
+
+        for alert in ports::opened_port_alerts(server_id, &opened) {
+            if let Err(e) = self.repo.create_alert(alert).await {
+                error!("Failed to create alert for {}: {}", server_id, e);
+            }
+        }
+    }
+
+    pub async fn get_source_capabilities(&self, server_id: &str) -> Option<SourceCapabilities> {
+        self.sources.read().await.get(server_id).map(|source| source.capabilities())
+    }
+
+    // 글로벌 메트릭스 조회
+    pub async fn get_current_metrics(&self) -> Option<ServerMetrics> {
+        // 첫 번째 서버의 메트릭스 반환
+        self.latest.read().await.values().next().cloned()
     }
 
     // 특정 서버의 메트릭스 조회
     pub async fn get_server_metrics(&self, server_id: &str) -> Option<ServerMetrics> {
-        if let Some(collector) = self.collectors.read().await.get(server_id) {
-            collector.get_current_metrics().await
-        } else {
-            // 컬렉터가 없다면 시작
+        if let Some(metrics) = self.latest.read().await.get(server_id) {
+            return Some(metrics.clone());
+        }
+
+        // 수집원이 없다면 시작
+        if !self.sources.read().await.contains_key(server_id) {
             self.start_monitoring(server_id).await;
-            None
         }
+        None
     }
 
     // 특정 서버의 프로세스 정보 조회
     pub async fn get_server_processes(&self, server_id: &str) -> Option<Vec<ProcessMetrics>> {
-        if let Some(collector) = self.collectors.read().await.get(server_id) {
-            collector.get_current_metrics().await.map(|m| m.processes)
-        } else {
-            None
-        }
+        self.latest.read().await.get(server_id).map(|m| m.processes.clone())
     }
 
-    pub async fn stop_monitoring(&self, server_id: &str) {
-        if let Some(_) = self.collectors.write().await.remove(server_id) {
-            let _ = self.repo.update_server_status(server_id, false).await;
+    // 요청 시점의 전체 프로세스 목록. 수집원이 지원하지 않으면 None
+    pub async fn list_processes(&self, server_id: &str) -> anyhow::Result<Option<Vec<ProcessDetail>>> {
+        if !self.sources.read().await.contains_key(server_id) {
+            self.start_monitoring(server_id).await;
         }
-    }
-}
+        let source = self
+            .sources
+            .read()
+            .await
+            .get(server_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No metric source for server {}", server_id))?;
 
-fn calculate_disk_usage(system: &System) -> f32 {
-    let disks = system.disks();
-    if disks.is_empty() {
-        return 0.0;
+        if !source.capabilities().process_table {
+            return Ok(None);
+        }
+        source.processes().await.map(Some)
     }
 
-    let total: u64 = disks.iter().map(|disk| disk.total_space()).sum();
-    let used: u64 = disks.iter().map(|disk| disk.total_space() - disk.available_space()).sum();
+    // 설정된 systemd 유닛의 현재 상태. 수집원이 지원하지 않으면 None
+    pub async fn list_services(&self, server_id: &str) -> anyhow::Result<Option<Vec<ServiceStatus>>> {
+        if !self.sources.read().await.contains_key(server_id) {
+            self.start_monitoring(server_id).await;
+        }
+        let source = self
+            .sources
+            .read()
+            .await
+            .get(server_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No metric source for server {}", server_id))?;
 
-    (used as f32 / total as f32) * 100.0
-}
+        if !source.capabilities().services {
+            return Ok(None);
+        }
+        let units = self.repo.list_service_units(server_id).await?;
+        source.services(units).await.map(Some)
+    }
 
-fn collect_process_metrics(system: &System) -> Vec<ProcessMetrics> {
-    let mut processes: Vec<ProcessMetrics> = system
-        .processes()
-        .iter()
-        .map(|(pid, process)| ProcessMetrics::new(
-            pid.as_u32(),
-            process.name().to_string(),
-            process.cpu_usage(),
-            process.memory(),
-        ))
-        .collect();
-
-    processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap());
-    processes.truncate(10);
-    
-    processes
-}
\ No newline at end of file
+    pub async fn stop_monitoring(&self, server_id: &str) {
+        if self.sources.write().await.remove(server_id).is_some() {
+            self.latest.write().await.remove(server_id);
+            self.alerts.clear(server_id);
+            self.watchlist.clear(server_id);
+            self.service_alerts.clear(server_id);
+            let _ = self.repo.update_server_status(server_id, false).await;
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// src/monitoring/packages.rs
+use std::collections::{HashMap, HashSet};
+use anyhow::{anyhow, Result};
+use crate::models::package::{InstalledPackage, PackageInventory, PackageUpdate};
+
+pub const PACKAGE_SECTION_MARKER: &str = "__FLICK_PACKAGES__";
+
+// 패키지 목록은 자주 바뀌지 않으므로 모니터링 시작 시와 6시간마다 조회한다
+pub const PACKAGES_INTERVAL: std::time::Duration = std::time::Duration::from_secs(6 * 60 * 60);
+
+// 패키지 인덱스는 갱신하지 않는다 (apt update / dnf makecache 는 호스트의 정기 작업에 맡긴다)
+const PACKAGE_SCRIPT: &str = r#"if command -v dpkg-query >/dev/null 2>&1; then
+  echo dpkg; echo __FLICK_PACKAGES__
+  dpkg-query -W -f='${db:Status-Abbrev}\t${Package}\t${Version}\t${Architecture}\n'; echo __FLICK_PACKAGES__
+  apt list --upgradable 2>/dev/null
+elif command -v rpm >/dev/null 2>&1; then
+  P=yum; command -v dnf >/dev/null 2>&1 && P=dnf
+  echo rpm; echo __FLICK_PACKAGES__
+  rpm -qa --qf '%{NAME}\t%|EPOCH?{%{EPOCH}:}:{}|%{VERSION}-%{RELEASE}\t%{ARCH}\n'; echo __FLICK_PACKAGES__
+  $P -q check-update 2>/dev/null; echo __FLICK_PACKAGES__
+  $P -q updateinfo list --security 2>/dev/null
+else
+  echo none
+fi"#;
+
+pub fn command() -> &'static str {
+    PACKAGE_SCRIPT
+}
+
+pub fn parse_package_output(output: &str) -> Result<PackageInventory> {
+    let sections: Vec<&str> = output
+        .split(PACKAGE_SECTION_MARKER)
+        .map(|s| s.trim_matches('\n'))
+        .collect();
+
+    match sections[0].trim() {
+        "dpkg" if sections.len() >= 3 => Ok(PackageInventory {
+            manager: "dpkg".to_string(),
+            packages: parse_dpkg_query(sections[1]),
+            updates: parse_apt_upgradable(sections[2]),
+        }),
+        "rpm" if sections.len() >= 4 => {
+            let packages = parse_rpm_query(sections[1]);
+            let security = parse_security_advisories(sections[3]);
+            let updates = parse_dnf_check_update(sections[2], &packages, &security);
+            Ok(PackageInventory { manager: "rpm".to_string(), packages, updates })
+        }
+        "none" => Err(anyhow!("No supported package manager (dpkg, rpm) found")),
+        _ => Err(anyhow!("Unexpected package output ({} sections)", sections.len())),
+    }
+}
+
+// "ii \topenssl\t3.0.2-0ubuntu1.14\tamd64" - 설치된(ii) 패키지만
+pub fn parse_dpkg_query(input: &str) -> Vec<InstalledPackage> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() < 4 || !fields[0].starts_with("ii") {
+                return None;
+            }
+            Some(InstalledPackage {
+                name: fields[1].to_string(),
+                version: fields[2].to_string(),
+                architecture: fields[3].to_string(),
+            })
+        })
+        .collect()
+}
+
+// "openssl/jammy-updates,jammy-security 3.0.2-0ubuntu1.15 amd64 [upgradable from: 3.0.2-0ubuntu1.14]"
+pub fn parse_apt_upgradable(input: &str) -> Vec<PackageUpdate> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (name, sources) = fields.first()?.split_once('/')?;
+            let available_version = fields.get(1)?.to_string();
+            let current_version = line
+                .split_once("upgradable from: ")
+                .map(|(_, rest)| rest.trim_end_matches(']').trim().to_string());
+            Some(PackageUpdate {
+                name: name.to_string(),
+                current_version,
+                available_version,
+                security: sources.split(',').any(|source| source.ends_with("-security")),
+            })
+        })
+        .collect()
+}
+
+// "openssl\t1:3.0.7-24.el9\tx86_64"
+pub fn parse_rpm_query(input: &str) -> Vec<InstalledPackage> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() < 3 || fields[0].starts_with("gpg-pubkey") {
+                return None;
+            }
+            Some(InstalledPackage {
+                name: fields[0].to_string(),
+                version: fields[1].to_string(),
+                architecture: fields[2].to_string(),
+            })
+        })
+        .collect()
+}
+
+// "openssl.x86_64   1:3.0.7-25.el9_3   baseos" - "Obsoleting Packages" 이후는 무시
+pub fn parse_dnf_check_update(
+    input: &str,
+    installed: &[InstalledPackage],
+    security: &HashSet<String>,
+) -> Vec<PackageUpdate> {
+    let current: HashMap<&str, &str> = installed
+        .iter()
+        .map(|p| (p.name.as_str(), p.version.as_str()))
+        .collect();
+
+    input
+        .lines()
+        .take_while(|line| !line.starts_with("Obsoleting"))
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 3 {
+                return None;
+            }
+            let (name, _arch) = fields[0].rsplit_once('.')?;
+            Some(PackageUpdate {
+                name: name.to_string(),
+                current_version: current.get(name).map(|v| v.to_string()),
+                available_version: fields[1].to_string(),
+                security: security.contains(name),
+            })
+        })
+        .collect()
+}
+
+// "RHSA-2024:1234 Important/Sec. openssl-1:3.0.7-25.el9_3.x86_64" -> 패키지 이름 집합
+pub fn parse_security_advisories(input: &str) -> HashSet<String> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let nevra = line.split_whitespace().nth(2)?;
+            let (nevr, _arch) = nevra.rsplit_once('.')?;
+            let mut parts = nevr.rsplitn(3, '-');
+            let (_release, _version) = (parts.next()?, parts.next()?);
+            parts.next().map(str::to_string)
+        })
+        .collect()
+}
@@ -0,0 +1,758 @@
+// src/monitoring/parsers.rs
+use std::collections::{HashMap, HashSet};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Utc};
+use crate::config::{DiskIoConfig, FilesystemConfig, NetworkConfig};
+use crate::models::metrics::{
+    CpuBreakdown, DiskIoMetrics, FilesystemMetrics, LoadAverage, MemoryMetrics, NetworkInterfaceMetrics,
+    ProcessMetrics,
+};
+use crate::models::process::ProcessDetail;
+use crate::models::service::ServiceStatus;
+
+// /proc/stat 의 cpu 라인 (단위: jiffies)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CpuTimes {
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+}
+
+impl CpuTimes {
+    pub fn total(&self) -> u64 {
+        self.user + self.nice + self.system + self.idle
+            + self.iowait + self.irq + self.softirq + self.steal
+    }
+
+    pub fn idle_total(&self) -> u64 {
+        self.idle + self.iowait
+    }
+
+    // 이전 샘플 대비 사용률(%) 계산
+    pub fn usage_since(&self, prev: &CpuTimes) -> f32 {
+        let total = self.total().saturating_sub(prev.total());
+        if total == 0 {
+            return 0.0;
+        }
+        let idle = self.idle_total().saturating_sub(prev.idle_total());
+        ((total.saturating_sub(idle)) as f64 / total as f64 * 100.0) as f32
+    }
+
+    // 이전 샘플 대비 user/system/iowait/steal 비율(%)
+    pub fn breakdown_since(&self, prev: &CpuTimes) -> CpuBreakdown {
+        let total = self.total().saturating_sub(prev.total());
+        if total == 0 {
+            return CpuBreakdown::default();
+        }
+        let percent = |current: u64, previous: u64| {
+            (current.saturating_sub(previous) as f64 / total as f64 * 100.0) as f32
+        };
+
+        CpuBreakdown {
+            user: percent(self.user + self.nice, prev.user + prev.nice),
+            system: percent(
+                self.system + self.irq + self.softirq,
+                prev.system + prev.irq + prev.softirq,
+            ),
+            iowait: percent(self.iowait, prev.iowait),
+            steal: percent(self.steal, prev.steal),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcStat {
+    pub total: CpuTimes,
+    pub cpus: Vec<CpuTimes>,
+}
+
+impl ProcStat {
+    // 코어별 사용률(%). 이전 샘플에 없던 코어는 부팅 이후 누적값 기준
+    pub fn per_core_usage_since(&self, prev: &ProcStat) -> Vec<f32> {
+        self.cpus
+            .iter()
+            .enumerate()
+            .map(|(i, cpu)| cpu.usage_since(&prev.cpus.get(i).copied().unwrap_or_default()))
+            .collect()
+    }
+}
+
+pub fn parse_proc_stat(input: &str) -> Result<ProcStat> {
+    let mut stat = ProcStat::default();
+    let mut found_total = false;
+
+    for line in input.lines() {
+        let mut fields = line.split_whitespace();
+        let label = match fields.next() {
+            Some(label) if label.starts_with("cpu") => label,
+            _ => continue,
+        };
+
+        let values: Vec<u64> = fields
+            .map(|v| v.parse::<u64>())
+            .collect::<Result<_, _>>()
+            .map_err(|e| anyhow!("Invalid /proc/stat line '{}': {}", line, e))?;
+        if values.len() < 4 {
+            return Err(anyhow!("Invalid /proc/stat line '{}'", line));
+        }
+
+        let field = |i: usize| values.get(i).copied().unwrap_or(0);
+        let times = CpuTimes {
+            user: field(0),
+            nice: field(1),
+            system: field(2),
+            idle: field(3),
+            iowait: field(4),
+            irq: field(5),
+            softirq: field(6),
+            steal: field(7),
+        };
+
+        if label == "cpu" {
+            stat.total = times;
+            found_total = true;
+        } else {
+            stat.cpus.push(times);
+        }
+    }
+
+    if !found_total {
+        return Err(anyhow!("No aggregate cpu line in /proc/stat"));
+    }
+    Ok(stat)
+}
+
+// /proc/loadavg: "0.52 0.58 0.59 1/467 12345"
+pub fn parse_loadavg(input: &str) -> Result<LoadAverage> {
+    let values: Vec<f64> = input
+        .split_whitespace()
+        .take(3)
+        .map(|v| v.parse::<f64>())
+        .collect::<Result<_, _>>()
+        .map_err(|e| anyhow!("Invalid /proc/loadavg '{}': {}", input.trim(), e))?;
+    if values.len() < 3 {
+        return Err(anyhow!("Invalid /proc/loadavg '{}'", input.trim()));
+    }
+
+    Ok(LoadAverage {
+        one: values[0],
+        five: values[1],
+        fifteen: values[2],
+    })
+}
+
+// /proc/meminfo (바이트 단위로 변환)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemInfo {
+    pub total: u64,
+    pub free: u64,
+    pub available: Option<u64>,
+    pub buffers: u64,
+    pub cached: u64,
+    pub swap_total: u64,
+    pub swap_free: u64,
+}
+
+impl MemInfo {
+    pub fn used(&self) -> u64 {
+        match self.available {
+            Some(available) => self.total.saturating_sub(available),
+            // MemAvailable 이 없는 오래된 커널
+            None => self.total.saturating_sub(self.free + self.buffers + self.cached),
+        }
+    }
+
+    pub fn usage_percent(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        (self.used() as f64 / self.total as f64 * 100.0) as f32
+    }
+
+    pub fn metrics(&self) -> MemoryMetrics {
+        MemoryMetrics {
+            total: self.total,
+            used: self.used(),
+            available: self.total.saturating_sub(self.used()),
+            cached: self.cached,
+            buffers: self.buffers,
+            swap_total: self.swap_total,
+            swap_used: self.swap_total.saturating_sub(self.swap_free),
+        }
+    }
+}
+
+pub fn parse_meminfo(input: &str) -> Result<MemInfo> {
+    let mut info = MemInfo::default();
+    let mut found_total = false;
+
+    for line in input.lines() {
+        let (key, rest) = match line.split_once(':') {
+            Some(pair) => pair,
+            None => continue,
+        };
+        let mut parts = rest.split_whitespace();
+        let value = match parts.next().and_then(|v| v.parse::<u64>().ok()) {
+            Some(value) => value,
+            None => continue,
+        };
+        let bytes = match parts.next() {
+            Some("kB") => value * 1024,
+            _ => value,
+        };
+
+        match key.trim() {
+            "MemTotal" => {
+                info.total = bytes;
+                found_total = true;
+            }
+            "MemFree" => info.free = bytes,
+            "MemAvailable" => info.available = Some(bytes),
+            "Buffers" => info.buffers = bytes,
+            "Cached" => info.cached = bytes,
+            "SwapTotal" => info.swap_total = bytes,
+            "SwapFree" => info.swap_free = bytes,
+            _ => {}
+        }
+    }
+
+    if !found_total {
+        return Err(anyhow!("MemTotal missing from /proc/meminfo"));
+    }
+    Ok(info)
+}
+
+// /proc/net/dev 의 인터페이스별 누적 카운터
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetDevStats {
+    pub interface: String,
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errors: u64,
+    pub rx_drops: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errors: u64,
+    pub tx_drops: u64,
+}
+
+pub fn parse_net_dev(input: &str) -> Result<Vec<NetDevStats>> {
+    let mut interfaces = Vec::new();
+
+    // 처음 두 줄은 헤더
+    for line in input.lines().skip(2) {
+        let (name, rest) = match line.split_once(':') {
+            Some(pair) => pair,
+            None => continue,
+        };
+        let values: Vec<u64> = rest
+            .split_whitespace()
+            .map(|v| v.parse::<u64>())
+            .collect::<Result<_, _>>()
+            .map_err(|e| anyhow!("Invalid /proc/net/dev line '{}': {}", line, e))?;
+        if values.len() < 16 {
+            return Err(anyhow!("Invalid /proc/net/dev line '{}'", line));
+        }
+
+        interfaces.push(NetDevStats {
+            interface: name.trim().to_string(),
+            rx_bytes: values[0],
+            rx_packets: values[1],
+            rx_errors: values[2],
+            rx_drops: values[3],
+            tx_bytes: values[8],
+            tx_packets: values[9],
+            tx_errors: values[10],
+            tx_drops: values[11],
+        });
+    }
+
+    Ok(interfaces)
+}
+
+// 32비트 카운터가 한 바퀴 돌았다고 볼 이전 값의 하한 (32비트 범위의 위쪽 절반)
+const COUNTER_WRAP_THRESHOLD: u64 = 1 << 31;
+
+// 카운터 증가량. 이전 값이 32비트 최대치 가까이에서 줄어들면 한 바퀴 돈 것으로 보고,
+// 그 밖에 줄어든 경우는 인터페이스 재설정이나 재부팅으로 보고 0 을 돌려준다
+pub fn counter_delta(prev: u64, current: u64) -> u64 {
+    if current >= prev {
+        current - prev
+    } else if (COUNTER_WRAP_THRESHOLD..=u32::MAX as u64).contains(&prev) {
+        (u32::MAX as u64 - prev) + current + 1
+    } else {
+        0
+    }
+}
+
+// 이전 샘플에 없던 인터페이스는 기준값이 없으므로 건너뛴다
+pub fn interface_rates(
+    prev: &[NetDevStats],
+    current: &[NetDevStats],
+    elapsed_secs: f64,
+    filter: &NetworkConfig,
+) -> Vec<NetworkInterfaceMetrics> {
+    if elapsed_secs <= 0.0 {
+        return Vec::new();
+    }
+
+    current
+        .iter()
+        .filter(|iface| !filter.excludes(&iface.interface))
+        .filter_map(|iface| {
+            let p = prev.iter().find(|p| p.interface == iface.interface)?;
+            let rate = |prev: u64, current: u64| counter_delta(prev, current) as f64 / elapsed_secs;
+            Some(NetworkInterfaceMetrics {
+                interface: iface.interface.clone(),
+                rx_bytes_per_sec: rate(p.rx_bytes, iface.rx_bytes),
+                tx_bytes_per_sec: rate(p.tx_bytes, iface.tx_bytes),
+                rx_packets_per_sec: rate(p.rx_packets, iface.rx_packets),
+                tx_packets_per_sec: rate(p.tx_packets, iface.tx_packets),
+                rx_errors: counter_delta(p.rx_errors, iface.rx_errors),
+                tx_errors: counter_delta(p.tx_errors, iface.tx_errors),
+                rx_drops: counter_delta(p.rx_drops, iface.rx_drops),
+                tx_drops: counter_delta(p.tx_drops, iface.tx_drops),
+            })
+        })
+        .collect()
+}
+
+// 인터페이스 합계 (bytes/s)
+pub fn total_rates(interfaces: &[NetworkInterfaceMetrics]) -> (u64, u64) {
+    let rx: f64 = interfaces.iter().map(|i| i.rx_bytes_per_sec).sum();
+    let tx: f64 = interfaces.iter().map(|i| i.tx_bytes_per_sec).sum();
+    (rx.round() as u64, tx.round() as u64)
+}
+
+// /proc/diskstats 의 한 줄. 섹터는 항상 512 bytes 단위
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DiskStats {
+    pub device: String,
+    pub reads_completed: u64,
+    pub sectors_read: u64,
+    pub read_time_ms: u64,
+    pub writes_completed: u64,
+    pub sectors_written: u64,
+    pub write_time_ms: u64,
+    pub io_time_ms: u64,          // 장치가 I/O 를 처리하던 시간
+}
+
+const SECTOR_SIZE: u64 = 512;
+
+pub fn parse_diskstats(input: &str) -> Result<Vec<DiskStats>> {
+    let mut disks = Vec::new();
+
+    for line in input.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.is_empty() {
+            continue;
+        }
+        if fields.len() < 14 {
+            return Err(anyhow!("Invalid /proc/diskstats line '{}'", line));
+        }
+
+        let value = |i: usize| {
+            fields[i]
+                .parse::<u64>()
+                .map_err(|e| anyhow!("Invalid /proc/diskstats line '{}': {}", line, e))
+        };
+        disks.push(DiskStats {
+            device: fields[2].to_string(),
+            reads_completed: value(3)?,
+            sectors_read: value(5)?,
+            read_time_ms: value(6)?,
+            writes_completed: value(7)?,
+            sectors_written: value(9)?,
+            write_time_ms: value(10)?,
+            io_time_ms: value(12)?,
+        });
+    }
+
+    Ok(disks)
+}
+
+// 다른 장치 이름 뒤에 번호가 붙은 것은 파티션으로 본다.
+// 이름이 숫자로 끝나는 장치는 'p' 를 사이에 둔다 (sda1, nvme0n1p1, mmcblk0p1 - dm-10 은 dm-1 의 파티션이 아님)
+fn is_partition(device: &str, all: &[DiskStats]) -> bool {
+    all.iter().any(|d| {
+        let rest = match device.strip_prefix(d.device.as_str()) {
+            Some(rest) if !rest.is_empty() => rest,
+            _ => return false,
+        };
+        let number = if d.device.ends_with(|c: char| c.is_ascii_digit()) {
+            match rest.strip_prefix('p') {
+                Some(number) => number,
+                None => return false,
+            }
+        } else {
+            rest
+        };
+        !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+// 파티션은 전체 장치에 이미 포함되므로 제외한다
+pub fn disk_io_rates(
+    prev: &[DiskStats],
+    current: &[DiskStats],
+    elapsed_secs: f64,
+    filter: &DiskIoConfig,
+) -> Vec<DiskIoMetrics> {
+    if elapsed_secs <= 0.0 {
+        return Vec::new();
+    }
+
+    current
+        .iter()
+        .filter(|d| !filter.excludes(&d.device) && !is_partition(&d.device, current))
+        .filter_map(|d| {
+            let p = prev.iter().find(|p| p.device == d.device)?;
+            let reads = counter_delta(p.reads_completed, d.reads_completed);
+            let writes = counter_delta(p.writes_completed, d.writes_completed);
+            let wait_ms = counter_delta(p.read_time_ms, d.read_time_ms)
+                + counter_delta(p.write_time_ms, d.write_time_ms);
+            let busy_ms = counter_delta(p.io_time_ms, d.io_time_ms);
+
+            Some(DiskIoMetrics {
+                device: d.device.clone(),
+                read_bytes_per_sec: (counter_delta(p.sectors_read, d.sectors_read) * SECTOR_SIZE) as f64 / elapsed_secs,
+                write_bytes_per_sec: (counter_delta(p.sectors_written, d.sectors_written) * SECTOR_SIZE) as f64 / elapsed_secs,
+                read_iops: reads as f64 / elapsed_secs,
+                write_iops: writes as f64 / elapsed_secs,
+                // iostat 의 await 와 같이 완료된 I/O 당 평균 대기 시간
+                await_ms: if reads + writes > 0 { wait_ms as f64 / (reads + writes) as f64 } else { 0.0 },
+                utilization: (busy_ms as f64 / (elapsed_secs * 1000.0) * 100.0).min(100.0) as f32,
+            })
+        })
+        .collect()
+}
+
+// /proc/uptime 의 첫 값 (부팅 후 경과 초)
+pub fn parse_uptime(input: &str) -> Result<f64> {
+    input
+        .split_whitespace()
+        .next()
+        .and_then(|v| v.parse::<f64>().ok())
+        .ok_or_else(|| anyhow!("Invalid /proc/uptime '{}'", input.trim()))
+}
+
+// `uname -r` 결과와 os-release 의 PRETTY_NAME 줄 -> (커널, OS)
+pub fn parse_os_info(input: &str) -> (Option<String>, Option<String>) {
+    let mut lines = input.lines().map(str::trim).filter(|l| !l.is_empty());
+    let kernel = lines.next().filter(|l| !l.contains('=')).map(str::to_string);
+    let os = input
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("PRETTY_NAME="))
+        .map(|name| name.trim_matches(|c| c == '"' || c == '\'').to_string())
+        .filter(|name| !name.is_empty());
+    (kernel, os)
+}
+
+// `df -P -k` 또는 `df -P -k -T` 출력 (1K 블록)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DfEntry {
+    pub filesystem: String,
+    pub fs_type: Option<String>,
+    pub total: u64,
+    pub used: u64,
+    pub available: u64,
+    pub mount_point: String,
+}
+
+pub fn parse_df(input: &str) -> Result<Vec<DfEntry>> {
+    let mut entries = Vec::new();
+    let mut lines = input.lines();
+    let typed = match lines.next() {
+        Some(header) => header.split_whitespace().nth(1) == Some("Type"),
+        None => return Ok(entries),
+    };
+    let offset = if typed { 1 } else { 0 };
+
+    for line in lines {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.is_empty() {
+            continue;
+        }
+        if fields.len() < 6 + offset {
+            return Err(anyhow!("Invalid df line '{}'", line));
+        }
+
+        let parse_blocks = |s: &str| -> Result<u64> {
+            s.parse::<u64>()
+                .map(|blocks| blocks * 1024)
+                .map_err(|e| anyhow!("Invalid df value '{}': {}", s, e))
+        };
+
+        entries.push(DfEntry {
+            filesystem: fields[0].to_string(),
+            fs_type: typed.then(|| fields[1].to_string()),
+            total: parse_blocks(fields[1 + offset])?,
+            used: parse_blocks(fields[2 + offset])?,
+            available: parse_blocks(fields[3 + offset])?,
+            // 마운트 경로에 공백이 있을 수 있음
+            mount_point: fields[5 + offset..].join(" "),
+        });
+    }
+
+    Ok(entries)
+}
+
+// `df -P -i` 출력. inode 개념이 없는 파일시스템은 '-' 로 표시된다
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DfInodeEntry {
+    pub filesystem: String,
+    pub inodes: Option<u64>,
+    pub used: Option<u64>,
+    pub free: Option<u64>,
+    pub mount_point: String,
+}
+
+pub fn parse_df_inodes(input: &str) -> Result<Vec<DfInodeEntry>> {
+    let mut entries = Vec::new();
+
+    for line in input.lines().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.is_empty() {
+            continue;
+        }
+        if fields.len() < 6 {
+            return Err(anyhow!("Invalid df -i line '{}'", line));
+        }
+
+        entries.push(DfInodeEntry {
+            filesystem: fields[0].to_string(),
+            inodes: fields[1].parse().ok(),
+            used: fields[2].parse().ok(),
+            free: fields[3].parse().ok(),
+            mount_point: fields[5..].join(" "),
+        });
+    }
+
+    Ok(entries)
+}
+
+// 제외 대상을 거르고, 같은 블록 장치가 여러 곳에 마운트된 경우(bind mount) 한 번만 센다
+pub fn build_filesystems(
+    entries: &[DfEntry],
+    inodes: &[DfInodeEntry],
+    filter: &FilesystemConfig,
+) -> Vec<FilesystemMetrics> {
+    let mut seen_devices = std::collections::HashSet::new();
+
+    entries
+        .iter()
+        .filter(|e| !filter.excludes(e.fs_type.as_deref().unwrap_or_default(), &e.mount_point))
+        .filter(|e| !e.filesystem.starts_with('/') || seen_devices.insert(e.filesystem.clone()))
+        .map(|e| {
+            // inode 수가 0 인 파일시스템(btrfs 등)은 정보 없음으로 취급
+            let inode = inodes
+                .iter()
+                .find(|i| i.mount_point == e.mount_point)
+                .filter(|i| i.inodes.unwrap_or(0) > 0);
+            FilesystemMetrics {
+                mount_point: e.mount_point.clone(),
+                device: e.filesystem.clone(),
+                fs_type: e.fs_type.clone().unwrap_or_default(),
+                total: e.total,
+                used: e.used,
+                free: e.available,
+                inodes_total: inode.and_then(|i| i.inodes),
+                inodes_used: inode.and_then(|i| i.used),
+                inodes_free: inode.and_then(|i| i.free),
+            }
+        })
+        .collect()
+}
+
+pub fn filesystems_usage_percent(filesystems: &[FilesystemMetrics]) -> f32 {
+    let total: u64 = filesystems.iter().map(|f| f.total).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let used: u64 = filesystems.iter().map(|f| f.used).sum();
+    (used as f64 / total as f64 * 100.0) as f32
+}
+
+pub fn disk_usage_percent(entries: &[DfEntry]) -> f32 {
+    let total: u64 = entries.iter().map(|e| e.total).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let used: u64 = entries.iter().map(|e| e.used).sum();
+    (used as f64 / total as f64 * 100.0) as f32
+}
+
+// `ps -eo pid,pcpu,rss,comm` 출력 (rss 는 KiB)
+pub fn parse_ps(input: &str) -> Result<Vec<ProcessMetrics>> {
+    let mut processes = Vec::new();
+
+    for line in input.lines().skip(1) {
+        let mut fields = line.split_whitespace();
+        let (pid, cpu, rss) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(pid), Some(cpu), Some(rss)) => (pid, cpu, rss),
+            _ => continue,
+        };
+        let name = fields.collect::<Vec<_>>().join(" ");
+
+        processes.push(ProcessMetrics::new(
+            pid.parse().map_err(|e| anyhow!("Invalid pid '{}': {}", pid, e))?,
+            name,
+            cpu.parse().map_err(|e| anyhow!("Invalid cpu '{}': {}", cpu, e))?,
+            rss.parse::<u64>().map_err(|e| anyhow!("Invalid rss '{}': {}", rss, e))? * 1024,
+        ));
+    }
+
+    Ok(processes)
+}
+
+// 주기적 스냅샷에 담을 프로세스: CPU 상위 limit 개와 메모리(RSS) 상위 limit 개의 합집합을 CPU 순으로.
+// CPU 를 쓰지 않고 메모리만 잡고 있는 프로세스도 메모리 기준 집계에 잡히게 한다
+pub fn snapshot_processes(mut processes: Vec<ProcessMetrics>, limit: usize) -> Vec<ProcessMetrics> {
+    processes.sort_by_key(|p| p.pid);
+    processes.dedup_by_key(|p| p.pid);
+
+    processes.sort_by(|a, b| b.memory_usage.cmp(&a.memory_usage).then(a.pid.cmp(&b.pid)));
+    let by_memory: HashSet<u32> = processes.iter().take(limit).map(|p| p.pid).collect();
+
+    processes.sort_by(|a, b| {
+        b.cpu_usage
+            .partial_cmp(&a.cpu_usage)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.pid.cmp(&b.pid))
+    });
+    processes
+        .into_iter()
+        .enumerate()
+        .filter(|(rank, p)| *rank < limit || by_memory.contains(&p.pid))
+        .map(|(_, p)| p)
+        .collect()
+}
+
+// ps STAT 첫 글자 -> 상태 이름
+pub fn process_state(code: char) -> &'static str {
+    match code {
+        'R' => "running",
+        'S' => "sleeping",
+        'D' => "disk_sleep",
+        'T' => "stopped",
+        't' => "tracing",
+        'Z' => "zombie",
+        'X' => "dead",
+        'I' => "idle",
+        _ => "unknown",
+    }
+}
+
+// `ps -eo pid=,ppid=,user:32=,stat=,nlwp=,etimes=,pcpu=,rss=,args=` 출력
+// 이름은 공백을 포함할 수 있어 `ps -eo pid=,comm=` 결과(names)에서 따로 가져온다
+pub fn parse_ps_detail(input: &str, names: &HashMap<u32, String>, now: DateTime<Utc>) -> Result<Vec<ProcessDetail>> {
+    let mut processes = Vec::new();
+
+    for line in input.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.is_empty() {
+            continue;
+        }
+        if fields.len() < 8 {
+            return Err(anyhow!("Invalid ps line '{}'", line));
+        }
+
+        let number = |i: usize| {
+            fields[i]
+                .parse::<u64>()
+                .map_err(|e| anyhow!("Invalid ps line '{}': {}", line, e))
+        };
+        let pid = number(0)? as u32;
+        let command = fields[8..].join(" ");
+        // 이름을 못 찾으면 실행 파일 이름으로 대신한다
+        let name = match names.get(&pid) {
+            Some(name) => name.clone(),
+            None => fields.get(8).and_then(|exe| exe.rsplit('/').next()).unwrap_or_default().to_string(),
+        };
+
+        processes.push(ProcessDetail {
+            pid,
+            ppid: Some(number(1)? as u32),
+            name,
+            user: Some(fields[2].to_string()),
+            command,
+            state: process_state(fields[3].chars().next().unwrap_or('?')).to_string(),
+            threads: Some(number(4)? as u32),
+            start_time: Some(now - Duration::seconds(number(5)? as i64)),
+            cpu_usage: fields[6].parse().map_err(|e| anyhow!("Invalid cpu '{}': {}", fields[6], e))?,
+            memory_usage: number(7)? * 1024,
+            open_files: None,
+        });
+    }
+
+    Ok(processes)
+}
+
+// `ps -eo pid=,comm=` 출력
+pub fn parse_ps_names(input: &str) -> HashMap<u32, String> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let (pid, name) = line.trim_start().split_once(char::is_whitespace)?;
+            Some((pid.parse().ok()?, name.trim().to_string()))
+        })
+        .collect()
+}
+
+// "<pid> <열린 fd 수>" 줄 목록
+pub fn parse_fd_counts(input: &str) -> HashMap<u32, u32> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some((fields.next()?.parse().ok()?, fields.next()?.parse().ok()?))
+        })
+        .collect()
+}
+
+// `systemctl show --property=Id,LoadState,ActiveState,SubState,NRestarts,StateChangeTimestampMonotonic` 출력.
+// 유닛마다 빈 줄로 구분된다. 시각은 부팅 후 경과(µs)라 /proc/uptime 으로 환산한다
+pub fn parse_systemctl_show(input: &str, uptime_secs: f64, now: DateTime<Utc>) -> Result<Vec<ServiceStatus>> {
+    let mut services = Vec::new();
+
+    for block in input.split("\n\n") {
+        let properties: HashMap<&str, &str> = block
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .collect();
+        if properties.is_empty() {
+            continue;
+        }
+
+        let property = |key: &str| {
+            properties
+                .get(key)
+                .map(|v| v.to_string())
+                .ok_or_else(|| anyhow!("Missing {} in systemctl output", key))
+        };
+        let since = properties
+            .get("StateChangeTimestampMonotonic")
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|&micros| micros > 0)
+            .map(|micros| {
+                let ago = (uptime_secs - micros as f64 / 1_000_000.0).max(0.0);
+                now - Duration::milliseconds((ago * 1000.0).round() as i64)
+            });
+
+        services.push(ServiceStatus {
+            unit: property("Id")?,
+            load_state: property("LoadState")?,
+            active_state: property("ActiveState")?,
+            sub_state: property("SubState")?,
+            restarts: properties.get("NRestarts").and_then(|v| v.parse().ok()),
+            since,
+        });
+    }
+
+    Ok(services)
+}
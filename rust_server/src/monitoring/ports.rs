@@ -0,0 +1,165 @@
+// src/monitoring/ports.rs
+use std::collections::HashSet;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use crate::db::models::{Alert, AlertSeverity};
+use crate::models::port::ListeningPort;
+
+pub const PORT_SECTION_MARKER: &str = "__FLICK_PORTS__";
+
+// 대기 포트는 메트릭보다 드물게 (1분마다) 확인한다
+pub const PORTS_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+// ss 가 없으면 /proc/net 을 읽는다 (이 경우 프로세스 정보는 없다)
+const PORT_SCRIPT: &str = r#"if command -v ss >/dev/null 2>&1; then
+  echo ss; echo __FLICK_PORTS__; ss -tulpn 2>/dev/null
+else
+  echo proc
+  for f in tcp tcp6 udp udp6; do echo __FLICK_PORTS__; echo $f; cat /proc/net/$f 2>/dev/null; done
+fi"#;
+
+pub fn command() -> &'static str {
+    PORT_SCRIPT
+}
+
+pub fn parse_port_output(output: &str) -> Result<Vec<ListeningPort>> {
+    let sections: Vec<&str> = output
+        .split(PORT_SECTION_MARKER)
+        .map(|s| s.trim_matches('\n'))
+        .collect();
+
+    let ports = match sections[0].trim() {
+        "ss" if sections.len() >= 2 => parse_ss(sections[1]),
+        "proc" => sections[1..].iter().flat_map(|section| parse_proc_net(section)).collect(),
+        _ => return Err(anyhow!("Unexpected port output ({} sections)", sections.len())),
+    };
+    Ok(dedup(ports))
+}
+
+// "tcp   LISTEN 0  4096  [::]:80  [::]:*  users:(("nginx",pid=1201,fd=7),("nginx",pid=1200,fd=7))"
+pub fn parse_ss(input: &str) -> Vec<ListeningPort> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 5 || !matches!(fields[0], "tcp" | "udp") {
+                return None;
+            }
+            let (address, port) = fields[4].rsplit_once(':')?;
+            let process = fields.get(6..).unwrap_or_default().join(" ");
+            Some(ListeningPort {
+                protocol: fields[0].to_string(),
+                address: normalize_address(address),
+                port: port.parse().ok()?,
+                process: process
+                    .split_once("((\"")
+                    .and_then(|(_, rest)| rest.split_once('"'))
+                    .map(|(name, _)| name.to_string()),
+                pid: process
+                    .split_once("pid=")
+                    .and_then(|(_, rest)| rest.split(|c: char| !c.is_ascii_digit()).next())
+                    .and_then(|pid| pid.parse().ok()),
+            })
+        })
+        .collect()
+}
+
+// "[::1]" -> "::1", "127.0.0.53%lo" -> "127.0.0.53"
+fn normalize_address(address: &str) -> String {
+    let address = address.trim_start_matches('[').trim_end_matches(']');
+    address.split('%').next().unwrap_or(address).to_string()
+}
+
+// 첫 줄은 파일 이름(tcp, tcp6, udp, udp6), 나머지는 /proc/net/<file> 내용
+pub fn parse_proc_net(section: &str) -> Vec<ListeningPort> {
+    let mut lines = section.lines();
+    let file = lines.next().unwrap_or_default().trim();
+    let (protocol, listen_state) = match file {
+        "tcp" | "tcp6" => ("tcp", "0A"),
+        "udp" | "udp6" => ("udp", "07"),
+        _ => return Vec::new(),
+    };
+
+    lines
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 4 || fields[3] != listen_state {
+                return None;
+            }
+            let (address, port) = fields[1].split_once(':')?;
+            Some(ListeningPort {
+                protocol: protocol.to_string(),
+                address: decode_proc_address(address)?,
+                port: u16::from_str_radix(port, 16).ok()?,
+                process: None,
+                pid: None,
+            })
+        })
+        .collect()
+}
+
+// 커널이 32비트 단위로 호스트 바이트 순서(리틀 엔디언)로 출력한다
+fn decode_proc_address(hex: &str) -> Option<String> {
+    let words: Vec<[u8; 4]> = (0..hex.len() / 8)
+        .map(|i| u32::from_str_radix(hex.get(i * 8..i * 8 + 8)?, 16).ok().map(u32::to_le_bytes))
+        .collect::<Option<_>>()?;
+    match words.as_slice() {
+        [v4] => Some(Ipv4Addr::from(*v4).to_string()),
+        [a, b, c, d] => {
+            let mut bytes = [0u8; 16];
+            for (i, word) in [a, b, c, d].iter().enumerate() {
+                bytes[i * 4..i * 4 + 4].copy_from_slice(*word);
+            }
+            Some(Ipv6Addr::from(bytes).to_string())
+        }
+        _ => None,
+    }
+}
+
+// SO_REUSEPORT 등으로 같은 소켓이 여러 번 나오면 첫 항목만 남긴다
+fn dedup(ports: Vec<ListeningPort>) -> Vec<ListeningPort> {
+    let mut seen = HashSet::new();
+    ports
+        .into_iter()
+        .filter(|port| seen.insert((port.protocol.clone(), port.address.clone(), port.port)))
+        .collect()
+}
+
+// 이전 수집 결과와 비교해 새로 열린 포트와 닫힌 포트를 돌려준다
+pub fn diff_ports(
+    previous: &[ListeningPort],
+    current: &[ListeningPort],
+) -> (Vec<ListeningPort>, Vec<ListeningPort>) {
+    let previous_keys: HashSet<_> = previous.iter().map(ListeningPort::key).collect();
+    let current_keys: HashSet<_> = current.iter().map(ListeningPort::key).collect();
+    let opened = current.iter().filter(|p| !previous_keys.contains(&p.key())).cloned().collect();
+    let closed = previous.iter().filter(|p| !current_keys.contains(&p.key())).cloned().collect();
+    (opened, closed)
+}
+
+pub fn opened_port_alerts(server_id: &str, opened: &[ListeningPort]) -> Vec<Alert> {
+    opened
+        .iter()
+        .map(|port| {
+            let owner = match (&port.process, port.pid) {
+                (Some(name), Some(pid)) => format!(" ({}, pid {})", name, pid),
+                (Some(name), None) => format!(" ({})", name),
+                _ => String::new(),
+            };
+            Alert {
+                id: 0,
+                server_id: server_id.to_string(),
+                alert_type: "port_opened".to_string(),
+                severity: AlertSeverity::Info,
+                message: format!(
+                    "New listening port {}/{} on {}{}",
+                    port.port, port.protocol, port.address, owner
+                ),
+                created_at: Utc::now(),
+                acknowledged_at: None,
+                acknowledged_by: None,
+            }
+        })
+        .collect()
+}
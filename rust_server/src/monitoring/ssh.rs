@@ -0,0 +1,430 @@
+// src/monitoring/ssh.rs
+use anyhow::{anyhow, Context, Result};
+use ssh2::Session;
+use std::io::Read;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use futures::future::BoxFuture;
+use tracing::debug;
+use crate::config::{DiskIoConfig, FilesystemConfig, NetworkConfig, DEFAULT_PROCESS_SNAPSHOT_LIMIT};
+use crate::db::models::{CollectionMethod, Server};
+use crate::models::agent::HostFacts;
+use crate::models::container::ContainerMetrics;
+use crate::models::metrics::{HostInfo, ServerMetrics};
+use crate::models::package::PackageInventory;
+use crate::models::port::ListeningPort;
+use crate::models::process::ProcessDetail;
+use crate::models::service::{is_valid_unit_name, ServiceStatus};
+use crate::monitoring::containers::ContainerCollector;
+use crate::monitoring::{inventory, packages, ports};
+use crate::monitoring::parsers::{self, DiskStats, NetDevStats, ProcStat};
+use crate::monitoring::traits::{MetricSource, SourceCapabilities};
+use crate::utils::encryption::Encryptor;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+// 블로킹 호출(핸드셰이크, 명령 출력 읽기) 하나를 기다리는 기본 시간
+const SESSION_TIMEOUT: Duration = Duration::from_secs(30);
+pub const SECTION_MARKER: &str = "__FLICK_SECTION__";
+
+#[derive(Debug, Clone)]
+pub struct SshTarget {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+}
+
+impl SshTarget {
+    pub fn from_server(server: &Server, encryptor: &Encryptor) -> Result<Self> {
+        let password = encryptor
+            .decrypt(&server.encrypted_password)
+            .map_err(|e| anyhow!("Failed to decrypt password for {}: {}", server.hostname, e))?;
+        let port = u16::try_from(server.port)
+            .map_err(|_| anyhow!("Invalid SSH port {} for {}", server.port, server.hostname))?;
+
+        Ok(Self {
+            host: server.hostname.clone(),
+            port,
+            username: server.username.clone(),
+            password,
+        })
+    }
+}
+
+// 재사용 가능한 SSH 세션. 끊어지면 다음 실행 시 재연결한다.
+pub struct SshSession {
+    target: SshTarget,
+    timeout: Duration,
+    session: Mutex<Option<Session>>,
+}
+
+impl SshSession {
+    pub fn new(target: SshTarget) -> Self {
+        Self {
+            target,
+            timeout: SESSION_TIMEOUT,
+            session: Mutex::new(None),
+        }
+    }
+
+    // 기본 시간보다 오래 걸릴 수 있는 명령(검사 플러그인 등)을 실행할 때 늘린다
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn target(&self) -> &SshTarget {
+        &self.target
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn connect(&self) -> Result<Session> {
+        let addr = (self.target.host.as_str(), self.target.port)
+            .to_socket_addrs()
+            .with_context(|| format!("DNS lookup failed for {}", self.target.host))?
+            .next()
+            .ok_or_else(|| anyhow!("Could not resolve {}", self.target.host))?;
+
+        let tcp = TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT)
+            .with_context(|| format!("Cannot connect to {}", addr))?;
+
+        let mut session = Session::new().context("SSH session creation failed")?;
+        session.set_tcp_stream(tcp);
+        session.set_timeout(self.timeout.as_millis().min(u32::MAX as u128) as u32);
+        session.handshake().context("SSH handshake failed")?;
+        session
+            .userauth_password(&self.target.username, &self.target.password)
+            .context("SSH authentication failed")?;
+
+        debug!("SSH session established to {}:{}", self.target.host, self.target.port);
+        Ok(session)
+    }
+
+    // 블로킹 호출 - async 컨텍스트에서는 exec 를 사용
+    pub fn exec_blocking(&self, command: &str) -> Result<String> {
+        self.exec_status_blocking(command).map(|(_, output)| output)
+    }
+
+    // 종료 코드와 stdout
+    pub fn exec_status_blocking(&self, command: &str) -> Result<(i32, String)> {
+        let (status, output) = self.exec_raw_blocking(command)?;
+        let output = String::from_utf8(output).context("Command output is not valid UTF-8")?;
+        Ok((status, output))
+    }
+
+    // 종료 코드와 stdout 바이트 그대로
+    pub fn exec_raw_blocking(&self, command: &str) -> Result<(i32, Vec<u8>)> {
+        let mut guard = self.session.lock().map_err(|_| anyhow!("SSH session lock poisoned"))?;
+
+        let session = match guard.take() {
+            Some(session) => session,
+            None => self.connect()?,
+        };
+
+        // 실패한 세션은 버리고 다음 호출에서 새로 연결
+        let result = run_command(&session, command);
+        if result.is_ok() {
+            *guard = Some(session);
+        }
+        result
+    }
+
+    pub async fn exec(self: &Arc<Self>, command: &str) -> Result<String> {
+        let session = self.clone();
+        let command = command.to_string();
+        tokio::task::spawn_blocking(move || session.exec_blocking(&command))
+            .await
+            .map_err(|e| anyhow!("Task join error: {}", e))?
+    }
+
+    pub async fn exec_status(self: &Arc<Self>, command: &str) -> Result<(i32, String)> {
+        let session = self.clone();
+        let command = command.to_string();
+        tokio::task::spawn_blocking(move || session.exec_status_blocking(&command))
+            .await
+            .map_err(|e| anyhow!("Task join error: {}", e))?
+    }
+
+    pub async fn exec_raw(self: &Arc<Self>, command: &str) -> Result<(i32, Vec<u8>)> {
+        let session = self.clone();
+        let command = command.to_string();
+        tokio::task::spawn_blocking(move || session.exec_raw_blocking(&command))
+            .await
+            .map_err(|e| anyhow!("Task join error: {}", e))?
+    }
+}
+
+fn run_command(session: &Session, command: &str) -> Result<(i32, Vec<u8>)> {
+    let mut channel = session.channel_session().context("Failed to open SSH channel")?;
+    channel.exec(command).with_context(|| format!("Failed to execute '{}'", command))?;
+
+    let mut output = Vec::new();
+    channel.read_to_end(&mut output).context("Failed to read command output")?;
+    channel.wait_close().context("Failed to close SSH channel")?;
+    let status = channel.exit_status().context("Failed to read exit status")?;
+
+    Ok((status, output))
+}
+
+// 원격 호스트의 /proc 와 df 출력을 읽어 ServerMetrics 를 만든다
+pub struct SshSource {
+    session: Arc<SshSession>,
+    previous: Mutex<Option<RemoteSample>>,
+    filesystems: FilesystemConfig,
+    network: NetworkConfig,
+    disk_io: DiskIoConfig,
+    process_limit: usize,
+    containers: ContainerCollector,
+}
+
+// 다음 수집 때 변화량 계산에 쓰는 이전 카운터
+struct RemoteSample {
+    stat: ProcStat,
+    net: Vec<NetDevStats>,
+    disks: Vec<DiskStats>,
+    uptime: f64,
+}
+
+impl SshSource {
+    pub fn new(target: SshTarget) -> Self {
+        Self {
+            session: Arc::new(SshSession::new(target)),
+            previous: Mutex::new(None),
+            filesystems: FilesystemConfig::default(),
+            network: NetworkConfig::default(),
+            disk_io: DiskIoConfig::default(),
+            process_limit: DEFAULT_PROCESS_SNAPSHOT_LIMIT,
+            containers: ContainerCollector::new(),
+        }
+    }
+
+    pub fn with_filesystem_config(mut self, config: FilesystemConfig) -> Self {
+        self.filesystems = config;
+        self
+    }
+
+    pub fn with_network_config(mut self, config: NetworkConfig) -> Self {
+        self.network = config;
+        self
+    }
+
+    pub fn with_disk_io_config(mut self, config: DiskIoConfig) -> Self {
+        self.disk_io = config;
+        self
+    }
+
+    // 주기적 스냅샷에 담을 상위 프로세스 수
+    pub fn with_process_limit(mut self, limit: usize) -> Self {
+        self.process_limit = limit;
+        self
+    }
+
+    pub fn session(&self) -> &Arc<SshSession> {
+        &self.session
+    }
+
+    pub fn collection_command(&self) -> String {
+        // CPU 상위와 메모리 상위를 함께 받아 합친다
+        let ps = format!(
+            "ps -eo pid,pcpu,rss,comm --sort=-pcpu | head -n {}; ps -eo pid,pcpu,rss,comm --no-headers --sort=-rss | head -n {}",
+            self.process_limit + 1,
+            self.process_limit
+        );
+        [
+            "cat /proc/stat",
+            "cat /proc/meminfo",
+            "cat /proc/net/dev",
+            "df -P -k -T",
+            ps.as_str(),
+            "cat /proc/loadavg",
+            "df -P -i",
+            "cat /proc/uptime",
+            "cat /proc/diskstats",
+            "uname -r; grep -hs '^PRETTY_NAME=' /etc/os-release /usr/lib/os-release | head -n1",
+        ]
+        .join(&format!("; echo {}; ", SECTION_MARKER))
+    }
+
+    pub fn parse_output(&self, output: &str) -> Result<ServerMetrics> {
+        let sections: Vec<&str> = output.split(SECTION_MARKER).map(|s| s.trim_start_matches('\n')).collect();
+        if sections.len() != 10 {
+            return Err(anyhow!("Unexpected collection output ({} sections)", sections.len()));
+        }
+
+        let stat = parsers::parse_proc_stat(sections[0])?;
+        let meminfo = parsers::parse_meminfo(sections[1])?;
+        let net = parsers::parse_net_dev(sections[2])?;
+        let disks = parsers::parse_df(sections[3])?;
+        let processes = parsers::snapshot_processes(parsers::parse_ps(sections[4])?, self.process_limit);
+        let load_average = parsers::parse_loadavg(sections[5])?;
+        let inodes = parsers::parse_df_inodes(sections[6])?;
+        let uptime = parsers::parse_uptime(sections[7])?;
+        let disk_stats = parsers::parse_diskstats(sections[8])?;
+        let (kernel, os) = parsers::parse_os_info(sections[9]);
+        let filesystems = parsers::build_filesystems(&disks, &inodes, &self.filesystems);
+
+        let mut previous = self.previous.lock().map_err(|_| anyhow!("Collector state lock poisoned"))?;
+        // 첫 샘플이나 재부팅 직후에는 CPU 는 부팅 이후 누적값 기준, 네트워크/디스크 I/O 는 비워 둔다.
+        // 경과 시간은 SSH 왕복 지연에 영향받지 않도록 원격 호스트의 uptime 으로 잰다
+        let (prev_stat, interfaces, disk_io) = match previous.as_ref() {
+            Some(prev) if uptime > prev.uptime => {
+                let elapsed = uptime - prev.uptime;
+                (
+                    prev.stat.clone(),
+                    parsers::interface_rates(&prev.net, &net, elapsed, &self.network),
+                    parsers::disk_io_rates(&prev.disks, &disk_stats, elapsed, &self.disk_io),
+                )
+            }
+            _ => (ProcStat::default(), Vec::new(), Vec::new()),
+        };
+        let (network_rx, network_tx) = parsers::total_rates(&interfaces);
+
+        let mut metrics = ServerMetrics::new(
+            stat.total.usage_since(&prev_stat.total),
+            meminfo.usage_percent(),
+            parsers::filesystems_usage_percent(&filesystems),
+            network_rx,
+            network_tx,
+            processes,
+        );
+        metrics.cpu_per_core = stat.per_core_usage_since(&prev_stat);
+        metrics.cpu_breakdown = Some(stat.total.breakdown_since(&prev_stat.total));
+        metrics.load_average = Some(load_average);
+        metrics.filesystems = filesystems;
+        metrics.network_interfaces = interfaces;
+        metrics.disk_io = disk_io;
+        metrics.memory = Some(meminfo.metrics());
+        metrics.host = Some(HostInfo::from_uptime(uptime, metrics.timestamp, kernel, os));
+
+        *previous = Some(RemoteSample { stat, net, disks: disk_stats, uptime });
+        Ok(metrics)
+    }
+}
+
+impl SshSource {
+    pub fn process_command() -> String {
+        [
+            "ps -eo pid=,ppid=,user:32=,stat=,nlwp=,etimes=,pcpu=,rss=,args=",
+            "ps -eo pid=,comm=",
+            // 접속 계정이 읽을 수 있는 프로세스만 집계된다
+            "find /proc/[0-9]*/fd -mindepth 1 -maxdepth 1 2>/dev/null | awk -F/ '{c[$3]++} END {for (p in c) print p, c[p]}'",
+        ]
+        .join(&format!("; echo {}; ", SECTION_MARKER))
+    }
+
+    pub fn parse_process_output(output: &str, now: chrono::DateTime<chrono::Utc>) -> Result<Vec<ProcessDetail>> {
+        let sections: Vec<&str> = output.split(SECTION_MARKER).map(|s| s.trim_start_matches('\n')).collect();
+        if sections.len() != 3 {
+            return Err(anyhow!("Unexpected process output ({} sections)", sections.len()));
+        }
+
+        let names = parsers::parse_ps_names(sections[1]);
+        let open_files = parsers::parse_fd_counts(sections[2]);
+        let mut processes = parsers::parse_ps_detail(sections[0], &names, now)?;
+        for process in &mut processes {
+            process.open_files = open_files.get(&process.pid).copied();
+        }
+        Ok(processes)
+    }
+}
+
+impl SshSource {
+    // 유닛 이름은 API 에서 검증되지만 셸에 넣기 전에 한 번 더 확인한다
+    pub fn service_command(units: &[String]) -> Result<String> {
+        if let Some(invalid) = units.iter().find(|u| !is_valid_unit_name(u)) {
+            return Err(anyhow!("Invalid unit name '{}'", invalid));
+        }
+        let units: Vec<String> = units.iter().map(|u| format!("'{}'", u)).collect();
+        Ok(format!(
+            "cat /proc/uptime; echo {}; systemctl show --no-pager \
+             --property=Id,LoadState,ActiveState,SubState,NRestarts,StateChangeTimestampMonotonic -- {}",
+            SECTION_MARKER,
+            units.join(" ")
+        ))
+    }
+
+    pub fn parse_service_output(output: &str, now: chrono::DateTime<chrono::Utc>) -> Result<Vec<ServiceStatus>> {
+        let (uptime, show) = output
+            .split_once(SECTION_MARKER)
+            .ok_or_else(|| anyhow!("Unexpected systemctl output"))?;
+        let uptime = parsers::parse_uptime(uptime)?;
+        parsers::parse_systemctl_show(show.trim_start_matches('\n'), uptime, now)
+    }
+}
+
+impl MetricSource for SshSource {
+    fn method(&self) -> CollectionMethod {
+        CollectionMethod::Ssh
+    }
+
+    fn capabilities(&self) -> SourceCapabilities {
+        SourceCapabilities {
+            polled: true,
+            remote: true,
+            processes: true,
+            process_table: true,
+            services: true,
+            containers: true,
+            inventory: true,
+            packages: true,
+            ports: true,
+        }
+    }
+
+    fn collect(&self) -> BoxFuture<'_, Result<ServerMetrics>> {
+        Box::pin(async move {
+            let output = self.session.exec(&self.collection_command()).await?;
+            self.parse_output(&output)
+        })
+    }
+
+    fn processes(&self) -> BoxFuture<'_, Result<Vec<ProcessDetail>>> {
+        Box::pin(async move {
+            let output = self.session.exec(&Self::process_command()).await?;
+            Self::parse_process_output(&output, chrono::Utc::now())
+        })
+    }
+
+    fn services(&self, units: Vec<String>) -> BoxFuture<'_, Result<Vec<ServiceStatus>>> {
+        Box::pin(async move {
+            if units.is_empty() {
+                return Ok(Vec::new());
+            }
+            let output = self.session.exec(&Self::service_command(&units)?).await?;
+            Self::parse_service_output(&output, chrono::Utc::now())
+        })
+    }
+
+    fn containers(&self) -> BoxFuture<'_, Result<Vec<ContainerMetrics>>> {
+        Box::pin(async move {
+            let output = self.session.exec(ContainerCollector::command()).await?;
+            self.containers.parse_output(&output)
+        })
+    }
+
+    fn facts(&self) -> BoxFuture<'_, Result<HostFacts>> {
+        Box::pin(async move {
+            let output = self.session.exec(inventory::command()).await?;
+            inventory::parse_facts_output(&output)
+        })
+    }
+
+    fn packages(&self) -> BoxFuture<'_, Result<PackageInventory>> {
+        Box::pin(async move {
+            let output = self.session.exec(packages::command()).await?;
+            packages::parse_package_output(&output)
+        })
+    }
+
+    fn listening_ports(&self) -> BoxFuture<'_, Result<Vec<ListeningPort>>> {
+        Box::pin(async move {
+            let output = self.session.exec(ports::command()).await?;
+            ports::parse_port_output(&output)
+        })
+    }
+}
+
@@ -0,0 +1,60 @@
+// src/monitoring/systemd.rs
+use std::collections::HashMap;
+use std::sync::Mutex;
+use chrono::Utc;
+use crate::db::models::{Alert, AlertSeverity};
+use crate::models::service::ServiceStatus;
+
+// 유닛이 failed 상태로 바뀌는 순간에만 알린다. failed 가 계속되는 동안은 다시 알리지 않는다
+#[derive(Default)]
+pub struct ServiceEvaluator {
+    states: Mutex<HashMap<(String, String), String>>,
+}
+
+impl ServiceEvaluator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn evaluate(&self, server_id: &str, services: &[ServiceStatus]) -> Vec<Alert> {
+        let mut states = match self.states.lock() {
+            Ok(states) => states,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        // 목록에서 빠진 유닛은 잊는다
+        states.retain(|(id, unit), _| id != server_id || services.iter().any(|s| &s.unit == unit));
+
+        services
+            .iter()
+            .filter_map(|service| {
+                let key = (server_id.to_string(), service.unit.clone());
+                let previous = states.insert(key, service.active_state.clone());
+                if !service.is_failed() || previous.as_deref() == Some("failed") {
+                    return None;
+                }
+                Some(Alert {
+                    id: 0,
+                    server_id: server_id.to_string(),
+                    alert_type: "service_failed".to_string(),
+                    severity: AlertSeverity::Critical,
+                    message: format!(
+                        "Service {} failed ({}{})",
+                        service.unit,
+                        previous.map_or_else(|| "first check".to_string(), |p| format!("was {}", p)),
+                        service.restarts.map_or_else(String::new, |n| format!(", {} restarts", n))
+                    ),
+                    created_at: Utc::now(),
+                    acknowledged_at: None,
+                    acknowledged_by: None,
+                })
+            })
+            .collect()
+    }
+
+    // 서버 모니터링이 중지되면 상태를 비운다
+    pub fn clear(&self, server_id: &str) {
+        if let Ok(mut states) = self.states.lock() {
+            states.retain(|(id, _), _| id != server_id);
+        }
+    }
+}
@@ -1,5 +1,14 @@
 // src/monitoring/traits.rs
+use futures::future::BoxFuture;
+use serde::Serialize;
+use crate::db::models::CollectionMethod;
+use crate::models::agent::HostFacts;
 use crate::models::metrics::{ServerMetrics, ProcessMetrics};
+use crate::models::container::ContainerMetrics;
+use crate::models::package::PackageInventory;
+use crate::models::port::ListeningPort;
+use crate::models::process::ProcessDetail;
+use crate::models::service::ServiceStatus;
 
 #[cfg_attr(test, mockall::automock)]
 pub trait MonitoringServiceTrait {
@@ -8,6 +17,72 @@ pub trait MonitoringServiceTrait {
     async fn get_server_processes(&self, server_id: &str) -> Option<Vec<ProcessMetrics>>;
 }
 
+// 수집 방식별 지원 기능
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq)]
+pub struct SourceCapabilities {
+    // 서버가 주기적으로 collect 를 호출해야 하는지 (false 면 push 방식)
+    pub polled: bool,
+    // 모니터링 서버가 아닌 원격 호스트의 값인지
+    pub remote: bool,
+    pub processes: bool,
+    // 요청 시점에 전체 프로세스 목록을 가져올 수 있는지
+    pub process_table: bool,
+    // systemd 유닛 상태를 조회할 수 있는지
+    pub services: bool,
+    // Docker/Podman 또는 cgroup v2 로 컨테이너 메트릭을 수집할 수 있는지
+    pub containers: bool,
+    // CPU 모델, 인터페이스 등 호스트 정보를 직접 수집할 수 있는지 (에이전트는 직접 보낸다)
+    pub inventory: bool,
+    // dpkg/rpm 으로 설치된 패키지와 대기 중인 업데이트를 조회할 수 있는지
+    pub packages: bool,
+    // 대기 중인 TCP/UDP 포트와 소유 프로세스를 조회할 수 있는지
+    pub ports: bool,
+}
+
+// 서버 하나의 메트릭을 가져오는 수집원 (로컬 sysinfo, SSH, 에이전트 push 등)
+pub trait MetricSource: Send + Sync {
+    fn method(&self) -> CollectionMethod;
+
+    fn capabilities(&self) -> SourceCapabilities;
+
+    fn collect(&self) -> BoxFuture<'_, anyhow::Result<ServerMetrics>>;
+
+    // 전체 프로세스 목록 (process_table 을 지원하는 수집원만 구현)
+    fn processes(&self) -> BoxFuture<'_, anyhow::Result<Vec<ProcessDetail>>> {
+        Box::pin(async { Err(anyhow::anyhow!("Process listing is not supported by this source")) })
+    }
+
+    // 지정한 systemd 유닛 상태 (services 를 지원하는 수집원만 구현)
+    fn services(&self, _units: Vec<String>) -> BoxFuture<'_, anyhow::Result<Vec<ServiceStatus>>> {
+        Box::pin(async { Err(anyhow::anyhow!("Service status is not supported by this source")) })
+    }
+
+    // 호스트에서 실행 중인 컨테이너 (containers 를 지원하는 수집원만 구현)
+    fn containers(&self) -> BoxFuture<'_, anyhow::Result<Vec<ContainerMetrics>>> {
+        Box::pin(async { Err(anyhow::anyhow!("Container metrics are not supported by this source")) })
+    }
+
+    // 호스트 정보 (inventory 를 지원하는 수집원만 구현)
+    fn facts(&self) -> BoxFuture<'_, anyhow::Result<HostFacts>> {
+        Box::pin(async { Err(anyhow::anyhow!("Host facts are not supported by this source")) })
+    }
+
+    // 설치된 패키지와 업데이트 (packages 를 지원하는 수집원만 구현)
+    fn packages(&self) -> BoxFuture<'_, anyhow::Result<PackageInventory>> {
+        Box::pin(async { Err(anyhow::anyhow!("Package inventory is not supported by this source")) })
+    }
+
+    // 대기 중인 포트 (ports 를 지원하는 수집원만 구현)
+    fn listening_ports(&self) -> BoxFuture<'_, anyhow::Result<Vec<ListeningPort>>> {
+        Box::pin(async { Err(anyhow::anyhow!("Listening ports are not supported by this source")) })
+    }
+
+    // push 방식 수집원만 구현 - 받아들였으면 true
+    fn push(&self, _metrics: ServerMetrics) -> BoxFuture<'_, bool> {
+        Box::pin(async { false })
+    }
+}
+
 // MonitoringService에 대한 trait
 impl MonitoringServiceTrait for super::MonitoringService {
     async fn get_current_metrics(&self) -> Option<ServerMetrics> {
@@ -21,4 +96,4 @@ impl MonitoringServiceTrait for super::MonitoringService {
     async fn get_server_processes(&self, server_id: &str) -> Option<Vec<ProcessMetrics>> {
         self.get_server_processes(server_id).await
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,173 @@
+// src/monitoring/watchlist.rs
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+use chrono::Utc;
+use regex::Regex;
+use tracing::warn;
+use crate::db::models::{Alert, AlertSeverity, ProcessWatch};
+use crate::models::metrics::ProcessMetrics;
+use crate::models::process::ProcessDetail;
+
+// 감시 대상 프로세스를 수집 주기마다 프로세스 목록과 비교한다.
+// 부족/상한 초과는 상태가 바뀔 때 한 번만 알리고, 재시작(pid 변경)은 발생할 때마다 알린다.
+#[derive(Default)]
+pub struct WatchlistEvaluator {
+    state: Mutex<HashMap<(String, String), WatchState>>,
+    // (서버, 감시 대상) 별 컴파일한 패턴. 패턴이 바뀔 때만 다시 컴파일하고, 잘못된 패턴은 None 으로 남겨 한 번만 경고한다
+    patterns: Mutex<HashMap<(String, String), CompiledPattern>>,
+}
+
+// (패턴 원문, 컴파일 결과)
+type CompiledPattern = (String, Option<Regex>);
+
+#[derive(Default)]
+struct WatchState {
+    pids: BTreeSet<u32>,
+    missing: bool,
+    over_cpu: bool,
+    over_memory: bool,
+}
+
+impl WatchlistEvaluator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn evaluate(
+        &self,
+        server_id: &str,
+        watches: &[ProcessWatch],
+        processes: &[ProcessDetail],
+    ) -> Vec<Alert> {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut patterns = match self.patterns.lock() {
+            Ok(patterns) => patterns,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        // 삭제된 감시 대상의 상태는 버린다
+        let deleted = |(id, watch_id): &(String, String)| id == server_id && !watches.iter().any(|w| &w.id == watch_id);
+        state.retain(|key, _| !deleted(key));
+        patterns.retain(|key, _| !deleted(key));
+
+        let mut alerts = Vec::new();
+        for watch in watches {
+            let key = (server_id.to_string(), watch.id.clone());
+            let cached = patterns.get(&key).filter(|(source, _)| source == &watch.pattern);
+            let pattern = match cached {
+                Some((_, compiled)) => compiled.clone(),
+                None => {
+                    let compiled = Regex::new(&watch.pattern)
+                        .map_err(|e| warn!("Invalid pattern for process watch {}: {}", watch.id, e))
+                        .ok();
+                    patterns.insert(key.clone(), (watch.pattern.clone(), compiled.clone()));
+                    compiled
+                }
+            };
+            let pattern = match pattern {
+                Some(pattern) => pattern,
+                None => continue,
+            };
+            let matched: Vec<ProcessMetrics> = processes
+                .iter()
+                .filter(|p| pattern.is_match(if watch.match_command { &p.command } else { &p.name }))
+                .map(ProcessMetrics::from)
+                .collect();
+            let pids: BTreeSet<u32> = matched.iter().map(|p| p.pid).collect();
+
+            let first = !state.contains_key(&key);
+            let entry = state.entry(key).or_default();
+
+            let missing = (pids.len() as i64) < watch.min_instances as i64;
+            if missing && !entry.missing {
+                alerts.push(alert(
+                    server_id,
+                    "process_missing",
+                    AlertSeverity::Critical,
+                    format!(
+                        "Process '{}': {} of {} expected instances running",
+                        watch.name, pids.len(), watch.min_instances
+                    ),
+                ));
+            }
+            entry.missing = missing;
+
+            // 이전 pid 가 사라지고 새 pid 가 생겼으면 재시작으로 본다
+            if !first && !entry.pids.is_empty() && !pids.is_empty() {
+                let gone: Vec<u32> = entry.pids.difference(&pids).copied().collect();
+                let started: Vec<u32> = pids.difference(&entry.pids).copied().collect();
+                if !gone.is_empty() && !started.is_empty() {
+                    alerts.push(alert(
+                        server_id,
+                        "process_restart",
+                        AlertSeverity::Warning,
+                        format!(
+                            "Process '{}' restarted (pid {} -> {})",
+                            watch.name, join_pids(&gone), join_pids(&started)
+                        ),
+                    ));
+                }
+            }
+            entry.pids = pids;
+
+            let over_cpu = watch.max_cpu.and_then(|limit| {
+                matched.iter().find(|p| p.exceeds_cpu(limit as f32)).map(|p| (p, limit))
+            });
+            if let (Some((process, limit)), false) = (over_cpu, entry.over_cpu) {
+                alerts.push(alert(
+                    server_id,
+                    "process_cpu",
+                    AlertSeverity::Warning,
+                    format!(
+                        "Process '{}' (pid {}) CPU usage is {:.1}% (limit {:.1}%)",
+                        watch.name, process.pid, process.cpu_usage, limit
+                    ),
+                ));
+            }
+            entry.over_cpu = over_cpu.is_some();
+
+            let over_memory = watch.max_memory.and_then(|limit| {
+                matched.iter().find(|p| p.exceeds_memory(limit.max(0) as u64)).map(|p| (p, limit))
+            });
+            if let (Some((process, limit)), false) = (over_memory, entry.over_memory) {
+                alerts.push(alert(
+                    server_id,
+                    "process_memory",
+                    AlertSeverity::Warning,
+                    format!(
+                        "Process '{}' (pid {}) RSS is {} bytes (limit {} bytes)",
+                        watch.name, process.pid, process.memory_usage, limit
+                    ),
+                ));
+            }
+            entry.over_memory = over_memory.is_some();
+        }
+        alerts
+    }
+
+    // 서버 모니터링이 중지되면 상태를 비운다
+    pub fn clear(&self, server_id: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state.retain(|(id, _), _| id != server_id);
+        }
+    }
+}
+
+fn alert(server_id: &str, alert_type: &str, severity: AlertSeverity, message: String) -> Alert {
+    Alert {
+        id: 0,
+        server_id: server_id.to_string(),
+        alert_type: alert_type.to_string(),
+        severity,
+        message,
+        created_at: Utc::now(),
+        acknowledged_at: None,
+        acknowledged_by: None,
+    }
+}
+
+fn join_pids(pids: &[u32]) -> String {
+    pids.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(",")
+}
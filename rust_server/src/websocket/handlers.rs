@@ -82,7 +82,15 @@ impl WebSocketConnection {
                             "cpuUsage": metrics.cpu_usage,
                             "memoryUsage": metrics.memory_usage,
                             "diskUsage": metrics.disk_usage,
-                            "networkUsage": (metrics.network_rx as f64 + metrics.network_tx as f64) / 2.0,
+                            "networkUsage": metrics.total_network_usage(),
+                            "networkRx": metrics.network_rx,
+                            "networkTx": metrics.network_tx,
+                            "networkInterfaces": metrics.network_interfaces,
+                            "diskIo": metrics.disk_io,
+                            "memoryDetail": metrics.memory,
+                            "cpuPerCore": metrics.cpu_per_core,
+                            "loadAverage": metrics.load_average,
+                            "cpuBreakdown": metrics.cpu_breakdown,
                             "processCount": metrics.processes.len(),
                             "processes": metrics.processes,
                             "timestamp": chrono::Utc::now().to_rfc3339()
@@ -0,0 +1,72 @@
+// tests/agent/buffer.rs
+use rust_server::agent::buffer::MetricsBuffer;
+use rust_server::agent::Backoff;
+use rust_server::models::metrics::ServerMetrics;
+use std::time::{Duration, Instant};
+
+fn sample(cpu: f32) -> ServerMetrics {
+    ServerMetrics::new(cpu, 10.0, 20.0, 0, 0, Vec::new())
+}
+
+#[test]
+fn test_buffer_drops_oldest_when_full() {
+    let mut buffer = MetricsBuffer::new(2);
+
+    assert_eq!(buffer.push(sample(1.0)), 0);
+    assert_eq!(buffer.push(sample(2.0)), 0);
+    assert_eq!(buffer.push(sample(3.0)), 1);
+
+    let batch = buffer.peek_batch(10);
+    assert_eq!(batch.len(), 2);
+    assert_eq!(batch[0].cpu_usage, 2.0);
+    assert_eq!(batch[1].cpu_usage, 3.0);
+}
+
+#[test]
+fn test_buffer_batches() {
+    let mut buffer = MetricsBuffer::new(10);
+    for i in 0..5 {
+        buffer.push(sample(i as f32));
+    }
+
+    assert_eq!(buffer.peek_batch(3).len(), 3);
+    buffer.remove_front(3);
+    assert_eq!(buffer.len(), 2);
+    assert_eq!(buffer.peek_batch(3)[0].cpu_usage, 3.0);
+
+    buffer.remove_front(10);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_buffer_spool_roundtrip() {
+    let path = std::env::temp_dir().join(format!("flick-agent-{}.jsonl", uuid::Uuid::new_v4()));
+
+    let mut buffer = MetricsBuffer::with_spool(10, path.clone()).unwrap();
+    buffer.push(sample(1.0));
+    buffer.push(sample(2.0));
+    buffer.persist().unwrap();
+
+    let restored = MetricsBuffer::with_spool(10, path.clone()).unwrap();
+    assert_eq!(restored.len(), 2);
+    assert_eq!(restored.peek_batch(1)[0].cpu_usage, 1.0);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_backoff_grows_and_resets() {
+    let mut backoff = Backoff::new(Duration::from_secs(5));
+    let now = Instant::now();
+
+    assert!(backoff.ready(now));
+    assert_eq!(backoff.fail(now), Duration::from_secs(1));
+    assert!(!backoff.ready(now));
+    assert_eq!(backoff.fail(now), Duration::from_secs(2));
+    assert_eq!(backoff.fail(now), Duration::from_secs(4));
+    // 최대값 제한
+    assert_eq!(backoff.fail(now), Duration::from_secs(5));
+
+    backoff.reset();
+    assert!(backoff.ready(now));
+}
@@ -0,0 +1,109 @@
+// tests/agent/config.rs
+use rust_server::agent::config::AgentConfig;
+use rust_server::agent::parse_args;
+use rust_server::models::metrics::MAX_BATCH_SIZE;
+
+fn write_config(content: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("flick-agent-{}.toml", uuid::Uuid::new_v4()));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn test_load_config_with_defaults() {
+    let path = write_config(r#"
+server_url = "https://monitor.example.com/"
+server_id = "server-1"
+token = "secret"
+"#);
+
+    let config = AgentConfig::load(&path).unwrap();
+    assert_eq!(config.interval_seconds, 10);
+    assert!(config.buffer_path.is_none());
+    assert_eq!(
+        config.batch_url(),
+        "https://monitor.example.com/api/v1/servers/server-1/metrics/batch"
+    );
+    assert_eq!(
+        config.facts_url(),
+        "https://monitor.example.com/api/v1/servers/server-1/inventory"
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_config_requires_https() {
+    let path = write_config(r#"
+server_url = "http://monitor.example.com"
+server_id = "server-1"
+token = "secret"
+"#);
+
+    assert!(AgentConfig::load(&path).is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_config_requires_credentials() {
+    let path = write_config(r#"
+server_url = "https://monitor.example.com"
+server_id = "server-1"
+token = ""
+"#);
+
+    assert!(AgentConfig::load(&path).is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_config_rejects_oversized_batch() {
+    let config = |batch_size: usize| format!(r#"
+server_url = "https://monitor.example.com"
+server_id = "server-1"
+token = "secret"
+batch_size = {}
+"#, batch_size);
+
+    let path = write_config(&config(MAX_BATCH_SIZE));
+    assert!(AgentConfig::load(&path).is_ok());
+    std::fs::remove_file(path).unwrap();
+
+    // 서버가 400 으로 거절해 버려질 크기
+    let path = write_config(&config(MAX_BATCH_SIZE + 1));
+    assert!(AgentConfig::load(&path).is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_parse_args() {
+    let args: Vec<String> = ["flick-agent", "--config", "/tmp/agent.toml", "--dry-run"]
+        .iter().map(|s| s.to_string()).collect();
+    let parsed = parse_args(&args).unwrap();
+    assert_eq!(parsed.config_path, std::path::PathBuf::from("/tmp/agent.toml"));
+    assert!(parsed.dry_run);
+    assert!(parsed.enroll_token.is_none());
+
+    let args = vec!["flick-agent".to_string(), "--bogus".to_string()];
+    assert!(parse_args(&args).is_err());
+}
+
+#[test]
+fn test_parse_enroll_args() {
+    let args: Vec<String> = ["flick-agent", "--enroll", "id.secret", "--server-url", "https://monitor.example.com", "--name", "web-01"]
+        .iter().map(|s| s.to_string()).collect();
+    let parsed = parse_args(&args).unwrap();
+    assert_eq!(parsed.enroll_token.as_deref(), Some("id.secret"));
+    assert_eq!(parsed.server_url.as_deref(), Some("https://monitor.example.com"));
+    assert_eq!(parsed.name.as_deref(), Some("web-01"));
+    assert_eq!(parsed.config_path, std::path::PathBuf::from("/etc/flick/agent.toml"));
+
+    // 서버 주소 없이 등록할 수 없음
+    let args: Vec<String> = ["flick-agent", "--enroll", "id.secret"]
+        .iter().map(|s| s.to_string()).collect();
+    assert!(parse_args(&args).is_err());
+
+    let args: Vec<String> = ["flick-agent", "--enroll"]
+        .iter().map(|s| s.to_string()).collect();
+    assert!(parse_args(&args).is_err());
+}
@@ -0,0 +1,67 @@
+// tests/agent/enroll.rs
+use rust_server::agent::config::AgentConfig;
+use rust_server::agent::enroll::{detect_host_facts, write_config};
+use rust_server::db::models::ServerType;
+use rust_server::models::agent::{EnrollResponse, HostFacts};
+
+#[test]
+fn test_write_config_round_trip() {
+    let dir = std::env::temp_dir().join(format!("flick-agent-{}", uuid::Uuid::new_v4()));
+    let path = dir.join("agent.toml");
+
+    let config = AgentConfig::new("https://monitor.example.com/", "server-1", "id.secret");
+    write_config(&config, &path).unwrap();
+
+    let loaded = AgentConfig::load(&path).unwrap();
+    assert_eq!(loaded.server_url, "https://monitor.example.com");
+    assert_eq!(loaded.server_id, "server-1");
+    assert_eq!(loaded.token, "id.secret");
+    assert_eq!(loaded.interval_seconds, config.interval_seconds);
+    assert!(loaded.buffer_path.is_none());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_host_facts_server_type() {
+    let facts = |os: &str| HostFacts {
+        hostname: "host".to_string(),
+        os_name: Some(os.to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(facts("Ubuntu").server_type(), ServerType::Linux);
+    assert_eq!(facts("Darwin").server_type(), ServerType::MacOS);
+    assert_eq!(facts("Windows").server_type(), ServerType::Windows);
+    assert_eq!(HostFacts::default().server_type(), ServerType::Linux);
+}
+
+#[test]
+fn test_detect_host_facts() {
+    let facts = detect_host_facts("https://127.0.0.1:8080");
+    assert!(!facts.hostname.is_empty());
+    assert!(facts.cpu_cores.unwrap_or(0) > 0);
+    assert_eq!(facts.ip_address.as_deref(), Some("127.0.0.1"));
+}
+
+#[test]
+fn test_enroll_response_deserialization() {
+    let response: EnrollResponse = serde_json::from_value(serde_json::json!({
+        "server_id": "server-1",
+        "config": {
+            "server_url": "https://monitor.example.com",
+            "server_id": "server-1",
+            "token": "id.secret"
+        }
+    })).unwrap();
+
+    assert_eq!(response.config.server_id, "server-1");
+    assert_eq!(response.config.batch_size, 30);
+}
@@ -0,0 +1,3 @@
+pub mod buffer;
+pub mod config;
+pub mod enroll;
@@ -0,0 +1,76 @@
+// tests/api/checks.rs
+use chrono::Utc;
+use rust_server::api::checks::{CheckState, CheckStatus, CreateCheckRequest};
+use rust_server::db::models::{LatestCheckResult, ServerCheck};
+use rust_server::models::check::CheckConfig;
+
+fn request(value: serde_json::Value) -> CreateCheckRequest {
+    serde_json::from_value(value).unwrap()
+}
+
+fn server_check() -> ServerCheck {
+    ServerCheck {
+        id: "check-1".to_string(),
+        server_id: "server-1".to_string(),
+        name: "ssh".to_string(),
+        check_type: "tcp".to_string(),
+        config: serde_json::json!({"type": "tcp", "host": "10.0.0.5", "port": 22}),
+        interval_seconds: 60,
+        failure_threshold: 3,
+        enabled: true,
+        created_by: None,
+        created_at: Utc::now(),
+    }
+}
+
+#[test]
+fn test_request_defaults_and_validation() {
+    let tcp = request(serde_json::json!({
+        "name": "ssh",
+        "config": {"type": "tcp", "port": 22, "banner": "SSH-2.0"}
+    }));
+    assert_eq!(tcp.interval_seconds, 60);
+    assert_eq!(tcp.failure_threshold, 3);
+    assert!(tcp.enabled);
+    assert!(tcp.validate().is_ok());
+
+    // 호스트가 없으면 서버 호스트를 쓴다
+    match tcp.config.with_default_host("web-01.internal") {
+        CheckConfig::Tcp(config) => assert_eq!(config.host, "web-01.internal"),
+        other => panic!("unexpected config {:?}", other),
+    }
+
+    let dns = request(serde_json::json!({
+        "name": "resolver",
+        "config": {"type": "dns", "name": "example.com", "record_type": "AAAA", "resolver": "1.1.1.1"},
+        "interval_seconds": 5
+    }));
+    assert!(dns.validate().is_err());
+
+    let invalid = request(serde_json::json!({
+        "name": " ",
+        "config": {"type": "http", "url": "https://example.com"}
+    }));
+    assert!(invalid.validate().is_err());
+}
+
+#[test]
+fn test_check_status_from_latest_result() {
+    assert_eq!(CheckStatus::new(server_check(), None).state, CheckState::Pending);
+
+    let result = LatestCheckResult {
+        check_id: "check-1".to_string(),
+        timestamp: Utc::now(),
+        success: false,
+        response_time_ms: None,
+        message: Some("Connection to 10.0.0.5:22 failed: Connection refused".to_string()),
+    };
+    let status = CheckStatus::new(server_check(), Some(&result));
+    assert_eq!(status.state, CheckState::Down);
+    assert_eq!(status.last_checked_at, Some(result.timestamp));
+
+    // 검사 설정과 상태가 한 객체로 직렬화된다
+    let json = serde_json::to_value(&status).unwrap();
+    assert_eq!(json["name"], "ssh");
+    assert_eq!(json["state"], "down");
+}
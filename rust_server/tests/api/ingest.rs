@@ -0,0 +1,76 @@
+// tests/api/ingest.rs
+use chrono::{Duration, Utc};
+use rust_server::api::ingest::*;
+use rust_server::models::metrics::{IngestResult, MetricsBatch, ServerMetrics};
+
+fn sample(offset_seconds: i64) -> ServerMetrics {
+    let mut metrics = ServerMetrics::new(10.0, 20.0, 30.0, 100, 200, Vec::new());
+    metrics.timestamp = Utc::now() + Duration::seconds(offset_seconds);
+    metrics
+}
+
+#[test]
+fn test_validate_sample_accepts_new_sample() {
+    let now = Utc::now();
+    assert!(validate_sample(&sample(0), None, now).is_ok());
+    assert!(validate_sample(&sample(0), Some(now - Duration::seconds(10)), now).is_ok());
+}
+
+#[test]
+fn test_validate_sample_rejects_future() {
+    let now = Utc::now();
+    let future = sample(MAX_FUTURE_SKEW_SECONDS + 60);
+    assert!(validate_sample(&future, None, now).is_err());
+
+    // 약간의 시계 오차는 허용
+    assert!(validate_sample(&sample(30), None, now).is_ok());
+}
+
+#[test]
+fn test_validate_sample_rejects_out_of_order() {
+    let now = Utc::now();
+    let metrics = sample(-60);
+    assert!(validate_sample(&metrics, Some(metrics.timestamp), now).is_err());
+    assert!(validate_sample(&metrics, Some(now), now).is_err());
+}
+
+#[test]
+fn test_validate_sample_rejects_invalid_percentages() {
+    let now = Utc::now();
+
+    let mut metrics = sample(0);
+    metrics.cpu_usage = 120.0;
+    assert!(validate_sample(&metrics, None, now).is_err());
+
+    let mut metrics = sample(0);
+    metrics.memory_usage = -1.0;
+    assert!(validate_sample(&metrics, None, now).is_err());
+
+    let mut metrics = sample(0);
+    metrics.disk_usage = f32::NAN;
+    assert!(validate_sample(&metrics, None, now).is_err());
+
+    let mut metrics = sample(0);
+    metrics.cpu_per_core = vec![10.0, 101.0];
+    assert!(validate_sample(&metrics, None, now).is_err());
+}
+
+#[test]
+fn test_batch_and_result_serialization() {
+    let batch: MetricsBatch = serde_json::from_value(serde_json::json!({
+        "metrics": [{
+            "cpu_usage": 1.0,
+            "memory_usage": 2.0,
+            "disk_usage": 3.0,
+            "network_rx": 4,
+            "network_tx": 5,
+            "timestamp": "2024-12-01T00:00:00Z"
+        }]
+    })).unwrap();
+    assert_eq!(batch.metrics.len(), 1);
+    assert!(batch.metrics[0].processes.is_empty());
+
+    let result: IngestResult = serde_json::from_str(r#"{"accepted":1,"rejected":0}"#).unwrap();
+    assert_eq!(result.accepted, 1);
+    assert!(result.errors.is_empty());
+}
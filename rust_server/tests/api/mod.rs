@@ -1,5 +1,9 @@
 pub mod alerts_handlers;
 pub mod alerts;
+pub mod checks;
 pub mod api_handlers;
 pub mod logs;
-pub mod servers;
\ No newline at end of file
+pub mod ingest;
+pub mod processes;
+pub mod servers;
+pub mod services;
@@ -0,0 +1,138 @@
+// tests/api/processes.rs
+use chrono::{Duration, Utc};
+use rust_server::api::processes::CreateProcessWatchRequest;
+use rust_server::models::process::{ProcessDetail, ProcessHistoryQuery, ProcessQuery, TopProcessQuery};
+
+fn process(pid: u32, name: &str, user: &str, cpu_usage: f32, memory_usage: u64) -> ProcessDetail {
+    ProcessDetail {
+        pid,
+        ppid: Some(1),
+        name: name.to_string(),
+        user: Some(user.to_string()),
+        command: format!("/usr/bin/{} --serve", name),
+        state: "sleeping".to_string(),
+        threads: Some(pid % 7 + 1),
+        start_time: Some(Utc::now() - Duration::seconds(pid as i64)),
+        cpu_usage,
+        memory_usage,
+        open_files: None,
+    }
+}
+
+fn processes() -> Vec<ProcessDetail> {
+    vec![
+        process(10, "nginx", "www-data", 5.0, 300),
+        process(11, "nginx", "www-data", 7.5, 200),
+        process(20, "postgres", "postgres", 40.0, 1000),
+        process(30, "sshd", "root", 0.0, 100),
+        process(40, "bash", "root", 0.5, 50),
+    ]
+}
+
+fn query(params: &str) -> ProcessQuery {
+    serde_qs::from_str(params).unwrap()
+}
+
+#[test]
+fn test_default_query_sorts_by_cpu_desc() {
+    let page = ProcessQuery::default().apply(processes()).unwrap();
+
+    assert_eq!(page.total, 5);
+    assert_eq!(page.page, 1);
+    let pids: Vec<u32> = page.processes.iter().map(|p| p.pid).collect();
+    assert_eq!(pids, vec![20, 11, 10, 40, 30]);
+}
+
+#[test]
+fn test_sort_by_any_column() {
+    let page = query("sort=name&order=asc").apply(processes()).unwrap();
+    let names: Vec<&str> = page.processes.iter().map(|p| p.name.as_str()).collect();
+    // 같은 이름은 pid 순
+    assert_eq!(names, vec!["bash", "nginx", "nginx", "postgres", "sshd"]);
+    assert_eq!(page.processes[1].pid, 10);
+
+    let page = query("sort=memory&order=asc").apply(processes()).unwrap();
+    assert_eq!(page.processes[0].pid, 40);
+
+    let page = query("sort=start_time&order=desc").apply(processes()).unwrap();
+    assert_eq!(page.processes[0].pid, 10);
+
+    assert!(query("sort=color").apply(processes()).is_err());
+    assert!(query("order=up").apply(processes()).is_err());
+}
+
+#[test]
+fn test_filter_by_name_and_user_regex() {
+    let page = query("name=^nginx$").apply(processes()).unwrap();
+    assert_eq!(page.total, 2);
+
+    // 명령줄에도 적용
+    let page = query("name=--serve").apply(processes()).unwrap();
+    assert_eq!(page.total, 5);
+
+    let page = query("user=^(root|postgres)$&sort=pid&order=asc").apply(processes()).unwrap();
+    let pids: Vec<u32> = page.processes.iter().map(|p| p.pid).collect();
+    assert_eq!(pids, vec![20, 30, 40]);
+
+    assert!(query("name=(").apply(processes()).is_err());
+}
+
+#[test]
+fn test_pagination() {
+    let page = query("sort=pid&order=asc&page=2&per_page=2").apply(processes()).unwrap();
+    assert_eq!(page.total, 5);
+    assert_eq!(page.per_page, 2);
+    let pids: Vec<u32> = page.processes.iter().map(|p| p.pid).collect();
+    assert_eq!(pids, vec![20, 30]);
+
+    let page = query("page=4&per_page=2").apply(processes()).unwrap();
+    assert!(page.processes.is_empty());
+    // 오프셋이 넘치는 page 도 빈 페이지
+    let page = query(&format!("page={}&per_page=500", usize::MAX)).apply(processes()).unwrap();
+    assert!(page.processes.is_empty());
+
+    assert!(query("page=0").apply(processes()).is_err());
+    assert!(query("per_page=0").apply(processes()).is_err());
+    assert!(query("per_page=100000").apply(processes()).is_err());
+}
+
+const RANGE: &str = "from=2024-12-09T00:00:00Z&to=2024-12-09T06:00:00Z";
+
+#[test]
+fn test_top_process_query_ranking() {
+    let top = |params: &str| serde_qs::from_str::<TopProcessQuery>(&format!("{}&{}", RANGE, params)).unwrap();
+
+    assert_eq!(top("").ranking().unwrap(), ("cpu_avg", 10));
+    assert_eq!(top("by=memory&aggregate=max&limit=5").ranking().unwrap(), ("memory_max", 5));
+
+    assert!(top("by=disk").ranking().is_err());
+    assert!(top("aggregate=sum").ranking().is_err());
+    assert!(top("limit=0").ranking().is_err());
+    assert!(top("limit=101").ranking().is_err());
+}
+
+#[test]
+fn test_process_queries_reject_inverted_range() {
+    let inverted = "from=2024-12-09T06:00:00Z&to=2024-12-09T00:00:00Z";
+
+    assert!(serde_qs::from_str::<TopProcessQuery>(inverted).unwrap().ranking().is_err());
+    assert!(serde_qs::from_str::<ProcessHistoryQuery>(inverted).unwrap().validate().is_err());
+    assert!(serde_qs::from_str::<ProcessHistoryQuery>(&format!("{}&name=nginx", RANGE)).unwrap().validate().is_ok());
+}
+
+#[test]
+fn test_process_watch_request_validation() {
+    let request = |body: serde_json::Value| serde_json::from_value::<CreateProcessWatchRequest>(body).unwrap();
+
+    let valid = request(serde_json::json!({ "name": "nginx", "pattern": "^nginx$" }));
+    assert_eq!(valid.min_instances, 1);
+    assert!(valid.validate().is_ok());
+    assert!(request(serde_json::json!({ "name": "worker", "pattern": "celery", "min_instances": 0, "max_cpu": 90.0 }))
+        .validate()
+        .is_ok());
+
+    assert!(request(serde_json::json!({ "name": "nginx", "pattern": "(" })).validate().is_err());
+    assert!(request(serde_json::json!({ "name": " ", "pattern": "nginx" })).validate().is_err());
+    assert!(request(serde_json::json!({ "name": "nginx", "pattern": "nginx", "min_instances": 0 })).validate().is_err());
+    assert!(request(serde_json::json!({ "name": "nginx", "pattern": "nginx", "max_memory": 0 })).validate().is_err());
+}
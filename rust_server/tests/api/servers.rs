@@ -1,13 +1,14 @@
 // tests/api/servers.rs
 use actix_web::{test, web, App};
-use chrono::Utc;
+use chrono::{TimeZone, Utc};
 use rust_server::{
     api::servers::*,
     api::response::ApiResponse,
     db::models::{*, UserRole, ServerCategory},
     config::ServerConfig,
     auth::types::AuthenticatedUser,
-    models::metrics::ServerMetrics,
+    models::logs::{LogEntry, LogLevel},
+    models::metrics::{HostInfo, ServerMetrics},
 };
 use mockall::predicate::*;
 use mockall::mock;
@@ -23,6 +24,7 @@ mock! {
         async fn delete_server(&self, id: &str) -> anyhow::Result<()>;
         async fn get_server_metrics(&self, server_id: &str, from: chrono::DateTime<Utc>, to: chrono::DateTime<Utc>) -> anyhow::Result<Vec<MetricsSnapshot>>;
         async fn get_server_metrics_history(&self, server_id: &str, from: chrono::DateTime<Utc>, to: chrono::DateTime<Utc>) -> anyhow::Result<Vec<ResourceHistory>>;
+        async fn get_recent_server_logs(&self, server_id: &str, limit: i64) -> anyhow::Result<Vec<rust_server::models::logs::LogEntry>>;
     }
 }
 
@@ -61,6 +63,7 @@ async fn test_create_server_success() {
         password: "testpass".to_string(),
         server_type: ServerType::Linux,
         category: ServerCategory::Virtual, 
+        collection_method: None,
     };
 
     let app = test::init_service(
@@ -135,6 +138,60 @@ async fn test_get_servers_as_admin() {
     assert_eq!(body.data.unwrap().len(), 2);
 }
 
+fn test_host() -> HostInfo {
+    HostInfo {
+        boot_time: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        uptime_seconds: 90_000,
+        kernel: Some("6.1.0-18-amd64".to_string()),
+        os: Some("Debian GNU/Linux 12 (bookworm)".to_string()),
+    }
+}
+
+#[actix_rt::test]
+async fn test_status_log_limit_clamp() {
+    let query = |logs| ServerStatusQuery { logs };
+    assert_eq!(query(None).log_limit(), DEFAULT_STATUS_LOG_LIMIT);
+    assert_eq!(DEFAULT_STATUS_LOG_LIMIT, 10);
+    assert_eq!(query(Some(25)).log_limit(), 25);
+    assert_eq!(query(Some(500)).log_limit(), MAX_STATUS_LOG_LIMIT);
+    assert_eq!(MAX_STATUS_LOG_LIMIT, 100);
+    assert_eq!(query(Some(0)).log_limit(), 0);
+    assert_eq!(query(Some(-5)).log_limit(), 0);
+}
+
+#[actix_rt::test]
+async fn test_server_status_response_host_fields() {
+    let server = Server {
+        id: "test-server".to_string(),
+        is_online: true,
+        ..Default::default()
+    };
+    let mut metrics = ServerMetrics::new(50.0, 70.0, 60.0, 1000, 2000, vec![]);
+    metrics.host = Some(test_host());
+    let log = LogEntry::new(LogLevel::Critical, "disk full".to_string(), "syslog".to_string(), Some("test-server".to_string()));
+    let now = Utc::now();
+
+    let response = ServerStatusResponse::build(server.clone(), Some(metrics), vec![], vec![log], now);
+    assert_eq!(response.status, "online");
+    assert_eq!(response.uptime, "1d 1h 0m");
+    assert_eq!(response.uptime_seconds, Some(90_000));
+    assert_eq!(response.boot_time, Some(test_host().boot_time));
+    assert_eq!(response.kernel.as_deref(), Some("6.1.0-18-amd64"));
+    assert_eq!(response.os.as_deref(), Some("Debian GNU/Linux 12 (bookworm)"));
+    assert_eq!(response.recent_logs.len(), 1);
+    assert_eq!(response.recent_logs[0].message, "disk full");
+    assert_eq!(response.resources.last_updated, now);
+
+    // 수집 전이면 호스트 정보 없이 unknown
+    let response = ServerStatusResponse::build(server, None, vec![], vec![], now);
+    assert_eq!(response.uptime, "unknown");
+    assert!(response.uptime_seconds.is_none());
+    assert!(response.boot_time.is_none());
+    assert!(response.kernel.is_none());
+    assert!(response.os.is_none());
+    assert!(response.recent_logs.is_empty());
+}
+
 #[actix_rt::test]
 async fn test_get_server_status() {
     let mut mock_repo = MockRepository::new();
@@ -160,6 +217,13 @@ async fn test_get_server_status() {
         .times(1)
         .returning(|_, _, _| Ok(vec![]));
 
+    // 요청한 500 개는 최대치로 잘린다
+    mock_repo
+        .expect_get_recent_server_logs()
+        .with(eq(server_id), eq(MAX_STATUS_LOG_LIMIT))
+        .times(1)
+        .returning(|_, _| Ok(vec![]));
+
     mock_monitoring
         .expect_get_server_metrics()
         .with(eq(server_id))
@@ -172,6 +236,14 @@ async fn test_get_server_status() {
             network_tx: 2000,
             processes: vec![],
             timestamp: Utc::now(),
+            cpu_per_core: vec![50.0, 50.0],
+            load_average: None,
+            cpu_breakdown: None,
+            filesystems: vec![],
+            network_interfaces: vec![],
+            disk_io: vec![],
+            memory: None,
+            host: Some(test_host()),
         }));
 
     let app = test::init_service(
@@ -183,7 +255,7 @@ async fn test_get_server_status() {
     .await;
 
     let resp = test::TestRequest::get()
-        .uri(&format!("/servers/{}/status", server_id))
+        .uri(&format!("/servers/{}/status?logs=500", server_id))
         .send_request(&app)
         .await;
 
@@ -193,6 +265,9 @@ async fn test_get_server_status() {
     assert_eq!(body["id"], server_id);
     assert_eq!(body["status"], "online");
     assert_eq!(body["resources"]["cpu"], 50.0);
+    assert_eq!(body["uptime"], "1d 1h 0m");
+    assert_eq!(body["kernel"], "6.1.0-18-amd64");
+    assert_eq!(body["os"], "Debian GNU/Linux 12 (bookworm)");
 }
 
 #[actix_rt::test]
@@ -0,0 +1,27 @@
+// tests/api/services.rs
+use rust_server::api::services::UpdateServiceUnitsRequest;
+
+fn request(units: &[&str]) -> UpdateServiceUnitsRequest {
+    UpdateServiceUnitsRequest {
+        units: units.iter().map(|u| u.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_unit_names_are_normalized() {
+    let units = request(&["nginx", "nginx.service", " docker.socket ", "getty@tty1.service"])
+        .normalized()
+        .unwrap();
+    assert_eq!(units, vec!["nginx.service", "docker.socket", "getty@tty1.service"]);
+}
+
+#[test]
+fn test_invalid_unit_names_are_rejected() {
+    assert!(request(&["nginx; reboot"]).normalized().is_err());
+    assert!(request(&["$(id)"]).normalized().is_err());
+    assert!(request(&["--all"]).normalized().is_err());
+    assert!(request(&[""]).normalized().is_err());
+
+    let too_many: Vec<String> = (0..101).map(|i| format!("unit{}", i)).collect();
+    assert!(UpdateServiceUnitsRequest { units: too_many }.normalized().is_err());
+}
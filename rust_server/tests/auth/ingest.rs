@@ -0,0 +1,82 @@
+// tests/auth/ingest.rs
+use actix_web::http::{Method, StatusCode};
+use actix_web::{test::{call_service, init_service, try_call_service, TestRequest}, web, App, HttpResponse};
+use rust_server::auth::middleware::AuthMiddleware;
+use rust_server::auth::ingest::*;
+use rust_server::auth::utils::verify_password;
+
+#[test]
+fn test_generate_token_hashes_secret() {
+    let generated = generate_token().unwrap();
+    let (id, secret) = split_token(&generated.token).unwrap();
+
+    assert_eq!(id, generated.id);
+    assert!(!generated.secret_hash.contains(secret));
+    assert!(verify_password(secret, &generated.secret_hash).unwrap());
+    assert!(!verify_password("wrong-secret", &generated.secret_hash).unwrap());
+}
+
+#[test]
+fn test_split_token_rejects_malformed() {
+    assert!(split_token("no-separator").is_none());
+    assert!(split_token(".secret").is_none());
+    assert!(split_token("id.").is_none());
+    assert_eq!(split_token("id.sec.ret"), Some(("id", "sec.ret")));
+}
+
+#[test]
+fn test_is_ingest_path() {
+    assert!(is_ingest_path(&Method::POST, "/api/v1/servers/abc/metrics"));
+    assert!(is_ingest_path(&Method::POST, "/api/v1/servers/abc/metrics/batch"));
+    assert!(is_ingest_path(&Method::POST, "/api/v1/servers/abc/metrics/"));
+    assert!(is_ingest_path(&Method::POST, "/api/v1/servers/abc/inventory"));
+
+    // 조회와 다른 경로는 사용자 인증 필요
+    assert!(!is_ingest_path(&Method::GET, "/api/v1/servers/abc/metrics"));
+    assert!(!is_ingest_path(&Method::GET, "/api/v1/servers/abc/inventory"));
+    assert!(!is_ingest_path(&Method::POST, "/api/v1/servers/abc/inventory/history"));
+    assert!(!is_ingest_path(&Method::POST, "/api/v1/servers//metrics"));
+    assert!(!is_ingest_path(&Method::POST, "/api/v1/servers/abc/ingest-tokens"));
+    assert!(!is_ingest_path(&Method::POST, "/api/v1/servers/abc/metrics/other"));
+}
+
+#[actix_rt::test]
+async fn test_bearer_token() {
+    let req = actix_web::test::TestRequest::default()
+        .insert_header(("Authorization", "Bearer id.secret"))
+        .to_http_request();
+    assert_eq!(bearer_token(&req), Some("id.secret"));
+
+    let req = actix_web::test::TestRequest::default()
+        .insert_header(("Authorization", "Basic abc"))
+        .to_http_request();
+    assert_eq!(bearer_token(&req), None);
+}
+
+// ingest 경로는 JWT 없이 핸들러까지 가고, 나머지는 미들웨어에서 막힌다
+#[actix_rt::test]
+async fn test_ingest_paths_bypass_jwt() {
+    let app = init_service(
+        App::new().service(
+            web::scope("/api/v1/servers")
+                .wrap(AuthMiddleware)
+                .route("/{server_id}/inventory", web::post().to(HttpResponse::Accepted))
+                .route("/{server_id}/inventory", web::get().to(HttpResponse::Ok))
+                .route("/{server_id}/metrics", web::post().to(HttpResponse::Accepted)),
+        ),
+    )
+    .await;
+
+    for uri in ["/api/v1/servers/abc/inventory", "/api/v1/servers/abc/metrics"] {
+        let req = TestRequest::post()
+            .uri(uri)
+            .insert_header(("Authorization", "Bearer id.secret"))
+            .to_request();
+        let resp = call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::ACCEPTED, "{}", uri);
+    }
+
+    let req = TestRequest::get().uri("/api/v1/servers/abc/inventory").to_request();
+    let err = try_call_service(&app, req).await.err().unwrap();
+    assert_eq!(err.as_response_error().status_code(), StatusCode::UNAUTHORIZED);
+}
@@ -1 +1,2 @@
-pub mod auth_handlers;
\ No newline at end of file
+pub mod auth_handlers;
+pub mod ingest;
\ No newline at end of file
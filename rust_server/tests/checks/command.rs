@@ -0,0 +1,257 @@
+// tests/checks/command.rs
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use rust_server::checks::command::{self, PluginStateTracker};
+use rust_server::db::models::{AlertSeverity, CustomMetric, CustomMetricSeries, ServerCheck};
+use rust_server::models::check::{CheckConfig, CommandCheckConfig, MAX_CHECK_TIMEOUT_SECONDS};
+use rust_server::models::plugin::{parse_perfdata, PerfData, PluginOutput, PluginStatus};
+use rust_server::monitoring::ssh::{SshSession, SshTarget};
+
+fn check(failure_threshold: i32) -> ServerCheck {
+    ServerCheck {
+        id: "check-1".to_string(),
+        server_id: "server-1".to_string(),
+        name: "root disk".to_string(),
+        check_type: "command".to_string(),
+        config: serde_json::json!({"type": "command", "command": "check_disk -w 20% -c 10% -p /"}),
+        interval_seconds: 60,
+        failure_threshold,
+        enabled: true,
+        created_by: None,
+        created_at: Utc::now(),
+    }
+}
+
+fn output(status: PluginStatus, summary: &str) -> PluginOutput {
+    PluginOutput { status, summary: summary.to_string(), perfdata: Vec::new() }
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(PluginStatus::from_exit_code(0), PluginStatus::Ok);
+    assert_eq!(PluginStatus::from_exit_code(1), PluginStatus::Warning);
+    assert_eq!(PluginStatus::from_exit_code(2), PluginStatus::Critical);
+    assert_eq!(PluginStatus::from_exit_code(3), PluginStatus::Unknown);
+    assert_eq!(PluginStatus::from_exit_code(127), PluginStatus::Unknown);
+    assert_eq!(PluginStatus::from_exit_code(-1), PluginStatus::Unknown);
+}
+
+#[test]
+fn test_parse_single_line_output() {
+    let output = PluginOutput::parse(
+        1,
+        "DISK WARNING - free space: / 3326 MB (18%); | /=8673MB;9000;9500;0;10240\n",
+    );
+    assert_eq!(output.status, PluginStatus::Warning);
+    assert_eq!(output.summary, "DISK WARNING - free space: / 3326 MB (18%);");
+    assert_eq!(
+        output.perfdata,
+        vec![PerfData {
+            label: "/".to_string(),
+            value: 8673.0,
+            unit: Some("MB".to_string()),
+            warn: Some("9000".to_string()),
+            crit: Some("9500".to_string()),
+            min: Some(0.0),
+            max: Some(10240.0),
+        }]
+    );
+}
+
+#[test]
+fn test_parse_multi_line_output() {
+    let text = "\
+OK - load average: 0.12, 0.20, 0.25 | load1=0.120;5;10;0
+Long output line 1
+Long output line 2 | load5=0.200;4;8;0
+load15=0.250;3;6;0
+";
+    let output = PluginOutput::parse(0, text);
+    assert_eq!(output.status, PluginStatus::Ok);
+    assert_eq!(output.summary, "OK - load average: 0.12, 0.20, 0.25");
+    let labels: Vec<&str> = output.perfdata.iter().map(|p| p.label.as_str()).collect();
+    assert_eq!(labels, vec!["load1", "load5", "load15"]);
+    assert_eq!(output.perfdata[2].value, 0.25);
+}
+
+#[test]
+fn test_parse_empty_and_out_of_bounds_output() {
+    let output = PluginOutput::parse(0, "");
+    assert_eq!(output.summary, "(No output returned from plugin)");
+    assert!(output.perfdata.is_empty());
+
+    let output = PluginOutput::parse(127, "sh: check_foo: not found\n");
+    assert_eq!(output.status, PluginStatus::Unknown);
+    assert_eq!(output.summary, "Return code of 127 is out of bounds: sh: check_foo: not found");
+}
+
+#[test]
+fn test_parse_perfdata_formats() {
+    let items = parse_perfdata("'Free Space'=42.5% 'it''s'=3c time=0.012s;;;0 size=U;1;2 junk rta=-1.5ms;@10:20;~:30");
+    assert_eq!(items.len(), 4);
+
+    assert_eq!(items[0].label, "Free Space");
+    assert_eq!(items[0].value, 42.5);
+    assert_eq!(items[0].unit.as_deref(), Some("%"));
+    assert_eq!(items[0].warn, None);
+
+    assert_eq!(items[1].label, "it's");
+    assert_eq!(items[1].unit.as_deref(), Some("c"));
+
+    assert_eq!(items[2].label, "time");
+    assert_eq!(items[2].min, Some(0.0));
+    assert_eq!(items[2].max, None);
+
+    // 값이 U 인 항목과 '=' 없는 토큰은 버린다
+    assert_eq!(items[3].label, "rta");
+    assert_eq!(items[3].value, -1.5);
+    assert_eq!(items[3].warn.as_deref(), Some("@10:20"));
+    assert_eq!(items[3].crit.as_deref(), Some("~:30"));
+}
+
+#[test]
+fn test_outcome_mapping() {
+    let ok = command::outcome(&output(PluginStatus::Ok, "PING OK"), Some(12.0));
+    assert!(ok.success);
+    assert_eq!(ok.status_code, Some(0));
+    assert_eq!(ok.message, None);
+
+    let critical = command::outcome(&output(PluginStatus::Critical, "PROCS CRITICAL: 0 processes"), Some(8.0));
+    assert!(!critical.success);
+    assert_eq!(critical.status_code, Some(2));
+    assert_eq!(critical.message.as_deref(), Some("CRITICAL: PROCS CRITICAL: 0 processes"));
+
+    // 실행되지 않았으면 OK 여도 성공이 아니다
+    assert!(!command::outcome(&output(PluginStatus::Unknown, "x"), None).success);
+}
+
+#[test]
+fn test_state_changes_become_alerts() {
+    let tracker = PluginStateTracker::new();
+    let check = check(2);
+
+    assert!(tracker.record(&check, &output(PluginStatus::Ok, "DISK OK")).is_none());
+
+    // OK 에서 벗어날 때는 failure_threshold 번 연속이어야 확정
+    assert!(tracker.record(&check, &output(PluginStatus::Warning, "DISK WARNING")).is_none());
+    assert_eq!(tracker.hard_state(&check.id), PluginStatus::Ok);
+    let alert = tracker.record(&check, &output(PluginStatus::Warning, "DISK WARNING")).unwrap();
+    assert_eq!(alert.alert_type, "check_warning");
+    assert_eq!(alert.severity, AlertSeverity::Warning);
+    assert_eq!(alert.server_id, "server-1");
+    assert_eq!(alert.message, "Check 'root disk' is WARNING: DISK WARNING");
+    assert!(tracker.record(&check, &output(PluginStatus::Warning, "DISK WARNING")).is_none());
+
+    // 문제 상태 사이의 변화와 복구는 바로 알린다
+    let alert = tracker.record(&check, &output(PluginStatus::Critical, "DISK CRITICAL")).unwrap();
+    assert_eq!(alert.alert_type, "check_critical");
+    assert_eq!(alert.severity, AlertSeverity::Critical);
+
+    let alert = tracker.record(&check, &output(PluginStatus::Unknown, "timeout")).unwrap();
+    assert_eq!(alert.alert_type, "check_unknown");
+    assert_eq!(alert.severity, AlertSeverity::Warning);
+
+    let alert = tracker.record(&check, &output(PluginStatus::Ok, "DISK OK")).unwrap();
+    assert_eq!(alert.alert_type, "check_recovered");
+    assert_eq!(alert.severity, AlertSeverity::Info);
+    assert_eq!(alert.message, "Check 'root disk' recovered from UNKNOWN: DISK OK");
+    assert_eq!(tracker.hard_state(&check.id), PluginStatus::Ok);
+}
+
+#[test]
+fn test_soft_state_resets_on_ok() {
+    let tracker = PluginStateTracker::new();
+    let check = check(3);
+
+    assert!(tracker.record(&check, &output(PluginStatus::Critical, "down")).is_none());
+    assert!(tracker.record(&check, &output(PluginStatus::Critical, "down")).is_none());
+    assert!(tracker.record(&check, &output(PluginStatus::Ok, "up")).is_none());
+    assert!(tracker.record(&check, &output(PluginStatus::Critical, "down")).is_none());
+    // 다른 문제 상태로 바뀌면 처음부터 센다
+    assert!(tracker.record(&check, &output(PluginStatus::Warning, "slow")).is_none());
+    assert!(tracker.record(&check, &output(PluginStatus::Warning, "slow")).is_none());
+    assert!(tracker.record(&check, &output(PluginStatus::Warning, "slow")).is_some());
+}
+
+#[tokio::test]
+async fn test_unreachable_server_is_unknown() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    drop(listener);
+
+    let session = Arc::new(SshSession::new(SshTarget {
+        host: "127.0.0.1".to_string(),
+        port,
+        username: "monitor".to_string(),
+        password: "secret".to_string(),
+    }));
+    let config = CommandCheckConfig { command: "check_load -w 5 -c 10".to_string(), timeout_seconds: 5 };
+
+    let (outcome, output) = command::run(&session, &config).await;
+    assert_eq!(output.status, PluginStatus::Unknown);
+    assert!(output.summary.starts_with("Failed to run command"));
+    assert!(!outcome.success);
+    assert_eq!(outcome.response_time_ms, None);
+    assert_eq!(outcome.status_code, Some(3));
+}
+
+#[test]
+fn test_session_timeout_outlasts_check_timeout() {
+    let config = CommandCheckConfig { command: "check_backup".to_string(), timeout_seconds: MAX_CHECK_TIMEOUT_SECONDS };
+    let timeout = command::session_timeout(&config);
+    assert!(timeout > std::time::Duration::from_secs(MAX_CHECK_TIMEOUT_SECONDS));
+
+    let session = SshSession::new(SshTarget {
+        host: "127.0.0.1".to_string(),
+        port: 22,
+        username: "monitor".to_string(),
+        password: "secret".to_string(),
+    });
+    assert_eq!(session.timeout(), std::time::Duration::from_secs(30));
+    assert_eq!(session.with_timeout(timeout).timeout(), timeout);
+}
+
+#[test]
+fn test_config() {
+    let config: CheckConfig = serde_json::from_value(serde_json::json!({
+        "type": "command",
+        "command": "/usr/lib/nagios/plugins/check_swap -w 50% -c 20%"
+    }))
+    .unwrap();
+    assert_eq!(config.kind(), "command");
+    assert!(config.validate().is_ok());
+
+    let empty = CommandCheckConfig { command: "  ".to_string(), timeout_seconds: 10 };
+    assert!(empty.validate().is_err());
+    let slow = CommandCheckConfig { command: "check_ping".to_string(), timeout_seconds: 0 };
+    assert!(slow.validate().is_err());
+}
+
+#[test]
+fn test_group_custom_metrics_by_label() {
+    let now = Utc::now();
+    let row = |label: &str, minutes: i64, value: f64, warn: &str| CustomMetric {
+        label: label.to_string(),
+        timestamp: now + Duration::minutes(minutes),
+        value,
+        unit: Some("MB".to_string()),
+        warn: Some(warn.to_string()),
+        crit: None,
+        min: Some(0.0),
+        max: None,
+    };
+    let series = CustomMetricSeries::group(vec![
+        row("/", 0, 10.0, "900"),
+        row("/", 1, 11.0, "950"),
+        row("/var", 0, 5.0, "500"),
+    ]);
+
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0].label, "/");
+    assert_eq!(series[0].points.len(), 2);
+    assert_eq!(series[0].points[1].value, 11.0);
+    // 임계값은 가장 최근 값
+    assert_eq!(series[0].warn.as_deref(), Some("950"));
+    assert_eq!(series[1].label, "/var");
+    assert_eq!(series[1].points.len(), 1);
+}
@@ -0,0 +1,190 @@
+// tests/checks/dns.rs
+use rust_server::checks::dns::{self, DnsResponse};
+use rust_server::models::check::{DnsCheckConfig, DnsRecordType};
+use tokio::net::UdpSocket;
+
+// 질문 이름(오프셋 12)을 가리키는 압축 포인터
+const QUESTION_NAME: [u8; 2] = [0xc0, 0x0c];
+
+fn encode_name(name: &str) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    for label in name.split('.') {
+        encoded.push(label.len() as u8);
+        encoded.extend_from_slice(label.as_bytes());
+    }
+    encoded.push(0);
+    encoded
+}
+
+fn record(owner: &[u8], rtype: u16, rdata: &[u8]) -> Vec<u8> {
+    let mut record = owner.to_vec();
+    record.extend_from_slice(&rtype.to_be_bytes());
+    record.extend_from_slice(&1u16.to_be_bytes());
+    record.extend_from_slice(&300u32.to_be_bytes());
+    record.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+    record.extend_from_slice(rdata);
+    record
+}
+
+// 질의 패킷에 응답 플래그와 레코드를 붙인다
+fn response(query: &[u8], rcode: u8, records: &[Vec<u8>]) -> Vec<u8> {
+    let mut packet = query.to_vec();
+    packet[2] = 0x81;
+    packet[3] = 0x80 | rcode;
+    packet[6..8].copy_from_slice(&(records.len() as u16).to_be_bytes());
+    for record in records {
+        packet.extend_from_slice(record);
+    }
+    packet
+}
+
+fn config(name: &str, record_type: DnsRecordType, expected: &[&str]) -> DnsCheckConfig {
+    DnsCheckConfig {
+        name: name.to_string(),
+        record_type,
+        expected: expected.iter().map(|e| e.to_string()).collect(),
+        resolver: Some("127.0.0.1".to_string()),
+        timeout_seconds: 2,
+    }
+}
+
+#[test]
+fn test_build_query() {
+    let query = dns::build_query(0x1234, "example.com.", DnsRecordType::A);
+
+    assert_eq!(&query[..4], &[0x12, 0x34, 0x01, 0x00]);
+    assert_eq!(&query[4..6], &[0, 1]);
+    assert_eq!(&query[12..25], encode_name("example.com").as_slice());
+    assert_eq!(&query[25..], &[0, 1, 0, 1]);
+}
+
+#[test]
+fn test_parse_a_records_behind_cname() {
+    let query = dns::build_query(7, "www.example.com", DnsRecordType::A);
+    let cname = encode_name("web.example.com");
+    let packet = response(&query, 0, &[
+        record(&QUESTION_NAME, 5, &cname),
+        record(&encode_name("web.example.com"), 1, &[93, 184, 216, 34]),
+        record(&encode_name("web.example.com"), 1, &[93, 184, 216, 35]),
+    ]);
+
+    let parsed = dns::parse_response(7, DnsRecordType::A, &packet).unwrap();
+    assert_eq!(parsed, DnsResponse {
+        rcode: 0,
+        answers: vec!["93.184.216.34".to_string(), "93.184.216.35".to_string()],
+    });
+
+    let parsed = dns::parse_response(7, DnsRecordType::Cname, &packet).unwrap();
+    assert_eq!(parsed.answers, vec!["web.example.com"]);
+}
+
+#[test]
+fn test_parse_other_record_types() {
+    let query = dns::build_query(9, "example.com", DnsRecordType::Mx);
+    // 교환 서버 이름의 example.com 부분은 질문 이름을 가리킨다
+    let mut mx = 10u16.to_be_bytes().to_vec();
+    mx.extend_from_slice(&[4, b'm', b'a', b'i', b'l', 0xc0, 0x0c]);
+    let packet = response(&query, 0, &[record(&QUESTION_NAME, 15, &mx)]);
+    let parsed = dns::parse_response(9, DnsRecordType::Mx, &packet).unwrap();
+    assert_eq!(parsed.answers, vec!["10 mail.example.com"]);
+
+    let query = dns::build_query(9, "example.com", DnsRecordType::Txt);
+    let txt = [&[9u8][..], b"v=spf1 -a", &[4u8][..], b" ~al"].concat();
+    let packet = response(&query, 0, &[record(&QUESTION_NAME, 16, &txt)]);
+    let parsed = dns::parse_response(9, DnsRecordType::Txt, &packet).unwrap();
+    assert_eq!(parsed.answers, vec!["v=spf1 -a ~al"]);
+
+    let query = dns::build_query(9, "example.com", DnsRecordType::Aaaa);
+    let address: std::net::Ipv6Addr = "2606:2800:220:1::248".parse().unwrap();
+    let packet = response(&query, 0, &[record(&QUESTION_NAME, 28, &address.octets())]);
+    let parsed = dns::parse_response(9, DnsRecordType::Aaaa, &packet).unwrap();
+    assert_eq!(parsed.answers, vec!["2606:2800:220:1::248"]);
+}
+
+#[test]
+fn test_invalid_responses_are_rejected() {
+    let query = dns::build_query(1, "example.com", DnsRecordType::A);
+    let packet = response(&query, 0, &[record(&QUESTION_NAME, 1, &[10, 0, 0, 1])]);
+
+    assert!(dns::parse_response(2, DnsRecordType::A, &packet).is_err());
+    assert!(dns::parse_response(1, DnsRecordType::A, &query).is_err());
+    assert!(dns::parse_response(1, DnsRecordType::A, &packet[..packet.len() - 2]).is_err());
+
+    // 자기 자신을 가리키는 포인터
+    let mut looped = response(&query, 0, &[]);
+    looped[12] = 0xc0;
+    looped[13] = 0x0c;
+    assert!(dns::parse_response(1, DnsRecordType::A, &looped).is_err());
+}
+
+#[test]
+fn test_evaluate() {
+    let ok = DnsResponse { rcode: 0, answers: vec!["10.0.0.1".into(), "10.0.0.2".into()] };
+
+    assert!(dns::evaluate(&config("db.internal", DnsRecordType::A, &[]), &ok).is_ok());
+    assert!(dns::evaluate(&config("db.internal", DnsRecordType::A, &["10.0.0.2"]), &ok).is_ok());
+    assert_eq!(
+        dns::evaluate(&config("db.internal", DnsRecordType::A, &["10.0.0.1", "10.0.0.3"]), &ok),
+        Err("Missing expected answers: 10.0.0.3 (got: 10.0.0.1, 10.0.0.2)".to_string())
+    );
+
+    let nxdomain = DnsResponse { rcode: 3, answers: vec![] };
+    assert_eq!(
+        dns::evaluate(&config("db.internal", DnsRecordType::A, &[]), &nxdomain),
+        Err("Resolver returned NXDOMAIN".to_string())
+    );
+    let empty = DnsResponse { rcode: 0, answers: vec![] };
+    assert_eq!(
+        dns::evaluate(&config("db.internal", DnsRecordType::Aaaa, &[]), &empty),
+        Err("No AAAA records for db.internal".to_string())
+    );
+
+    // 이름은 대소문자와 끝의 '.' 를 무시한다
+    let cname = DnsResponse { rcode: 0, answers: vec!["LB.Example.com".into()] };
+    assert!(dns::evaluate(&config("www.example.com", DnsRecordType::Cname, &["lb.example.com."]), &cname).is_ok());
+}
+
+#[test]
+fn test_parse_resolv_conf() {
+    let content = "# generated\nsearch example.com\nnameserver fe80::1%eth0\nnameserver 10.0.0.2\nnameserver 1.1.1.1\n";
+    assert_eq!(dns::parse_resolv_conf(content), Some("10.0.0.2:53".parse().unwrap()));
+    assert_eq!(dns::parse_resolv_conf("search example.com\n"), None);
+}
+
+#[test]
+fn test_config_validation() {
+    assert!(config("example.com", DnsRecordType::A, &[]).validate().is_ok());
+    assert!(config("", DnsRecordType::A, &[]).validate().is_err());
+    assert!(config("bad..name", DnsRecordType::A, &[]).validate().is_err());
+
+    let mut check = config("example.com", DnsRecordType::A, &[]);
+    check.resolver = Some("10.0.0.2:5353".to_string());
+    assert_eq!(check.resolver_addr(), Some("10.0.0.2:5353".parse().unwrap()));
+    check.resolver = Some("resolver.local".to_string());
+    assert!(check.validate().is_err());
+}
+
+#[tokio::test]
+async fn test_query_against_stub_resolver() {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let address = socket.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut buf = [0u8; 512];
+        while let Ok((n, peer)) = socket.recv_from(&mut buf).await {
+            let answer = response(&buf[..n], 0, &[record(&QUESTION_NAME, 1, &[10, 0, 0, 5])]);
+            let _ = socket.send_to(&answer, peer).await;
+        }
+    });
+
+    let mut check = config("db.internal", DnsRecordType::A, &["10.0.0.5"]);
+    check.resolver = Some(address.to_string());
+    let outcome = dns::run(&check).await;
+    assert!(outcome.success, "{:?}", outcome.message);
+    assert_eq!(outcome.status_code, Some(0));
+    assert!(outcome.response_time_ms.is_some());
+
+    check.expected = vec!["10.0.0.6".to_string()];
+    let outcome = dns::run(&check).await;
+    assert!(!outcome.success);
+    assert_eq!(outcome.message.as_deref(), Some("Missing expected answers: 10.0.0.6 (got: 10.0.0.5)"));
+}
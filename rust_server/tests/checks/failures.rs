@@ -0,0 +1,62 @@
+// tests/checks/failures.rs
+use chrono::Utc;
+use rust_server::checks::FailureTracker;
+use rust_server::db::models::{AlertSeverity, ServerCheck};
+use rust_server::models::check::CheckOutcome;
+
+fn check(failure_threshold: i32) -> ServerCheck {
+    ServerCheck {
+        id: "check-1".to_string(),
+        server_id: "server-1".to_string(),
+        name: "api health".to_string(),
+        check_type: "http".to_string(),
+        config: serde_json::json!({"type": "http", "url": "https://example.com"}),
+        interval_seconds: 60,
+        failure_threshold,
+        enabled: true,
+        created_by: None,
+        created_at: Utc::now(),
+    }
+}
+
+fn failed() -> CheckOutcome {
+    CheckOutcome::down(Some(12.0), Some(503), "Unexpected status 503")
+}
+
+#[test]
+fn test_alerts_once_after_consecutive_failures() {
+    let tracker = FailureTracker::new();
+    let check = check(3);
+
+    assert!(tracker.record(&check, &failed()).is_none());
+    assert!(tracker.record(&check, &failed()).is_none());
+    let alert = tracker.record(&check, &failed()).unwrap();
+    assert_eq!(alert.alert_type, "check_failed");
+    assert_eq!(alert.severity, AlertSeverity::Critical);
+    assert_eq!(alert.server_id, "server-1");
+    assert_eq!(alert.message, "Check 'api health' failed 3 times in a row: Unexpected status 503");
+
+    // 계속 실패해도 다시 알리지 않는다
+    assert!(tracker.record(&check, &failed()).is_none());
+    assert_eq!(tracker.consecutive_failures("check-1"), 4);
+
+    let recovered = tracker.record(&check, &CheckOutcome::up(8.0, Some(200))).unwrap();
+    assert_eq!(recovered.alert_type, "check_recovered");
+    assert_eq!(recovered.severity, AlertSeverity::Info);
+    assert_eq!(tracker.consecutive_failures("check-1"), 0);
+}
+
+#[test]
+fn test_success_resets_the_count() {
+    let tracker = FailureTracker::new();
+    let check = check(2);
+
+    assert!(tracker.record(&check, &failed()).is_none());
+    // 알리기 전에 회복하면 복구 알림도 없다
+    assert!(tracker.record(&check, &CheckOutcome::up(8.0, Some(200))).is_none());
+    assert!(tracker.record(&check, &failed()).is_none());
+    assert!(tracker.record(&check, &failed()).is_some());
+
+    tracker.clear("check-1");
+    assert_eq!(tracker.consecutive_failures("check-1"), 0);
+}
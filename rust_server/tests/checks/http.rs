@@ -0,0 +1,227 @@
+// tests/checks/http.rs
+use std::collections::HashMap;
+use std::time::Duration;
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use rust_server::checks::http;
+use rust_server::models::check::{CheckConfig, HttpCheckConfig};
+
+// 검사 대상으로 쓸 로컬 stub 서버. 주소를 돌려준다
+async fn start_stub() -> String {
+    let server = HttpServer::new(|| {
+        App::new()
+            .route("/health", web::get().to(|| async { HttpResponse::Ok().body("status: ok, version 1.4.2") }))
+            .route("/down", web::get().to(|| async { HttpResponse::ServiceUnavailable().body("maintenance") }))
+            .route("/created", web::post().to(|| async { HttpResponse::Created().finish() }))
+            .route("/large", web::get().to(|| async {
+                let mut body = "begin ".repeat(1024).into_bytes();
+                body.resize(http::MAX_BODY_BYTES + 64 * 1024, b'x');
+                body.extend_from_slice(b"needle");
+                HttpResponse::Ok().body(body)
+            }))
+            .route("/slow", web::get().to(|| async {
+                tokio::time::sleep(Duration::from_millis(300)).await;
+                HttpResponse::Ok().body("late")
+            }))
+            .route("/secure", web::get().to(|req: HttpRequest| async move {
+                match req.headers().get("X-Check-Token").and_then(|v| v.to_str().ok()) {
+                    Some("secret") => HttpResponse::Ok().body("welcome"),
+                    _ => HttpResponse::Unauthorized().finish(),
+                }
+            }))
+    })
+    .workers(1)
+    .bind(("127.0.0.1", 0))
+    .unwrap();
+    let address = server.addrs()[0];
+    actix_rt::spawn(server.run());
+    format!("http://{}", address)
+}
+
+fn config(url: String) -> HttpCheckConfig {
+    HttpCheckConfig {
+        url,
+        method: "GET".to_string(),
+        expected_status: Vec::new(),
+        body_contains: None,
+        body_regex: None,
+        max_response_ms: None,
+        headers: HashMap::new(),
+        timeout_seconds: 5,
+    }
+}
+
+#[actix_rt::test]
+async fn test_healthy_endpoint_is_up() {
+    let base = start_stub().await;
+    let client = reqwest::Client::new();
+
+    let mut check = config(format!("{}/health", base));
+    check.body_contains = Some("status: ok".to_string());
+    check.body_regex = Some(r"version \d+\.\d+\.\d+".to_string());
+    let outcome = http::run(&client, &check).await;
+
+    assert!(outcome.success, "{:?}", outcome.message);
+    assert_eq!(outcome.status_code, Some(200));
+    assert!(outcome.response_time_ms.is_some());
+    assert_eq!(outcome.message, None);
+}
+
+#[actix_rt::test]
+async fn test_unexpected_status_and_body_fail() {
+    let base = start_stub().await;
+    let client = reqwest::Client::new();
+
+    let outcome = http::run(&client, &config(format!("{}/down", base))).await;
+    assert!(!outcome.success);
+    assert_eq!(outcome.status_code, Some(503));
+    assert_eq!(outcome.message.as_deref(), Some("Unexpected status 503"));
+
+    // 503 을 기대하면 성공
+    let mut check = config(format!("{}/down", base));
+    check.expected_status = vec![503];
+    assert!(http::run(&client, &check).await.success);
+
+    let mut check = config(format!("{}/health", base));
+    check.body_contains = Some("status: degraded".to_string());
+    let outcome = http::run(&client, &check).await;
+    assert!(!outcome.success);
+    assert_eq!(outcome.message.as_deref(), Some("Body does not contain 'status: degraded'"));
+
+    let mut check = config(format!("{}/health", base));
+    check.body_regex = Some(r"version 2\.".to_string());
+    assert!(!http::run(&client, &check).await.success);
+}
+
+#[actix_rt::test]
+async fn test_method_and_headers_are_sent() {
+    let base = start_stub().await;
+    let client = reqwest::Client::new();
+
+    let mut check = config(format!("{}/created", base));
+    check.method = "POST".to_string();
+    check.expected_status = vec![201];
+    assert!(http::run(&client, &check).await.success);
+
+    let check = config(format!("{}/secure", base));
+    assert_eq!(http::run(&client, &check).await.status_code, Some(401));
+
+    let mut check = config(format!("{}/secure", base));
+    check.headers.insert("X-Check-Token".to_string(), "secret".to_string());
+    assert!(http::run(&client, &check).await.success);
+}
+
+#[actix_rt::test]
+async fn test_response_time_budget_and_timeout() {
+    let base = start_stub().await;
+    let client = reqwest::Client::new();
+
+    let mut check = config(format!("{}/slow", base));
+    check.max_response_ms = Some(100);
+    let outcome = http::run(&client, &check).await;
+    assert!(!outcome.success);
+    assert_eq!(outcome.status_code, Some(200));
+    assert!(outcome.response_time_ms.unwrap() >= 300.0);
+    assert!(outcome.message.unwrap().contains("budget 100ms"));
+
+    let mut check = config(format!("{}/slow", base));
+    check.max_response_ms = Some(5000);
+    assert!(http::run(&client, &check).await.success);
+}
+
+#[actix_rt::test]
+async fn test_body_is_read_up_to_limit() {
+    let base = start_stub().await;
+    let client = reqwest::Client::new();
+
+    let mut check = config(format!("{}/large", base));
+    check.body_contains = Some("begin".to_string());
+    assert!(http::run(&client, &check).await.success);
+
+    // 한도 뒤에 있는 내용은 보지 않는다
+    check.body_contains = Some("needle".to_string());
+    let outcome = http::run(&client, &check).await;
+    assert!(!outcome.success);
+    assert!(outcome.message.unwrap().contains("does not contain"));
+}
+
+#[test]
+fn test_body_regex_cache() {
+    let cache = http::BodyRegexCache::new();
+    let mut check = config("https://example.com/health".to_string());
+    assert!(cache.get("check-1", &check).unwrap().is_none());
+
+    check.body_regex = Some(r"version \d+".to_string());
+    let regex = cache.get("check-1", &check).unwrap().unwrap();
+    assert!(regex.is_match("version 2"));
+    assert_eq!(cache.get("check-1", &check).unwrap().unwrap().as_str(), regex.as_str());
+
+    // 패턴이 바뀌면 다시 컴파일한다
+    check.body_regex = Some("^ok$".to_string());
+    assert_eq!(cache.get("check-1", &check).unwrap().unwrap().as_str(), "^ok$");
+
+    check.body_regex = Some("(unclosed".to_string());
+    assert!(cache.get("check-1", &check).is_err());
+}
+
+#[actix_rt::test]
+async fn test_connection_failure_is_down() {
+    let client = reqwest::Client::new();
+    // 아무도 듣지 않는 포트
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    drop(listener);
+
+    let outcome = http::run(&client, &config(format!("http://{}/health", address))).await;
+    assert!(!outcome.success);
+    assert_eq!(outcome.status_code, None);
+    assert_eq!(outcome.response_time_ms, None);
+    assert!(outcome.message.unwrap().starts_with("Request failed"));
+}
+
+#[test]
+fn test_config_validation() {
+    let valid = config("https://example.com/health".to_string());
+    assert!(valid.validate().is_ok());
+
+    let mut check = valid.clone();
+    check.url = "ftp://example.com".to_string();
+    assert!(check.validate().is_err());
+
+    let mut check = valid.clone();
+    check.body_regex = Some("(unclosed".to_string());
+    assert!(check.validate().is_err());
+
+    let mut check = valid.clone();
+    check.expected_status = vec![200, 700];
+    assert!(check.validate().is_err());
+
+    let mut check = valid.clone();
+    check.headers.insert("Bad Header".to_string(), "x".to_string());
+    assert!(check.validate().is_err());
+
+    let mut check = valid.clone();
+    check.timeout_seconds = 0;
+    assert!(check.validate().is_err());
+}
+
+#[test]
+fn test_config_is_tagged_by_type() {
+    let config: CheckConfig = serde_json::from_value(serde_json::json!({
+        "type": "http",
+        "url": "https://example.com",
+        "expected_status": [200, 204],
+        "headers": {"Authorization": "Bearer token"}
+    }))
+    .unwrap();
+
+    assert_eq!(config.kind(), "http");
+    match config {
+        CheckConfig::Http(http_config) => {
+            assert_eq!(http_config.method, "GET");
+            assert_eq!(http_config.timeout_seconds, 10);
+            assert!(http_config.status_ok(204));
+            assert!(!http_config.status_ok(301));
+        }
+        other => panic!("unexpected config {:?}", other),
+    }
+}
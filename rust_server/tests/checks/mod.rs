@@ -0,0 +1,6 @@
+pub mod command;
+pub mod dns;
+pub mod failures;
+pub mod http;
+pub mod tcp;
+pub mod tls;
@@ -0,0 +1,72 @@
+// tests/checks/tcp.rs
+use rust_server::checks::tcp;
+use rust_server::models::check::TcpCheckConfig;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+
+// 접속하면 banner 를 보내는 stub 서버의 포트
+async fn start_stub(banner: &'static str) -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let _ = stream.write_all(banner.as_bytes()).await;
+        }
+    });
+    port
+}
+
+fn config(port: u16, banner: Option<&str>) -> TcpCheckConfig {
+    TcpCheckConfig {
+        host: "127.0.0.1".to_string(),
+        port,
+        banner: banner.map(str::to_string),
+        timeout_seconds: 2,
+    }
+}
+
+#[tokio::test]
+async fn test_open_port_is_up() {
+    let port = start_stub("SSH-2.0-OpenSSH_9.6\r\n").await;
+
+    let outcome = tcp::run(&config(port, None)).await;
+    assert!(outcome.success, "{:?}", outcome.message);
+    assert!(outcome.response_time_ms.is_some());
+
+    let outcome = tcp::run(&config(port, Some("SSH-2.0"))).await;
+    assert!(outcome.success, "{:?}", outcome.message);
+}
+
+#[tokio::test]
+async fn test_banner_mismatch_is_down() {
+    let port = start_stub("220 mail.example.com ESMTP Postfix\r\n").await;
+
+    let outcome = tcp::run(&config(port, Some("SSH-2.0"))).await;
+    assert!(!outcome.success);
+    // 연결은 됐으므로 응답 시간은 남는다
+    assert!(outcome.response_time_ms.is_some());
+    assert_eq!(outcome.message.as_deref(), Some("Banner does not contain 'SSH-2.0'"));
+}
+
+#[tokio::test]
+async fn test_closed_port_is_down() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    drop(listener);
+
+    let outcome = tcp::run(&config(port, None)).await;
+    assert!(!outcome.success);
+    assert_eq!(outcome.response_time_ms, None);
+    assert!(outcome.message.unwrap().starts_with("Connection to 127.0.0.1"));
+}
+
+#[test]
+fn test_config_validation() {
+    assert!(config(22, None).validate().is_ok());
+    assert!(config(0, None).validate().is_err());
+    assert!(config(22, Some("")).validate().is_err());
+
+    let mut check = config(22, None);
+    check.host = "bad host".to_string();
+    assert!(check.validate().is_err());
+}
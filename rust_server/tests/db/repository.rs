@@ -9,8 +9,6 @@ use chrono::DateTime;
 
 mock! {
     Repository {
-        fn new() -> Self;
-        
         // Server operations
         async fn create_server(&self, server: Server) -> anyhow::Result<Server>;
         async fn get_server(&self, id: &str) -> anyhow::Result<Option<Server>>;
@@ -58,7 +56,7 @@ async fn test_server_operations() {
     let test_server_clone = test_server.clone();
     mock_repo
         .expect_create_server()
-        .returning(move |server| Ok(server));
+        .returning(Ok);
 
     mock_repo
         .expect_list_servers()
@@ -66,7 +64,10 @@ async fn test_server_operations() {
 
     mock_repo
         .expect_get_server()
-        .returning(|_| Ok(Some(test_server.clone())));
+        .returning({
+            let test_server = test_server.clone();
+            move |_| Ok(Some(test_server.clone()))
+        });
 
     mock_repo
         .expect_delete_server()
@@ -109,6 +110,27 @@ async fn test_metrics_storage() {
             "memory_usage": 1024
         }]),
         timestamp: Utc::now(),
+        cpu_per_core: serde_json::json!([]),
+        load_1: None,
+        load_5: None,
+        load_15: None,
+        cpu_user: None,
+        cpu_system: None,
+        cpu_iowait: None,
+        cpu_steal: None,
+        filesystems: serde_json::json!([]),
+        network_interfaces: serde_json::json!([]),
+        disk_io: serde_json::json!([]),
+        disk_io_util: None,
+        disk_read_bps: None,
+        disk_write_bps: None,
+        memory_total: None,
+        memory_used: None,
+        memory_available: None,
+        memory_cached: None,
+        memory_buffers: None,
+        swap_total: None,
+        swap_used: None,
     };
 
     mock_repo
@@ -147,15 +169,21 @@ async fn test_log_management() {
     // Setup expectations
     mock_repo
         .expect_create_log()
-        .returning(|log| Ok(log));
+        .returning(Ok);
 
     mock_repo
         .expect_get_log()
-        .returning(|_| Ok(Some(test_log.clone())));
+        .returning({
+            let test_log = test_log.clone();
+            move |_| Ok(Some(test_log.clone()))
+        });
 
     mock_repo
         .expect_get_logs()
-        .returning(|_| Ok(vec![test_log.clone()]));
+        .returning({
+            let test_log = test_log.clone();
+            move |_| Ok(vec![test_log.clone()])
+        });
 
     mock_repo
         .expect_delete_logs()
@@ -207,11 +235,14 @@ async fn test_alert_handling() {
     // Setup expectations
     mock_repo
         .expect_create_alert()
-        .returning(|alert| Ok(alert));
+        .returning(Ok);
 
     mock_repo
         .expect_get_unacknowledged_alerts()
-        .returning(|| Ok(vec![test_alert.clone()]));
+        .returning({
+            let test_alert = test_alert.clone();
+            move || Ok(vec![test_alert.clone()])
+        });
 
     mock_repo
         .expect_acknowledge_alert()
@@ -252,15 +283,18 @@ async fn test_user_management() {
     // Setup expectations
     mock_repo
         .expect_create_user()
-        .returning(|user| Ok(user));
+        .returning(Ok);
 
     mock_repo
         .expect_get_user_by_email()
-        .returning(|_| Ok(Some(test_user.clone())));
+        .returning({
+            let test_user = test_user.clone();
+            move |_| Ok(Some(test_user.clone()))
+        });
 
     mock_repo
         .expect_update_user()
-        .returning(|user| Ok(user));
+        .returning(Ok);
 
     // Test user creation
     let result = mock_repo.create_user(test_user.clone()).await;
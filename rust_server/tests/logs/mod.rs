@@ -0,0 +1,3 @@
+// tests/logs/mod.rs
+pub mod syslog;
+pub mod tail;
@@ -0,0 +1,171 @@
+// tests/logs/syslog.rs
+use std::net::{IpAddr, Ipv4Addr};
+use chrono::{TimeZone, Utc};
+use rust_server::logs::syslog::{log_entry, lookup_keys, read_frame, MAX_MESSAGE_BYTES};
+use rust_server::models::logs::LogLevel;
+use rust_server::models::syslog::{SyslogFormat, SyslogMessage};
+
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 12, 20, 12, 0, 0).unwrap()
+}
+
+#[test]
+fn test_parse_rfc5424() {
+    let message = SyslogMessage::parse(
+        "<165>1 2024-12-20T10:14:15.003Z fw01.example.com evntslog 8710 ID47 \
+         [exampleSDID@32473 iut=\"3\" eventSource=\"App\\\"lication\"][meta x=\"]\"] \u{feff}An application event\n",
+        now(),
+    );
+    assert_eq!(message.format, SyslogFormat::Rfc5424);
+    assert_eq!(message.facility, 20);
+    assert_eq!(message.facility_name(), "local4");
+    assert_eq!(message.severity, 5);
+    assert_eq!(message.timestamp, Some(Utc.with_ymd_and_hms(2024, 12, 20, 10, 14, 15).unwrap() + chrono::Duration::milliseconds(3)));
+    assert_eq!(message.hostname.as_deref(), Some("fw01.example.com"));
+    assert_eq!(message.app_name.as_deref(), Some("evntslog"));
+    assert_eq!(message.proc_id.as_deref(), Some("8710"));
+    assert_eq!(message.msg_id.as_deref(), Some("ID47"));
+    assert_eq!(
+        message.structured_data.as_deref(),
+        Some("[exampleSDID@32473 iut=\"3\" eventSource=\"App\\\"lication\"][meta x=\"]\"]")
+    );
+    assert_eq!(message.message, "An application event");
+}
+
+#[test]
+fn test_parse_rfc5424_nil_values() {
+    let message = SyslogMessage::parse("<34>1 - - - - - -", now());
+    assert_eq!(message.format, SyslogFormat::Rfc5424);
+    assert_eq!(message.severity, 2);
+    assert_eq!(message.timestamp, None);
+    assert_eq!(message.hostname, None);
+    assert_eq!(message.app_name, None);
+    assert_eq!(message.structured_data, None);
+    assert_eq!(message.message, "");
+
+    let message = SyslogMessage::parse("<14>1 2024-12-20T10:00:00+09:00 host app - - - started", now());
+    assert_eq!(message.timestamp, Some(Utc.with_ymd_and_hms(2024, 12, 20, 1, 0, 0).unwrap()));
+    assert_eq!(message.message, "started");
+}
+
+#[test]
+fn test_parse_rfc3164() {
+    let message = SyslogMessage::parse("<38>Dec  5 22:14:15 mymachine sshd[4721]: Accepted publickey for root", now());
+    assert_eq!(message.format, SyslogFormat::Rfc3164);
+    assert_eq!(message.facility_name(), "auth");
+    assert_eq!(message.severity, 6);
+    assert_eq!(message.timestamp, Some(Utc.with_ymd_and_hms(2024, 12, 5, 22, 14, 15).unwrap()));
+    assert_eq!(message.hostname.as_deref(), Some("mymachine"));
+    assert_eq!(message.app_name.as_deref(), Some("sshd"));
+    assert_eq!(message.proc_id.as_deref(), Some("4721"));
+    assert_eq!(message.message, "Accepted publickey for root");
+}
+
+#[test]
+fn test_parse_rfc3164_variants() {
+    // 호스트 이름 없이 태그만
+    let message = SyslogMessage::parse("<187>Dec 20 11:59:00 %LINK-3-UPDOWN: Interface Gi0/1, changed state to down", now());
+    assert_eq!(message.hostname, None);
+    assert_eq!(message.app_name.as_deref(), Some("%LINK-3-UPDOWN"));
+    assert_eq!(message.severity, 3);
+    assert_eq!(message.message, "Interface Gi0/1, changed state to down");
+
+    // 태그 없는 본문
+    let message = SyslogMessage::parse("<13>Dec 20 11:59:00 router link flapping", now());
+    assert_eq!(message.hostname.as_deref(), Some("router"));
+    assert_eq!(message.app_name, None);
+    assert_eq!(message.message, "link flapping");
+
+    // 타임스탬프도 PRI 도 없으면 user.notice 의 본문
+    let message = SyslogMessage::parse("just some text", now());
+    assert_eq!((message.facility, message.severity), (1, 5));
+    assert_eq!(message.timestamp, None);
+    assert_eq!(message.message, "just some text");
+
+    // 범위를 벗어난 PRI 는 본문의 일부
+    let message = SyslogMessage::parse("<999>oops", now());
+    assert_eq!(message.message, "<999>oops");
+}
+
+#[test]
+fn test_rfc3164_year_rollover() {
+    // 12월 31일에 찍힌 메시지를 1월 1일에 받으면 지난해
+    let new_year = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 5).unwrap();
+    let message = SyslogMessage::parse("<13>Dec 31 23:59:59 host app: bye", new_year);
+    assert_eq!(message.timestamp, Some(Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap()));
+}
+
+#[test]
+fn test_severity_to_level() {
+    let level = |severity: u8| SyslogMessage::parse(&format!("<{}>x", severity), now()).level();
+    assert_eq!(level(0), LogLevel::Critical);
+    assert_eq!(level(1), LogLevel::Critical);
+    assert_eq!(level(2), LogLevel::Critical);
+    assert_eq!(level(3), LogLevel::Alert);
+    assert_eq!(level(4), LogLevel::Warning);
+    assert_eq!(level(5), LogLevel::Info);
+    assert_eq!(level(6), LogLevel::Info);
+    assert_eq!(level(7), LogLevel::Debug);
+}
+
+#[test]
+fn test_log_entry() {
+    let peer = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 7));
+    let message = SyslogMessage::parse("<11>1 2024-12-20T10:00:00Z sw01 snmpd 99 - - disk failure", now());
+    let entry = log_entry(&message, Some("server-1".to_string()), peer, now());
+    assert_eq!(entry.level, LogLevel::Alert);
+    assert_eq!(entry.component, "snmpd");
+    assert_eq!(entry.server_id.as_deref(), Some("server-1"));
+    assert_eq!(entry.message, "disk failure");
+    assert_eq!(entry.timestamp, Utc.with_ymd_and_hms(2024, 12, 20, 10, 0, 0).unwrap());
+    let details = entry.metadata.details.unwrap();
+    assert_eq!(details["facility"], "user");
+    assert_eq!(details["remote_addr"], "10.0.0.7");
+    assert_eq!(details["hostname"], "sw01");
+    assert!(details.get("msg_id").is_none());
+
+    // 앱 이름이 없으면 facility, 시각이 없으면 받은 시각
+    let message = SyslogMessage::parse("<0>kernel panic", now());
+    let entry = log_entry(&message, None, peer, now());
+    assert_eq!(entry.component, "kern");
+    assert_eq!(entry.timestamp, now());
+    assert_eq!(entry.server_id, None);
+}
+
+#[test]
+fn test_lookup_keys_ignore_spoofed_hostname() {
+    let attacker = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 66));
+    let relay = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+    let message = SyslogMessage::parse("<11>1 2024-12-20T10:00:00Z db01 app - - - forged", now());
+
+    // 아무 장비나 HOSTNAME 을 db01 로 써서 보낼 수 있으므로 보낸 주소로만 찾는다
+    assert_eq!(lookup_keys(&message, attacker, &[]), vec!["10.0.0.66"]);
+    assert_eq!(lookup_keys(&message, attacker, &[relay]), vec!["10.0.0.66"]);
+
+    // 중계 서버가 보낸 메시지는 원래 장비의 HOSTNAME 부터
+    assert_eq!(lookup_keys(&message, relay, &[relay]), vec!["db01", "10.0.0.2"]);
+
+    let message = SyslogMessage::parse("<11>1 2024-12-20T10:00:00Z - app - - - no host", now());
+    assert_eq!(lookup_keys(&message, relay, &[relay]), vec!["10.0.0.2"]);
+}
+
+#[tokio::test]
+async fn test_read_frames() {
+    let stream = b"<13>first line\n\r\n26 <14>1 - - - - - - counted\n<15>last".to_vec();
+    let mut reader = stream.as_slice();
+    assert_eq!(read_frame(&mut reader).await.unwrap().unwrap(), b"<13>first line\n");
+    assert_eq!(read_frame(&mut reader).await.unwrap().unwrap(), b"<14>1 - - - - - - counted\n");
+    assert_eq!(read_frame(&mut reader).await.unwrap().unwrap(), b"<15>last");
+    assert_eq!(read_frame(&mut reader).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_read_frame_rejects_oversized_length() {
+    let stream = format!("{} <13>x", MAX_MESSAGE_BYTES + 1);
+    let mut reader = stream.as_bytes();
+    assert!(read_frame(&mut reader).await.is_err());
+
+    let stream = "12x <13>x".to_string();
+    let mut reader = stream.as_bytes();
+    assert!(read_frame(&mut reader).await.is_err());
+}
@@ -0,0 +1,135 @@
+// tests/logs/tail.rs
+use rust_server::api::log_sources::CreateLogSourceRequest;
+use rust_server::logs::tail::{self, FileStat, TailPlan, MAX_READ_BYTES};
+use rust_server::models::log_source::{file_name, validate_log_path, LevelParser, LevelRule};
+use rust_server::models::logs::LogLevel;
+
+fn stat(inode: u64, size: u64) -> FileStat {
+    FileStat { inode, size }
+}
+
+#[test]
+fn test_plan_new_file_starts_at_end() {
+    assert_eq!(tail::plan(None, stat(10, 500)), TailPlan::Start { inode: 10, position: 500 });
+}
+
+#[test]
+fn test_plan_same_file() {
+    assert_eq!(tail::plan(Some((10, 200)), stat(10, 500)), TailPlan::Read { inode: 10, from: 200 });
+    // 잘렸으면(copytruncate) 처음부터
+    assert_eq!(tail::plan(Some((10, 200)), stat(10, 50)), TailPlan::Read { inode: 10, from: 0 });
+}
+
+#[test]
+fn test_plan_rotated_file() {
+    assert_eq!(
+        tail::plan(Some((10, 200)), stat(11, 30)),
+        TailPlan::Rotated { old_inode: 10, from: 200, inode: 11 }
+    );
+}
+
+#[test]
+fn test_parse_stat() {
+    assert_eq!(tail::parse_stat("1835021 40960\n"), Some(stat(1835021, 40960)));
+    assert_eq!(tail::parse_stat("stat: cannot stat '/x': No such file or directory"), None);
+    assert_eq!(tail::parse_stat(""), None);
+}
+
+#[test]
+fn test_commands() {
+    assert_eq!(tail::stat_command("/var/log/syslog"), "stat -L -c '%i %s' -- '/var/log/syslog'");
+    assert_eq!(
+        tail::read_command("/var/log/syslog", 0),
+        format!("tail -c +1 -- '/var/log/syslog' | head -c {}", MAX_READ_BYTES)
+    );
+    let rotated = tail::read_rotated_command("/var/log/nginx/error.log", 42, 99);
+    assert!(rotated.starts_with("f=$(find '/var/log/nginx/' -maxdepth 1 -inum 42 -type f"));
+    assert!(rotated.contains("tail -c +100 -- \"$f\""));
+}
+
+#[test]
+fn test_split_lines_keeps_partial_line() {
+    let (lines, consumed) = tail::split_lines(b"first\r\n\nsecond\nthird", false);
+    assert_eq!(lines, vec!["first", "second"]);
+    assert_eq!(consumed, 15);
+
+    let (lines, consumed) = tail::split_lines(b"no newline yet", false);
+    assert!(lines.is_empty());
+    assert_eq!(consumed, 0);
+}
+
+#[test]
+fn test_split_lines_at_eof_and_limit() {
+    // 회전된 파일의 끝이면 마지막 줄도 꺼낸다
+    let (lines, consumed) = tail::split_lines(b"a\nb", true);
+    assert_eq!(lines, vec!["a", "b"]);
+    assert_eq!(consumed, 3);
+
+    // 개행 없이 한도를 채운 줄은 통째로
+    let long = vec![b'x'; MAX_READ_BYTES];
+    let (lines, consumed) = tail::split_lines(&long, false);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(consumed, MAX_READ_BYTES);
+
+    // 잘못된 UTF-8 은 대체 문자로
+    let (lines, _) = tail::split_lines(b"bad \xff byte\n", false);
+    assert_eq!(lines, vec!["bad \u{fffd} byte"]);
+}
+
+#[test]
+fn test_default_level_rules() {
+    let parser = LevelParser::new(&[], LogLevel::Info).unwrap();
+    assert_eq!(parser.level("2024/12/19 10:00:00 [error] 123#0: upstream timed out"), LogLevel::Alert);
+    assert_eq!(parser.level("PHP Fatal error: Allowed memory size exhausted"), LogLevel::Critical);
+    assert_eq!(parser.level("[warn] conflicting server name"), LogLevel::Warning);
+    assert_eq!(parser.level("DEBUG connection pool size=4"), LogLevel::Debug);
+    assert_eq!(parser.level("GET /index.html 200"), LogLevel::Info);
+    // 단어 일부는 맞지 않는다
+    assert_eq!(parser.level("terrors of the deep"), LogLevel::Info);
+}
+
+#[test]
+fn test_custom_level_rules() {
+    let rules = vec![
+        LevelRule { pattern: r#"" 5\d\d "#.to_string(), level: LogLevel::Alert },
+        LevelRule { pattern: r#"" 4\d\d "#.to_string(), level: LogLevel::Warning },
+    ];
+    let parser = LevelParser::new(&rules, LogLevel::Debug).unwrap();
+    assert_eq!(parser.level(r#""GET / HTTP/1.1" 502 157 "-""#), LogLevel::Alert);
+    assert_eq!(parser.level(r#""GET /x HTTP/1.1" 404 0 "-""#), LogLevel::Warning);
+    assert_eq!(parser.level(r#""GET / HTTP/1.1" 200 612 "-""#), LogLevel::Debug);
+
+    let invalid = vec![LevelRule { pattern: "(unclosed".to_string(), level: LogLevel::Alert }];
+    assert!(LevelParser::new(&invalid, LogLevel::Info).is_err());
+}
+
+#[test]
+fn test_log_path_validation() {
+    assert!(validate_log_path("/var/log/nginx/error.log").is_ok());
+    assert!(validate_log_path("var/log/syslog").is_err());
+    assert!(validate_log_path("/var/log/").is_err());
+    assert!(validate_log_path("/var/log/x'; rm -rf /; '").is_err());
+    assert!(validate_log_path("/var/log/a\nb").is_err());
+    assert_eq!(file_name("/var/log/nginx/error.log"), "error.log");
+}
+
+#[test]
+fn test_create_request() {
+    let request: CreateLogSourceRequest = serde_json::from_value(serde_json::json!({
+        "path": "/var/log/nginx/error.log"
+    }))
+    .unwrap();
+    assert!(request.validate().is_ok());
+    assert_eq!(request.component(), "error.log");
+    assert_eq!(request.default_level, LogLevel::Info);
+    assert!(request.enabled);
+
+    let request: CreateLogSourceRequest = serde_json::from_value(serde_json::json!({
+        "path": "/var/log/app.log",
+        "component": " app ",
+        "level_rules": [{"pattern": "[", "level": "Alert"}]
+    }))
+    .unwrap();
+    assert_eq!(request.component(), "app");
+    assert!(request.validate().is_err());
+}
@@ -1,7 +1,10 @@
 // tests/mod.rs
 pub mod common;
+pub mod agent;
 pub mod api;
 pub mod auth;
+pub mod checks;
+pub mod logs;
 pub mod metrics;
 pub mod monitoring;
 pub mod utils;
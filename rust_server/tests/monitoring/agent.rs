@@ -0,0 +1,52 @@
+// tests/monitoring/agent.rs
+use chrono::{Duration, Utc};
+use rust_server::db::models::CollectionMethod;
+use rust_server::models::metrics::ServerMetrics;
+use rust_server::monitoring::agent::AgentSource;
+use rust_server::monitoring::collector::LocalSource;
+use rust_server::monitoring::MetricSource;
+
+#[tokio::test]
+async fn test_agent_source_requires_push() {
+    let source = AgentSource::new();
+
+    assert_eq!(source.method(), CollectionMethod::Agent);
+    assert!(!source.capabilities().polled);
+    assert!(source.collect().await.is_err());
+}
+
+#[tokio::test]
+async fn test_agent_source_keeps_latest_sample() {
+    let source = AgentSource::new();
+
+    let newer = ServerMetrics::new(50.0, 40.0, 30.0, 100, 200, Vec::new());
+    let mut older = ServerMetrics::new(10.0, 10.0, 10.0, 1, 2, Vec::new());
+    older.timestamp = newer.timestamp - Duration::seconds(30);
+
+    assert!(source.push(newer).await);
+    assert!(source.push(older).await);
+
+    let metrics = source.collect().await.unwrap();
+    assert_eq!(metrics.cpu_usage, 50.0);
+    assert!(metrics.timestamp <= Utc::now());
+}
+
+#[tokio::test]
+async fn test_local_source_collects_sysinfo() {
+    let source = LocalSource::new();
+
+    assert_eq!(source.method(), CollectionMethod::Local);
+    assert!(source.capabilities().polled);
+    assert!(!source.capabilities().remote);
+
+    let metrics = source.collect().await.unwrap();
+    assert!(metrics.memory_usage > 0.0 && metrics.memory_usage <= 100.0);
+    assert!(!metrics.cpu_per_core.is_empty());
+    #[cfg(target_os = "linux")]
+    {
+        assert!(metrics.load_average.is_some());
+        assert!(metrics.cpu_breakdown.is_some());
+    }
+    // push 방식이 아니면 거부
+    assert!(!source.push(metrics).await);
+}
@@ -0,0 +1,166 @@
+// tests/monitoring/alerts.rs
+use rust_server::config::AlertThresholds;
+use rust_server::db::models::AlertSeverity;
+use rust_server::models::metrics::{DiskIoMetrics, FilesystemMetrics, MemoryMetrics, ServerMetrics};
+use rust_server::monitoring::alerts::AlertEvaluator;
+
+fn filesystem(mount_point: &str, used: u64, inodes_used: Option<u64>) -> FilesystemMetrics {
+    FilesystemMetrics {
+        mount_point: mount_point.to_string(),
+        device: "/dev/sda1".to_string(),
+        fs_type: "ext4".to_string(),
+        total: 100,
+        used,
+        free: 100 - used,
+        inodes_total: inodes_used.map(|_| 1000),
+        inodes_used,
+        inodes_free: inodes_used.map(|u| 1000 - u),
+    }
+}
+
+fn metrics(filesystems: Vec<FilesystemMetrics>) -> ServerMetrics {
+    let mut metrics = ServerMetrics::new(10.0, 10.0, 10.0, 0, 0, Vec::new());
+    metrics.filesystems = filesystems;
+    metrics
+}
+
+#[test]
+fn test_alerts_per_mount_point() {
+    let evaluator = AlertEvaluator::new(AlertThresholds::default());
+
+    // 평균은 50% 지만 /var 는 95%
+    let alerts = evaluator.evaluate("srv-1", &metrics(vec![
+        filesystem("/", 5, None),
+        filesystem("/var", 95, None),
+    ]));
+
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_type, "disk_usage");
+    assert_eq!(alerts[0].severity, AlertSeverity::Critical);
+    assert!(alerts[0].message.contains("/var"));
+}
+
+#[test]
+fn test_alerts_are_not_repeated_until_escalated_or_cleared() {
+    let evaluator = AlertEvaluator::new(AlertThresholds::default());
+
+    let warning = metrics(vec![filesystem("/data", 85, None)]);
+    assert_eq!(evaluator.evaluate("srv-1", &warning).len(), 1);
+    assert!(evaluator.evaluate("srv-1", &warning).is_empty());
+
+    // 심각도가 올라가면 다시 알림
+    let critical = evaluator.evaluate("srv-1", &metrics(vec![filesystem("/data", 92, None)]));
+    assert_eq!(critical.len(), 1);
+    assert_eq!(critical[0].severity, AlertSeverity::Critical);
+
+    // 내려가는 것만으로는 알리지 않는다
+    assert!(evaluator.evaluate("srv-1", &warning).is_empty());
+
+    // 해소 후 재발하면 다시 알림
+    assert!(evaluator.evaluate("srv-1", &metrics(vec![filesystem("/data", 50, None)])).is_empty());
+    assert_eq!(evaluator.evaluate("srv-1", &warning).len(), 1);
+
+    // 다른 서버의 상태와는 독립적
+    assert_eq!(evaluator.evaluate("srv-2", &warning).len(), 1);
+}
+
+#[test]
+fn test_inode_alerts() {
+    let evaluator = AlertEvaluator::new(AlertThresholds::default());
+
+    let alerts = evaluator.evaluate("srv-1", &metrics(vec![filesystem("/", 10, Some(950))]));
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_type, "inode_usage");
+    assert_eq!(alerts[0].severity, AlertSeverity::Critical);
+
+    // inode 정보가 없는 파일시스템은 건너뛴다
+    let alerts = evaluator.evaluate("srv-1", &metrics(vec![filesystem("/data", 10, None)]));
+    assert!(alerts.is_empty());
+}
+
+#[test]
+fn test_disk_io_alerts() {
+    let evaluator = AlertEvaluator::new(AlertThresholds::default());
+
+    let mut saturated = metrics(vec![]);
+    saturated.disk_io = vec![DiskIoMetrics {
+        device: "sdb".to_string(),
+        utilization: 99.0,
+        await_ms: 80.0,
+        ..Default::default()
+    }];
+
+    let mut alerts = evaluator.evaluate("srv-1", &saturated);
+    alerts.sort_by(|a, b| a.alert_type.cmp(&b.alert_type));
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[0].alert_type, "disk_await");
+    assert_eq!(alerts[0].severity, AlertSeverity::Warning);
+    assert!(alerts[0].message.contains("80.0ms"));
+    assert_eq!(alerts[1].alert_type, "disk_io_util");
+    assert_eq!(alerts[1].severity, AlertSeverity::Critical);
+    assert!(alerts[1].message.contains("sdb"));
+}
+
+#[test]
+fn test_swap_alerts_are_separate_from_memory() {
+    let evaluator = AlertEvaluator::new(AlertThresholds::default());
+
+    // 메모리는 여유가 있지만 스왑을 많이 쓰는 경우
+    let mut swapping = metrics(vec![]);
+    swapping.memory = Some(MemoryMetrics {
+        total: 100,
+        used: 40,
+        available: 60,
+        swap_total: 100,
+        swap_used: 85,
+        ..Default::default()
+    });
+
+    let alerts = evaluator.evaluate("srv-1", &swapping);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_type, "swap_usage");
+    assert_eq!(alerts[0].severity, AlertSeverity::Critical);
+
+    // 메모리 사용량만 높은 경우
+    let mut busy = metrics(vec![]);
+    busy.memory_usage = 85.0;
+    busy.memory = Some(MemoryMetrics { total: 100, used: 85, available: 15, ..Default::default() });
+
+    let alerts = evaluator.evaluate("srv-2", &busy);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_type, "memory_usage");
+    assert_eq!(alerts[0].severity, AlertSeverity::Warning);
+}
+
+#[test]
+fn test_clear_resets_state() {
+    let evaluator = AlertEvaluator::new(AlertThresholds::default());
+    let full = metrics(vec![filesystem("/", 95, None)]);
+
+    assert_eq!(evaluator.evaluate("srv-1", &full).len(), 1);
+    evaluator.clear("srv-1");
+    assert_eq!(evaluator.evaluate("srv-1", &full).len(), 1);
+}
+
+#[test]
+fn test_security_update_alerts() {
+    let evaluator = AlertEvaluator::new(AlertThresholds::default());
+
+    assert!(evaluator.evaluate_security_updates("srv-1", 0).is_empty());
+
+    let alerts = evaluator.evaluate_security_updates("srv-1", 3);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_type, "security_updates");
+    assert_eq!(alerts[0].severity, AlertSeverity::Warning);
+    assert_eq!(alerts[0].message, "Pending security updates is 3 (threshold 1)");
+
+    // 같은 단계에서는 다시 알리지 않고, 위험 단계로 올라가면 알린다
+    assert!(evaluator.evaluate_security_updates("srv-1", 5).is_empty());
+    let alerts = evaluator.evaluate_security_updates("srv-1", 12);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].severity, AlertSeverity::Critical);
+
+    // 메트릭 평가와 상태가 섞이지 않는다
+    assert!(evaluator.evaluate("srv-1", &metrics(vec![])).is_empty());
+    assert!(evaluator.evaluate_security_updates("srv-1", 12).is_empty());
+}
@@ -1,5 +1,6 @@
 // tests/monitoring/collector.rs
-use rust_server::monitoring::collector::MetricsCollector;
+use rust_server::monitoring::collector::{LocalSource, MetricsCollector};
+use rust_server::monitoring::MetricSource;
 use tokio::time::{sleep, Duration};
 
 #[tokio::test]
@@ -23,8 +24,6 @@ async fn test_metrics_collection() {
     assert!(metrics.cpu_usage >= 0.0 && metrics.cpu_usage <= 100.0);
     assert!(metrics.memory_usage >= 0.0 && metrics.memory_usage <= 100.0);
     assert!(metrics.disk_usage >= 0.0 && metrics.disk_usage <= 100.0);
-    assert!(metrics.network_rx >= 0);
-    assert!(metrics.network_tx >= 0);
     assert!(!metrics.processes.is_empty());
 }
 
@@ -41,11 +40,12 @@ async fn test_process_collection() {
     assert!(!processes.is_empty());
     assert!(processes.len() <= 10); // Should only collect top 10 processes
 
+    // 커널 스레드는 RSS 가 0 이므로 모든 프로세스가 아니라 하나 이상만 본다
+    assert!(processes.iter().any(|process| process.memory_usage > 0));
     for process in processes {
         assert!(process.pid > 0);
         assert!(!process.name.is_empty());
         assert!(process.cpu_usage >= 0.0);
-        assert!(process.memory_usage > 0);
     }
 }
 
@@ -56,16 +56,63 @@ async fn test_metrics_updates() {
 
     // Get initial metrics
     sleep(Duration::from_secs(1)).await;
-    let initial_metrics = collector.get_current_metrics().await.unwrap();
+    assert!(collector.get_current_metrics().await.is_some());
+
+    // 한가한 호스트에서는 값이 그대로일 수 있으므로 루프백 트래픽과 CPU 부하를 만든다
+    let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let addr = socket.local_addr().unwrap();
+    let load = std::thread::spawn(move || {
+        let started = std::time::Instant::now();
+        while started.elapsed() < std::time::Duration::from_millis(2500) {
+            socket.send_to(&[0u8; 1024], addr).unwrap();
+            let mut buf = [0u8; 1024];
+            socket.recv_from(&mut buf).unwrap();
+        }
+    });
 
     // Wait for update
     sleep(Duration::from_secs(2)).await;
     let updated_metrics = collector.get_current_metrics().await.unwrap();
+    load.join().unwrap();
 
-    // Metrics should be different after update
-    assert_ne!(initial_metrics.cpu_usage, updated_metrics.cpu_usage);
-    assert_ne!(initial_metrics.network_rx, updated_metrics.network_rx);
-    assert_ne!(initial_metrics.network_tx, updated_metrics.network_tx);
+    assert!(updated_metrics.cpu_usage > 0.0);
+    assert!(updated_metrics.network_rx > 0);
+    assert!(updated_metrics.network_tx > 0);
+}
+
+#[tokio::test]
+async fn test_local_source_collection() {
+    let source = LocalSource::new().with_process_limit(5);
+
+    // 처리량은 이전 수집과의 차이라 첫 수집에는 없다
+    let initial = source.collect().await.unwrap();
+    assert!(initial.network_interfaces.is_empty());
+    assert_eq!((initial.network_rx, initial.network_tx), (0, 0));
+
+    sleep(Duration::from_millis(200)).await;
+    let updated = source.collect().await.unwrap();
+    assert!(updated.timestamp > initial.timestamp);
+    assert!(updated.cpu_usage >= 0.0 && updated.cpu_usage <= 100.0);
+    assert!(updated.memory_usage >= 0.0 && updated.memory_usage <= 100.0);
+    assert!(updated.memory.unwrap().total > 0);
+    assert!(!updated.processes.is_empty());
+    // CPU 상위와 메모리 상위의 합집합
+    assert!(updated.processes.len() <= 10);
+}
+
+#[tokio::test]
+async fn test_local_source_process_table() {
+    let source = LocalSource::new();
+    source.collect().await.unwrap();
+
+    // 온디맨드 조회는 별도 System 을 쓰므로 주기 수집과 섞이지 않는다
+    let processes = source.processes().await.unwrap();
+    let own = processes.iter().find(|p| p.pid == std::process::id()).unwrap();
+    assert!(own.cpu_usage >= 0.0);
+    assert!(own.memory_usage > 0);
+
+    let updated = source.collect().await.unwrap();
+    assert!(!updated.processes.is_empty());
 }
 
 #[tokio::test]
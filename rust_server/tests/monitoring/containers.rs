@@ -0,0 +1,64 @@
+// tests/monitoring/containers.rs
+use rust_server::monitoring::containers::{parse_container_output, ContainerCollector};
+
+const ENGINE: &str = include_str!("../fixtures/containers_engine.txt");
+const CGROUP: &str = include_str!("../fixtures/containers_cgroup.txt");
+
+#[test]
+fn test_parse_engine_output() {
+    let (uptime, samples) = parse_container_output(ENGINE).unwrap();
+    assert_eq!(uptime, 350735.47);
+    assert_eq!(samples.len(), 2);
+
+    let web = &samples[0];
+    assert_eq!(web.name, "web");
+    assert_eq!(web.image.as_deref(), Some("nginx:1.25"));
+    assert_eq!(web.restart_count, Some(2));
+    assert_eq!(web.cpu_usec, 120_000_000);
+    // inactive_file 은 사용량에서 제외
+    assert_eq!(web.memory_usage, 104857600);
+    assert_eq!(web.memory_limit, Some(536870912));
+    assert_eq!(web.network, Some((1024000, 501000)));
+
+    let migrate = &samples[1];
+    assert_eq!(migrate.state, "exited");
+    assert_eq!(migrate.memory_usage, 0);
+    assert_eq!(migrate.network, None);
+}
+
+#[test]
+fn test_parse_cgroup_output() {
+    let (_, samples) = parse_container_output(CGROUP).unwrap();
+    assert_eq!(samples.len(), 2);
+
+    assert_eq!(samples[0].id, "3f4e5d6c7b8a9f0e1d2c3b4a5f6e7d8c9b0a1f2e3d4c5b6a7f8e9d0c1b2a3f4e");
+    assert_eq!(samples[0].name, "3f4e5d6c7b8a");
+    assert_eq!(samples[0].network, Some((1024000, 501000)));
+    assert_eq!(samples[0].restart_count, None);
+
+    // memory.max 가 "max" 면 제한 없음, 네트워크를 읽지 못하면 None
+    assert_eq!(samples[1].memory_limit, None);
+    assert_eq!(samples[1].network, None);
+
+    assert!(parse_container_output("12.0 3.0").is_err());
+}
+
+#[test]
+fn test_container_rates_since_previous_sample() {
+    let collector = ContainerCollector::new();
+    let first = collector.parse_output(CGROUP).unwrap();
+    assert_eq!(first[0].cpu_usage, 0.0);
+    assert_eq!(first[0].network_rx, None);
+
+    // 10초 동안 CPU 5초(50%), 수신 1MB
+    let next = CGROUP
+        .replace("350735.47", "350745.47")
+        .replace(" 120000000 ", " 125000000 ")
+        .replace(" 1024000 ", " 2072576 ");
+    let metrics = collector.parse_output(&next).unwrap();
+    assert!((metrics[0].cpu_usage - 50.0).abs() < 0.01);
+    assert!((metrics[0].network_rx.unwrap() - 104857.6).abs() < 0.01);
+    assert_eq!(metrics[0].network_tx, Some(0.0));
+    assert_eq!(metrics[1].cpu_usage, 0.0);
+    assert_eq!(metrics[1].network_rx, None);
+}
@@ -0,0 +1,74 @@
+// tests/monitoring/inventory.rs
+use rust_server::models::agent::{HostFacts, InterfaceFacts};
+use rust_server::monitoring::inventory::{parse_facts_output, parse_os_release};
+
+const FACTS: &str = include_str!("../fixtures/host_facts.txt");
+
+#[test]
+fn test_parse_facts_output() {
+    let facts = parse_facts_output(FACTS).unwrap();
+    assert_eq!(facts.hostname, "web-01");
+    assert_eq!(facts.cpu_model.as_deref(), Some("Intel(R) Xeon(R) Gold 6230R CPU @ 2.10GHz"));
+    assert_eq!(facts.cpu_cores, Some(4));
+    assert_eq!(facts.total_memory, Some(8131604 * 1024));
+    assert_eq!(facts.os_name.as_deref(), Some("Ubuntu"));
+    assert_eq!(facts.os_version.as_deref(), Some("22.04"));
+    assert_eq!(facts.kernel_version.as_deref(), Some("5.15.0-105-generic"));
+    assert_eq!(facts.architecture.as_deref(), Some("x86_64"));
+    assert_eq!(facts.virtualization.as_deref(), Some("kvm"));
+
+    // loopback 은 제외하고 이름순
+    let names: Vec<&str> = facts.interfaces.iter().map(|i| i.name.as_str()).collect();
+    assert_eq!(names, vec!["docker0", "eth0"]);
+    let eth0 = &facts.interfaces[1];
+    assert_eq!(eth0.mac_address.as_deref(), Some("52:54:00:ab:cd:ef"));
+    assert_eq!(eth0.addresses, vec!["10.0.1.15/24", "fe80::5054:ff:feab:cdef/64"]);
+
+    // docker0 같은 브리지보다 물리 인터페이스 주소를 쓴다
+    assert_eq!(facts.primary_ip().as_deref(), Some("10.0.1.15"));
+}
+
+#[test]
+fn test_parse_facts_output_rejects_truncated_output() {
+    assert!(parse_facts_output("web-01\n__FLICK_FACTS__\n").is_err());
+}
+
+#[test]
+fn test_parse_os_release_falls_back_to_version() {
+    let (name, version) = parse_os_release("NAME='Arch Linux'\nVERSION=\"rolling\"\n");
+    assert_eq!(name.as_deref(), Some("Arch Linux"));
+    assert_eq!(version.as_deref(), Some("rolling"));
+}
+
+#[test]
+fn test_primary_ip_prefers_reported_address() {
+    let mut facts = HostFacts {
+        hostname: "host".to_string(),
+        interfaces: vec![InterfaceFacts {
+            name: "eth0".to_string(),
+            mac_address: None,
+            addresses: vec!["fe80::1/64".to_string(), "2001:db8::5/64".to_string()],
+        }],
+        ..Default::default()
+    };
+    // link-local 은 건너뛰고 IPv4 가 없으면 IPv6
+    assert_eq!(facts.primary_ip().as_deref(), Some("2001:db8::5"));
+
+    facts.ip_address = Some("192.0.2.10".to_string());
+    assert_eq!(facts.primary_ip().as_deref(), Some("192.0.2.10"));
+}
+
+#[test]
+fn test_changed_fields() {
+    let previous = parse_facts_output(FACTS).unwrap();
+    assert!(previous.changed_fields(Some(&previous)).is_empty());
+
+    let mut current = previous.clone();
+    current.kernel_version = Some("5.15.0-107-generic".to_string());
+    current.total_memory = Some(16 * 1024 * 1024 * 1024);
+    assert_eq!(current.changed_fields(Some(&previous)), vec!["kernel_version", "total_memory"]);
+
+    // 이전 값이 없으면 값이 있는 항목 전부
+    let first = HostFacts { hostname: "host".to_string(), cpu_cores: Some(2), ..Default::default() };
+    assert_eq!(first.changed_fields(None), vec!["cpu_cores", "hostname"]);
+}
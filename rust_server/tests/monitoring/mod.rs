@@ -1 +1,11 @@
-pub mod collector;
\ No newline at end of file
+pub mod collector;
+pub mod agent;
+pub mod alerts;
+pub mod parsers;
+pub mod ssh;
+pub mod watchlist;
+pub mod systemd;
+pub mod containers;
+pub mod inventory;
+pub mod packages;
+pub mod ports;
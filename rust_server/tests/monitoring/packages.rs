@@ -0,0 +1,63 @@
+// tests/monitoring/packages.rs
+use std::cmp::Ordering;
+use rust_server::models::package::compare_versions;
+use rust_server::monitoring::packages::parse_package_output;
+
+const DPKG: &str = include_str!("../fixtures/packages_dpkg.txt");
+const RPM: &str = include_str!("../fixtures/packages_rpm.txt");
+
+#[test]
+fn test_parse_dpkg_output() {
+    let inventory = parse_package_output(DPKG).unwrap();
+    assert_eq!(inventory.manager, "dpkg");
+
+    // 설정 파일만 남은(rc) 패키지는 제외
+    let names: Vec<&str> = inventory.packages.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["adduser", "libssl3", "openssl", "tzdata"]);
+    assert_eq!(inventory.packages[2].version, "3.0.2-0ubuntu1.14");
+    assert_eq!(inventory.packages[2].architecture, "amd64");
+
+    assert_eq!(inventory.updates.len(), 3);
+    let openssl = &inventory.updates[1];
+    assert_eq!(openssl.name, "openssl");
+    assert_eq!(openssl.current_version.as_deref(), Some("3.0.2-0ubuntu1.14"));
+    assert_eq!(openssl.available_version, "3.0.2-0ubuntu1.15");
+    assert!(openssl.security);
+    assert!(!inventory.updates[2].security);
+    assert_eq!(inventory.security_update_count(), 2);
+}
+
+#[test]
+fn test_parse_rpm_output() {
+    let inventory = parse_package_output(RPM).unwrap();
+    assert_eq!(inventory.manager, "rpm");
+
+    // gpg-pubkey 는 패키지가 아니다
+    assert_eq!(inventory.packages.len(), 3);
+    assert_eq!(inventory.packages[1].version, "1:3.0.7-24.el9");
+
+    // Obsoleting 이후는 무시
+    assert_eq!(inventory.updates.len(), 2);
+    let openssl = &inventory.updates[0];
+    assert_eq!(openssl.current_version.as_deref(), Some("1:3.0.7-24.el9"));
+    assert_eq!(openssl.available_version, "1:3.0.7-25.el9_3");
+    assert!(openssl.security);
+    assert!(!inventory.updates[1].security);
+}
+
+#[test]
+fn test_parse_package_output_without_manager() {
+    assert!(parse_package_output("none\n").is_err());
+}
+
+#[test]
+fn test_compare_versions() {
+    assert_eq!(compare_versions("3.0.2-0ubuntu1.14", "3.0.2-0ubuntu1.15"), Ordering::Less);
+    assert_eq!(compare_versions("3.0.10", "3.0.9"), Ordering::Greater);
+    assert_eq!(compare_versions("1.0", "1.0"), Ordering::Equal);
+    assert_eq!(compare_versions("1:1.0", "2.0"), Ordering::Greater);
+    // '~' 는 정식 버전보다 앞선다
+    assert_eq!(compare_versions("1.0~rc1", "1.0"), Ordering::Less);
+    assert_eq!(compare_versions("1.0a", "1.0"), Ordering::Greater);
+    assert_eq!(compare_versions("1:3.0.7-24.el9", "1:3.0.7-25.el9_3"), Ordering::Less);
+}
@@ -0,0 +1,422 @@
+// tests/monitoring/parsers.rs
+use rust_server::config::{DiskIoConfig, FilesystemConfig, NetworkConfig};
+use rust_server::models::metrics::ProcessMetrics;
+use rust_server::monitoring::parsers::*;
+
+const PROC_STAT: &str = include_str!("../fixtures/proc_stat.txt");
+const MEMINFO: &str = include_str!("../fixtures/meminfo.txt");
+const NET_DEV: &str = include_str!("../fixtures/net_dev.txt");
+const DF: &str = include_str!("../fixtures/df.txt");
+const PS: &str = include_str!("../fixtures/ps.txt");
+const LOADAVG: &str = include_str!("../fixtures/loadavg.txt");
+const DF_TYPED: &str = include_str!("../fixtures/df_typed.txt");
+const DF_INODES: &str = include_str!("../fixtures/df_inodes.txt");
+const UPTIME: &str = include_str!("../fixtures/uptime.txt");
+const DISKSTATS: &str = include_str!("../fixtures/diskstats.txt");
+const PS_DETAIL: &str = include_str!("../fixtures/ps_detail.txt");
+const PS_NAMES: &str = include_str!("../fixtures/ps_names.txt");
+const FD_COUNTS: &str = include_str!("../fixtures/fd_counts.txt");
+const OS_INFO: &str = include_str!("../fixtures/os_info.txt");
+const SYSTEMCTL_SHOW: &str = include_str!("../fixtures/systemctl_show.txt");
+
+#[test]
+fn test_parse_proc_stat() {
+    let stat = parse_proc_stat(PROC_STAT).unwrap();
+
+    assert_eq!(stat.total.user, 4705);
+    assert_eq!(stat.total.iowait, 520);
+    assert_eq!(stat.total.steal, 30);
+    assert_eq!(stat.cpus.len(), 2);
+    assert_eq!(stat.cpus[1].idle, 8130);
+}
+
+#[test]
+fn test_cpu_usage_since_previous_sample() {
+    let prev = parse_proc_stat(PROC_STAT).unwrap().total;
+    let mut current = prev;
+    current.user += 30;
+    current.system += 10;
+    current.idle += 50;
+    current.iowait += 10;
+
+    // 전체 100 jiffies 중 idle + iowait 60
+    let usage = current.usage_since(&prev);
+    assert!((usage - 40.0).abs() < 0.01);
+
+    // 카운터 변화가 없으면 0
+    assert_eq!(prev.usage_since(&prev), 0.0);
+}
+
+#[test]
+fn test_cpu_breakdown_since_previous_sample() {
+    let prev = parse_proc_stat(PROC_STAT).unwrap().total;
+    let mut current = prev;
+    current.user += 20;
+    current.nice += 10;
+    current.system += 5;
+    current.softirq += 5;
+    current.idle += 40;
+    current.iowait += 15;
+    current.steal += 5;
+
+    let breakdown = current.breakdown_since(&prev);
+    assert!((breakdown.user - 30.0).abs() < 0.01);
+    assert!((breakdown.system - 10.0).abs() < 0.01);
+    assert!((breakdown.iowait - 15.0).abs() < 0.01);
+    assert!((breakdown.steal - 5.0).abs() < 0.01);
+
+    assert_eq!(prev.breakdown_since(&prev).iowait, 0.0);
+}
+
+#[test]
+fn test_per_core_usage_since_previous_sample() {
+    let prev = parse_proc_stat(PROC_STAT).unwrap();
+    let mut current = prev.clone();
+    current.cpus[0].user += 50;
+    current.cpus[0].idle += 50;
+    current.cpus[1].idle += 100;
+
+    let usage = current.per_core_usage_since(&prev);
+    assert_eq!(usage.len(), 2);
+    assert!((usage[0] - 50.0).abs() < 0.01);
+    assert_eq!(usage[1], 0.0);
+
+    // 이전 샘플이 없으면 부팅 이후 누적값 기준
+    let usage = current.per_core_usage_since(&ProcStat::default());
+    assert!(usage.iter().all(|u| *u > 0.0 && *u <= 100.0));
+}
+
+#[test]
+fn test_parse_loadavg() {
+    let load = parse_loadavg(LOADAVG).unwrap();
+    assert_eq!(load.one, 0.52);
+    assert_eq!(load.five, 0.58);
+    assert_eq!(load.fifteen, 0.59);
+
+    assert!(parse_loadavg("0.52 0.58").is_err());
+    assert!(parse_loadavg("abc 0.58 0.59").is_err());
+}
+
+#[test]
+fn test_parse_proc_stat_invalid() {
+    assert!(parse_proc_stat("intr 1 2 3").is_err());
+    assert!(parse_proc_stat("cpu a b c d").is_err());
+}
+
+#[test]
+fn test_parse_meminfo() {
+    let info = parse_meminfo(MEMINFO).unwrap();
+
+    assert_eq!(info.total, 8048288 * 1024);
+    assert_eq!(info.available, Some(4024144 * 1024));
+    assert_eq!(info.swap_free, 1048574 * 1024);
+    assert!((info.usage_percent() - 50.0).abs() < 0.01);
+}
+
+#[test]
+fn test_meminfo_metrics() {
+    let memory = parse_meminfo(MEMINFO).unwrap().metrics();
+
+    assert_eq!(memory.total, 8048288 * 1024);
+    assert_eq!(memory.available, 4024144 * 1024);
+    assert_eq!(memory.used, (8048288 - 4024144) * 1024);
+    assert_eq!(memory.cached, 3072000 * 1024);
+    assert_eq!(memory.buffers, 204800 * 1024);
+    assert_eq!(memory.swap_total, 2097148 * 1024);
+    assert_eq!(memory.swap_used, (2097148 - 1048574) * 1024);
+    assert!((memory.swap_usage_percent().unwrap() - 50.0).abs() < 0.01);
+
+    // 스왑이 없는 호스트
+    let memory = parse_meminfo("MemTotal: 1000 kB\nMemAvailable: 400 kB\n").unwrap().metrics();
+    assert_eq!(memory.used, 600 * 1024);
+    assert!(memory.swap_usage_percent().is_none());
+}
+
+#[test]
+fn test_meminfo_without_mem_available() {
+    let input = "MemTotal: 1000 kB\nMemFree: 200 kB\nBuffers: 100 kB\nCached: 200 kB\n";
+    let info = parse_meminfo(input).unwrap();
+
+    assert_eq!(info.available, None);
+    assert_eq!(info.used(), 500 * 1024);
+}
+
+#[test]
+fn test_parse_net_dev() {
+    let interfaces = parse_net_dev(NET_DEV).unwrap();
+
+    assert_eq!(interfaces.len(), 2);
+    assert_eq!(interfaces[0].interface, "lo");
+
+    let eth0 = &interfaces[1];
+    assert_eq!(eth0.interface, "eth0");
+    assert_eq!(eth0.rx_bytes, 98765432);
+    assert_eq!(eth0.rx_errors, 2);
+    assert_eq!(eth0.tx_bytes, 12345678);
+    assert_eq!(eth0.tx_drops, 3);
+}
+
+#[test]
+fn test_counter_delta_handles_wrap_and_reset() {
+    assert_eq!(counter_delta(100, 250), 150);
+    // 32비트 카운터가 한 바퀴 돈 경우
+    assert_eq!(counter_delta(u32::MAX as u64 - 9, 20), 30);
+    // 64비트 카운터가 줄어들면 재설정으로 본다
+    assert_eq!(counter_delta(u32::MAX as u64 + 100, 20), 0);
+    // 이전 값이 작으면 32비트 범위라도 한 바퀴가 아니라 재설정
+    assert_eq!(counter_delta(5_000, 20), 0);
+    assert_eq!(counter_delta(1 << 30, 20), 0);
+}
+
+#[test]
+fn test_interface_rates() {
+    let prev = parse_net_dev(NET_DEV).unwrap();
+    let mut current = prev.clone();
+    current[1].rx_bytes += 5000;
+    current[1].tx_bytes += 1000;
+    current[1].rx_packets += 20;
+    current[1].tx_errors += 2;
+    current.push(NetDevStats { interface: "wlan0".to_string(), rx_bytes: 1, ..Default::default() });
+
+    // lo 는 기본 설정에서 제외, 이전 샘플에 없던 wlan0 은 건너뜀
+    let rates = interface_rates(&prev, &current, 5.0, &NetworkConfig::default());
+    assert_eq!(rates.len(), 1);
+    assert_eq!(rates[0].interface, "eth0");
+    assert_eq!(rates[0].rx_bytes_per_sec, 1000.0);
+    assert_eq!(rates[0].tx_bytes_per_sec, 200.0);
+    assert_eq!(rates[0].rx_packets_per_sec, 4.0);
+    assert_eq!(rates[0].tx_errors, 2);
+    assert_eq!(total_rates(&rates), (1000, 200));
+
+    let include_all = NetworkConfig { exclude_loopback: false, exclude_interface_prefixes: vec![] };
+    assert_eq!(interface_rates(&prev, &current, 5.0, &include_all).len(), 2);
+
+    assert!(interface_rates(&prev, &current, 0.0, &include_all).is_empty());
+}
+
+#[test]
+fn test_network_config_excludes_virtual_interfaces() {
+    let config = NetworkConfig::default();
+    assert!(config.excludes("lo"));
+    assert!(config.excludes("veth1a2b3c"));
+    assert!(config.excludes("docker0"));
+    assert!(config.excludes("br-4f2e1a"));
+    assert!(!config.excludes("eth0"));
+    assert!(!config.excludes("ens3"));
+}
+
+#[test]
+fn test_parse_diskstats() {
+    let disks = parse_diskstats(DISKSTATS).unwrap();
+
+    assert_eq!(disks.len(), 7);
+    let sda = &disks[1];
+    assert_eq!(sda.device, "sda");
+    assert_eq!(sda.reads_completed, 120000);
+    assert_eq!(sda.sectors_read, 9600000);
+    assert_eq!(sda.write_time_ms, 120000);
+    assert_eq!(sda.io_time_ms, 90000);
+
+    assert!(parse_diskstats("8 0 sda 1 2 3").is_err());
+}
+
+#[test]
+fn test_disk_io_rates() {
+    let prev = parse_diskstats(DISKSTATS).unwrap();
+    let mut current = prev.clone();
+    current[1].reads_completed += 100;
+    current[1].sectors_read += 2048;
+    current[1].read_time_ms += 500;
+    current[1].writes_completed += 100;
+    current[1].write_time_ms += 500;
+    current[1].io_time_ms += 1000;
+
+    // loop 장치와 파티션은 제외, dm-10 은 dm-1 의 파티션이 아니다
+    let rates = disk_io_rates(&prev, &current, 2.0, &DiskIoConfig::default());
+    let devices: Vec<&str> = rates.iter().map(|d| d.device.as_str()).collect();
+    assert_eq!(devices, vec!["sda", "nvme0n1", "dm-0", "dm-10"]);
+
+    let sda = &rates[0];
+    assert_eq!(sda.read_bytes_per_sec, 524288.0);
+    assert_eq!(sda.write_bytes_per_sec, 0.0);
+    assert_eq!(sda.read_iops, 50.0);
+    assert_eq!(sda.write_iops, 50.0);
+    assert_eq!(sda.await_ms, 5.0);
+    assert_eq!(sda.utilization, 50.0);
+
+    // 변화가 없는 장치
+    assert_eq!(rates[1].await_ms, 0.0);
+    assert_eq!(rates[1].utilization, 0.0);
+
+    // 사용률은 100% 를 넘지 않는다
+    current[1].io_time_ms += 10000;
+    assert_eq!(disk_io_rates(&prev, &current, 2.0, &DiskIoConfig::default())[0].utilization, 100.0);
+}
+
+#[test]
+fn test_parse_uptime() {
+    assert_eq!(parse_uptime(UPTIME).unwrap(), 350735.47);
+    assert!(parse_uptime("").is_err());
+}
+
+#[test]
+fn test_parse_df() {
+    let entries = parse_df(DF).unwrap();
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].filesystem, "/dev/sda1");
+    assert_eq!(entries[0].total, 51475068 * 1024);
+    assert_eq!(entries[0].mount_point, "/");
+    assert_eq!(entries[1].mount_point, "/var/lib/my data");
+
+    assert!(entries[0].fs_type.is_none());
+
+    let usage = disk_usage_percent(&entries);
+    assert!((usage - 19.99).abs() < 0.01);
+}
+
+#[test]
+fn test_parse_df_with_types_and_inodes() {
+    let entries = parse_df(DF_TYPED).unwrap();
+    assert_eq!(entries.len(), 6);
+    assert_eq!(entries[0].fs_type.as_deref(), Some("ext4"));
+    assert_eq!(entries[2].mount_point, "/var/lib/my data");
+
+    let inodes = parse_df_inodes(DF_INODES).unwrap();
+    assert_eq!(inodes.len(), 6);
+    assert_eq!(inodes[0].used, Some(3000000));
+    assert_eq!(inodes[2].mount_point, "/var/lib/my data");
+}
+
+#[test]
+fn test_build_filesystems_filters_and_dedups() {
+    let entries = parse_df(DF_TYPED).unwrap();
+    let inodes = parse_df_inodes(DF_INODES).unwrap();
+    let filesystems = build_filesystems(&entries, &inodes, &FilesystemConfig::default());
+
+    // tmpfs, overlay, snap 은 제외되고 bind mount 는 한 번만
+    let mounts: Vec<&str> = filesystems.iter().map(|f| f.mount_point.as_str()).collect();
+    assert_eq!(mounts, vec!["/", "/var/lib/my data"]);
+
+    let root = &filesystems[0];
+    assert_eq!(root.device, "/dev/sda1");
+    assert_eq!(root.fs_type, "ext4");
+    assert!((root.usage_percent() - 42.16).abs() < 0.01);
+    assert!((root.inode_usage_percent().unwrap() - 91.55).abs() < 0.01);
+
+    // xfs 처럼 inode 수가 0 으로 보고되면 정보 없음
+    assert!(filesystems[1].inode_usage_percent().is_none());
+
+    let usage = filesystems_usage_percent(&filesystems);
+    assert!((usage - 19.99).abs() < 0.01);
+}
+
+#[test]
+fn test_build_filesystems_custom_exclusions() {
+    let entries = parse_df(DF_TYPED).unwrap();
+    let config = FilesystemConfig {
+        exclude_types: vec!["xfs".to_string()],
+        exclude_mount_prefixes: vec![],
+    };
+    let filesystems = build_filesystems(&entries, &[], &config);
+
+    let mounts: Vec<&str> = filesystems.iter().map(|f| f.mount_point.as_str()).collect();
+    assert_eq!(mounts, vec!["/", "/dev/shm", "/var/lib/docker/overlay2/abc/merged", "/snap/core18/2128"]);
+    assert!(filesystems[0].inodes_total.is_none());
+}
+
+#[test]
+fn test_parse_ps() {
+    let processes = parse_ps(PS).unwrap();
+
+    assert_eq!(processes.len(), 3);
+    assert_eq!(processes[0].pid, 1234);
+    assert_eq!(processes[0].name, "postgres");
+    assert_eq!(processes[0].cpu_usage, 35.5);
+    assert_eq!(processes[0].memory_usage, 204800 * 1024);
+    assert_eq!(processes[1].name, "nginx: worker");
+}
+
+#[test]
+fn test_snapshot_processes_keeps_memory_heavy_idle_process() {
+    let processes = vec![
+        ProcessMetrics::new(10, "worker".to_string(), 80.0, 100 * 1024),
+        ProcessMetrics::new(20, "nginx".to_string(), 20.0, 50 * 1024),
+        ProcessMetrics::new(30, "bash".to_string(), 5.0, 10 * 1024),
+        ProcessMetrics::new(40, "java".to_string(), 0.0, 8 * 1024 * 1024 * 1024),
+        ProcessMetrics::new(20, "nginx".to_string(), 20.0, 50 * 1024),
+    ];
+    let snapshot = snapshot_processes(processes, 2);
+
+    // CPU 상위 2개 + CPU 0% 이지만 메모리가 가장 큰 java, 중복 pid 는 한 번만
+    let pids: Vec<u32> = snapshot.iter().map(|p| p.pid).collect();
+    assert_eq!(pids, vec![10, 20, 40]);
+    assert_eq!(snapshot[2].cpu_usage, 0.0);
+}
+
+#[test]
+fn test_parse_ps_detail() {
+    let now = chrono::Utc::now();
+    let names = parse_ps_names(PS_NAMES);
+    let processes = parse_ps_detail(PS_DETAIL, &names, now).unwrap();
+
+    assert_eq!(processes.len(), 4);
+    let postgres = &processes[1];
+    assert_eq!(postgres.pid, 1234);
+    assert_eq!(postgres.ppid, Some(1));
+    assert_eq!(postgres.name, "postgres");
+    assert_eq!(postgres.user.as_deref(), Some("postgres"));
+    assert_eq!(postgres.command, "postgres: checkpointer");
+    assert_eq!(postgres.state, "sleeping");
+    assert_eq!(postgres.threads, Some(8));
+    assert_eq!(postgres.start_time, Some(now - chrono::Duration::seconds(3600)));
+    assert_eq!(postgres.cpu_usage, 35.5);
+    assert_eq!(postgres.memory_usage, 204800 * 1024);
+
+    assert_eq!(processes[0].name, "systemd");
+    assert_eq!(processes[3].state, "idle");
+
+    // comm 결과가 없으면 실행 파일 이름으로 대신
+    let processes = parse_ps_detail(PS_DETAIL, &Default::default(), now).unwrap();
+    assert_eq!(processes[0].name, "init");
+
+    assert!(parse_ps_detail("1 0 root Ss", &names, now).is_err());
+}
+
+#[test]
+fn test_parse_fd_counts() {
+    let counts = parse_fd_counts(FD_COUNTS);
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&1234], 45);
+}
+
+#[test]
+fn test_parse_systemctl_show() {
+    let now = chrono::Utc::now();
+    let uptime = parse_uptime(UPTIME).unwrap();
+    let services = parse_systemctl_show(SYSTEMCTL_SHOW, uptime, now).unwrap();
+    assert_eq!(services.len(), 3);
+
+    let postgres = &services[1];
+    assert_eq!(postgres.unit, "postgresql.service");
+    assert!(postgres.is_failed());
+    assert_eq!(postgres.restarts, Some(3));
+    assert_eq!(postgres.since, Some(now - chrono::Duration::seconds(35)));
+
+    // 한 번도 상태가 바뀌지 않은 유닛, NRestarts 가 없는 systemd
+    assert_eq!(services[2].load_state, "not-found");
+    assert_eq!(services[2].since, None);
+    assert_eq!(services[2].restarts, None);
+
+    assert!(parse_systemctl_show("Id=nginx.service\nLoadState=loaded", uptime, now).is_err());
+}
+
+#[test]
+fn test_parse_os_info() {
+    let (kernel, os) = parse_os_info(OS_INFO);
+    assert_eq!(kernel.as_deref(), Some("6.1.0-18-amd64"));
+    assert_eq!(os.as_deref(), Some("Debian GNU/Linux 12 (bookworm)"));
+
+    // os-release 가 없는 호스트
+    assert_eq!(parse_os_info("5.4.0\n"), (Some("5.4.0".to_string()), None));
+}
@@ -0,0 +1,82 @@
+// tests/monitoring/ports.rs
+use rust_server::db::models::AlertSeverity;
+use rust_server::models::port::ListeningPort;
+use rust_server::monitoring::ports::{diff_ports, opened_port_alerts, parse_port_output};
+
+const SS: &str = include_str!("../fixtures/ss_tulpn.txt");
+const PROC_NET: &str = include_str!("../fixtures/proc_net_ports.txt");
+
+fn port(protocol: &str, address: &str, port: u16, process: Option<&str>) -> ListeningPort {
+    ListeningPort {
+        protocol: protocol.to_string(),
+        address: address.to_string(),
+        port,
+        process: process.map(str::to_string),
+        pid: None,
+    }
+}
+
+#[test]
+fn test_parse_ss_output() {
+    let ports = parse_port_output(SS).unwrap();
+    // 같은 소켓(SO_REUSEPORT)은 한 번만
+    assert_eq!(ports.len(), 4);
+
+    assert_eq!(ports[0], ListeningPort {
+        protocol: "udp".to_string(),
+        address: "127.0.0.53".to_string(),
+        port: 53,
+        process: Some("systemd-resolve".to_string()),
+        pid: Some(612),
+    });
+    assert_eq!(ports[2].address, "::");
+    assert_eq!(ports[2].port, 80);
+    assert_eq!(ports[2].process.as_deref(), Some("nginx"));
+    assert_eq!(ports[2].pid, Some(1201));
+
+    // 권한이 없으면 프로세스 열이 비어 있다
+    assert_eq!(ports[3], port("tcp", "*", 9100, None));
+}
+
+#[test]
+fn test_parse_proc_net_output() {
+    let ports = parse_port_output(PROC_NET).unwrap();
+    // ESTABLISHED 연결은 제외
+    assert_eq!(ports, vec![
+        port("tcp", "0.0.0.0", 22, None),
+        port("tcp", "127.0.0.1", 5432, None),
+        port("tcp", "::1", 80, None),
+        port("udp", "127.0.0.53", 53, None),
+    ]);
+}
+
+#[test]
+fn test_diff_ports() {
+    let previous = vec![port("tcp", "0.0.0.0", 22, Some("sshd")), port("tcp", "::", 80, Some("nginx"))];
+    let current = vec![
+        port("tcp", "0.0.0.0", 22, Some("sshd")),
+        // 프로세스만 바뀐 경우는 같은 포트
+        port("tcp", "::", 80, Some("apache2")),
+        port("tcp", "0.0.0.0", 4444, Some("nc")),
+    ];
+
+    let (opened, closed) = diff_ports(&previous, &current);
+    assert_eq!(opened, vec![port("tcp", "0.0.0.0", 4444, Some("nc"))]);
+    assert!(closed.is_empty());
+
+    let (opened, closed) = diff_ports(&current, &previous);
+    assert!(opened.is_empty());
+    assert_eq!(closed.len(), 1);
+}
+
+#[test]
+fn test_opened_port_alerts() {
+    let mut opened = port("tcp", "0.0.0.0", 4444, Some("nc"));
+    opened.pid = Some(3100);
+
+    let alerts = opened_port_alerts("srv-1", &[opened]);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_type, "port_opened");
+    assert_eq!(alerts[0].severity, AlertSeverity::Info);
+    assert_eq!(alerts[0].message, "New listening port 4444/tcp on 0.0.0.0 (nc, pid 3100)");
+}
@@ -0,0 +1,175 @@
+// tests/monitoring/ssh.rs
+use rust_server::monitoring::ssh::{SshSource, SshTarget, SECTION_MARKER};
+use rust_server::monitoring::MetricSource;
+
+const NET_DEV: &str = include_str!("../fixtures/net_dev.txt");
+const UPTIME: &str = include_str!("../fixtures/uptime.txt");
+
+fn fixture_output() -> String {
+    collection_output(NET_DEV, UPTIME)
+}
+
+fn collection_output(net_dev: &str, uptime: &str) -> String {
+    [
+        include_str!("../fixtures/proc_stat.txt"),
+        include_str!("../fixtures/meminfo.txt"),
+        net_dev,
+        include_str!("../fixtures/df_typed.txt"),
+        include_str!("../fixtures/ps.txt"),
+        include_str!("../fixtures/loadavg.txt"),
+        include_str!("../fixtures/df_inodes.txt"),
+        uptime,
+        include_str!("../fixtures/diskstats.txt"),
+        include_str!("../fixtures/os_info.txt"),
+    ]
+    .join(&format!("{}\n", SECTION_MARKER))
+}
+
+fn test_target() -> SshTarget {
+    SshTarget {
+        host: std::env::var("SSH_TEST_HOST").unwrap_or_else(|_| "127.0.0.1".to_string()),
+        port: std::env::var("SSH_TEST_PORT").ok().and_then(|p| p.parse().ok()).unwrap_or(22),
+        username: std::env::var("SSH_TEST_USER").unwrap_or_else(|_| "root".to_string()),
+        password: std::env::var("SSH_TEST_PASSWORD").unwrap_or_default(),
+    }
+}
+
+#[test]
+fn test_parse_collection_output() {
+    let source = SshSource::new(test_target());
+    let metrics = source.parse_output(&fixture_output()).unwrap();
+
+    assert!((metrics.memory_usage - 50.0).abs() < 0.01);
+    assert_eq!(metrics.memory.unwrap().cached, 3072000 * 1024);
+    assert!((metrics.disk_usage - 19.99).abs() < 0.01);
+    assert!(metrics.cpu_usage > 0.0 && metrics.cpu_usage <= 100.0);
+    // 첫 샘플은 네트워크 변화량이 없음
+    assert_eq!(metrics.network_rx, 0);
+    assert_eq!(metrics.processes.len(), 3);
+    assert_eq!(metrics.cpu_per_core.len(), 2);
+    assert_eq!(metrics.load_average.unwrap().fifteen, 0.59);
+    assert!(metrics.cpu_breakdown.unwrap().iowait > 0.0);
+    assert_eq!(metrics.filesystems.len(), 2);
+    assert!(metrics.filesystems[0].inodes_used.is_some());
+
+    assert!(metrics.network_interfaces.is_empty());
+
+    let host = metrics.host.unwrap();
+    assert_eq!(host.uptime_seconds, 350735);
+    assert_eq!(host.kernel.as_deref(), Some("6.1.0-18-amd64"));
+    assert_eq!(host.os.as_deref(), Some("Debian GNU/Linux 12 (bookworm)"));
+
+    // 같은 카운터로 다시 파싱하면 변화량 0
+    let metrics = source.parse_output(&collection_output(NET_DEV, "350745.47 1386525.22")).unwrap();
+    assert_eq!(metrics.cpu_usage, 0.0);
+    assert_eq!(metrics.network_tx, 0);
+    assert_eq!(metrics.disk_io.len(), 4);
+    assert!(metrics.disk_io.iter().all(|d| d.utilization == 0.0));
+}
+
+#[test]
+fn test_parse_collection_output_network_rates() {
+    let source = SshSource::new(test_target());
+    source.parse_output(&fixture_output()).unwrap();
+
+    // 10초 동안 eth0 수신 1MB, 송신 200KB, lo 는 합계에서 제외
+    let net_dev = NET_DEV
+        .replace("98765432   65432    2    1", "99813992   66432    2    4")
+        .replace("12345678   43210", "12550478   43710")
+        .replace("123456     1000", "923456     1500");
+    let metrics = source.parse_output(&collection_output(&net_dev, "350745.47 1386525.22")).unwrap();
+
+    assert_eq!(metrics.network_rx, 104856);
+    assert_eq!(metrics.network_tx, 20480);
+    assert_eq!(metrics.network_interfaces.len(), 1);
+
+    let eth0 = &metrics.network_interfaces[0];
+    assert_eq!(eth0.interface, "eth0");
+    assert!((eth0.rx_packets_per_sec - 100.0).abs() < 0.01);
+    assert!((eth0.tx_packets_per_sec - 50.0).abs() < 0.01);
+    assert_eq!(eth0.rx_drops, 3);
+    assert_eq!(eth0.rx_errors, 0);
+
+    // uptime 이 줄었으면 재부팅으로 보고 기준값을 다시 잡는다
+    let metrics = source.parse_output(&collection_output(&net_dev, "12.00 20.00")).unwrap();
+    assert!(metrics.network_interfaces.is_empty());
+}
+
+#[test]
+fn test_parse_process_output() {
+    let output = [
+        include_str!("../fixtures/ps_detail.txt"),
+        include_str!("../fixtures/ps_names.txt"),
+        include_str!("../fixtures/fd_counts.txt"),
+    ]
+    .join(&format!("{}\n", SECTION_MARKER));
+
+    let processes = SshSource::parse_process_output(&output, chrono::Utc::now()).unwrap();
+    assert_eq!(processes.len(), 4);
+    assert_eq!(processes[1].name, "postgres");
+    assert_eq!(processes[1].open_files, Some(45));
+    // 읽을 권한이 없던 프로세스
+    assert_eq!(processes[2].open_files, None);
+
+    assert!(SshSource::parse_process_output("1 0 root", chrono::Utc::now()).is_err());
+}
+
+#[test]
+fn test_service_command_and_output() {
+    let command = SshSource::service_command(&["nginx.service".to_string(), "getty@tty1.service".to_string()]).unwrap();
+    assert!(command.ends_with("-- 'nginx.service' 'getty@tty1.service'"));
+    assert!(SshSource::service_command(&["nginx; reboot".to_string()]).is_err());
+
+    let output = format!(
+        "{}{}\n{}",
+        include_str!("../fixtures/uptime.txt"),
+        SECTION_MARKER,
+        include_str!("../fixtures/systemctl_show.txt")
+    );
+    let services = SshSource::parse_service_output(&output, chrono::Utc::now()).unwrap();
+    assert_eq!(services.len(), 3);
+    assert_eq!(services[0].sub_state, "running");
+}
+
+#[test]
+fn test_collection_samples_top_cpu_and_memory_processes() {
+    let source = SshSource::new(test_target()).with_process_limit(2);
+    let command = source.collection_command();
+    assert!(command.contains("ps -eo pid,pcpu,rss,comm --sort=-pcpu | head -n 3"));
+    assert!(command.contains("ps -eo pid,pcpu,rss,comm --no-headers --sort=-rss | head -n 2"));
+
+    // 두 번째 ps 는 헤더가 없고 첫 번째와 겹칠 수 있다
+    let ps = [
+        "    PID %CPU   RSS COMMAND",
+        "   1234 35.5 204800 postgres",
+        "    987 12.0  51200 nginx: worker",
+        "   4321  0.0 8388608 java",
+        "   1234 35.5 204800 postgres",
+    ]
+    .join("\n");
+    let output = fixture_output().replace(include_str!("../fixtures/ps.txt"), &format!("{}\n", ps));
+    let metrics = source.parse_output(&output).unwrap();
+    let pids: Vec<u32> = metrics.processes.iter().map(|p| p.pid).collect();
+    assert_eq!(pids, vec![1234, 987, 4321]);
+    assert_eq!(metrics.processes[2].memory_usage, 8388608 * 1024);
+}
+
+#[test]
+fn test_parse_collection_output_missing_section() {
+    let source = SshSource::new(test_target());
+    assert!(source.parse_output("cpu  1 2 3 4").is_err());
+}
+
+// 로컬 sshd 필요: SSH_TEST_HOST/PORT/USER/PASSWORD 설정 후 --ignored 로 실행
+#[tokio::test]
+#[ignore]
+async fn test_collect_from_local_sshd() {
+    let source = SshSource::new(test_target());
+    assert!(source.capabilities().remote);
+
+    let metrics = source.collect().await.unwrap();
+
+    assert!(metrics.memory_usage > 0.0 && metrics.memory_usage <= 100.0);
+    assert!(metrics.disk_usage >= 0.0 && metrics.disk_usage <= 100.0);
+    assert!(!metrics.processes.is_empty());
+}
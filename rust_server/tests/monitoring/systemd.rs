@@ -0,0 +1,45 @@
+// tests/monitoring/systemd.rs
+use rust_server::models::service::ServiceStatus;
+use rust_server::monitoring::systemd::ServiceEvaluator;
+
+fn service(unit: &str, active_state: &str) -> ServiceStatus {
+    ServiceStatus {
+        unit: unit.to_string(),
+        load_state: "loaded".to_string(),
+        active_state: active_state.to_string(),
+        sub_state: if active_state == "active" { "running" } else { "failed" }.to_string(),
+        restarts: Some(2),
+        since: None,
+    }
+}
+
+#[test]
+fn test_alerts_on_transition_into_failed() {
+    let evaluator = ServiceEvaluator::new();
+
+    assert!(evaluator.evaluate("server-1", &[service("nginx.service", "active")]).is_empty());
+
+    let alerts = evaluator.evaluate("server-1", &[service("nginx.service", "failed")]);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_type, "service_failed");
+    assert!(alerts[0].message.contains("was active"));
+
+    // failed 가 유지되는 동안은 다시 알리지 않는다
+    assert!(evaluator.evaluate("server-1", &[service("nginx.service", "failed")]).is_empty());
+
+    assert!(evaluator.evaluate("server-1", &[service("nginx.service", "activating")]).is_empty());
+    assert_eq!(evaluator.evaluate("server-1", &[service("nginx.service", "failed")]).len(), 1);
+}
+
+#[test]
+fn test_state_is_tracked_per_server() {
+    let evaluator = ServiceEvaluator::new();
+    let failed = [service("nginx.service", "failed")];
+
+    assert_eq!(evaluator.evaluate("server-1", &failed).len(), 1);
+    assert_eq!(evaluator.evaluate("server-2", &failed).len(), 1);
+
+    evaluator.clear("server-1");
+    assert_eq!(evaluator.evaluate("server-1", &failed).len(), 1);
+    assert!(evaluator.evaluate("server-2", &failed).is_empty());
+}
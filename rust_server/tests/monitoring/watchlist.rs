@@ -0,0 +1,100 @@
+// tests/monitoring/watchlist.rs
+use chrono::Utc;
+use rust_server::db::models::{AlertSeverity, ProcessWatch};
+use rust_server::models::process::ProcessDetail;
+use rust_server::monitoring::watchlist::WatchlistEvaluator;
+
+fn watch(pattern: &str, min_instances: i32) -> ProcessWatch {
+    ProcessWatch {
+        id: "watch-1".to_string(),
+        server_id: "server-1".to_string(),
+        name: "nginx".to_string(),
+        pattern: pattern.to_string(),
+        match_command: false,
+        min_instances,
+        max_cpu: None,
+        max_memory: None,
+        created_by: None,
+        created_at: Utc::now(),
+    }
+}
+
+fn process(pid: u32, name: &str, cpu_usage: f32, memory_usage: u64) -> ProcessDetail {
+    ProcessDetail {
+        pid,
+        name: name.to_string(),
+        command: format!("/usr/sbin/{} -g daemon off;", name),
+        cpu_usage,
+        memory_usage,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_missing_process_alerts_once_until_recovered() {
+    let evaluator = WatchlistEvaluator::new();
+    let watches = vec![watch("^nginx$", 2)];
+
+    let alerts = evaluator.evaluate("server-1", &watches, &[process(10, "nginx", 1.0, 100)]);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_type, "process_missing");
+    assert_eq!(alerts[0].severity, AlertSeverity::Critical);
+
+    // 계속 부족한 동안에는 다시 알리지 않는다
+    assert!(evaluator.evaluate("server-1", &watches, &[process(10, "nginx", 1.0, 100)]).is_empty());
+
+    let running = [process(10, "nginx", 1.0, 100), process(11, "nginx", 1.0, 100)];
+    assert!(evaluator.evaluate("server-1", &watches, &running).is_empty());
+    assert_eq!(evaluator.evaluate("server-1", &watches, &[]).len(), 1);
+}
+
+#[test]
+fn test_pid_change_is_reported_as_restart() {
+    let evaluator = WatchlistEvaluator::new();
+    let watches = vec![watch("^nginx$", 1)];
+
+    assert!(evaluator.evaluate("server-1", &watches, &[process(10, "nginx", 1.0, 100)]).is_empty());
+    // 인스턴스가 늘어난 것은 재시작이 아니다
+    let scaled = [process(10, "nginx", 1.0, 100), process(11, "nginx", 1.0, 100)];
+    assert!(evaluator.evaluate("server-1", &watches, &scaled).is_empty());
+
+    let alerts = evaluator.evaluate("server-1", &watches, &[process(12, "nginx", 1.0, 100)]);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_type, "process_restart");
+    assert!(alerts[0].message.contains("10,11 -> 12"));
+}
+
+#[test]
+fn test_resource_limits_and_command_match() {
+    let evaluator = WatchlistEvaluator::new();
+    let mut limited = watch("daemon off", 1);
+    limited.match_command = true;
+    limited.max_cpu = Some(50.0);
+    limited.max_memory = Some(1000);
+    let watches = vec![limited];
+
+    let alerts = evaluator.evaluate("server-1", &watches, &[process(10, "nginx", 75.0, 2000)]);
+    let types: Vec<&str> = alerts.iter().map(|a| a.alert_type.as_str()).collect();
+    assert_eq!(types, vec!["process_cpu", "process_memory"]);
+
+    assert!(evaluator.evaluate("server-1", &watches, &[process(10, "nginx", 80.0, 2000)]).is_empty());
+    assert!(evaluator.evaluate("server-1", &watches, &[process(10, "nginx", 10.0, 500)]).is_empty());
+    assert_eq!(evaluator.evaluate("server-1", &watches, &[process(10, "nginx", 90.0, 500)]).len(), 1);
+}
+
+#[test]
+fn test_changed_pattern_is_recompiled() {
+    let evaluator = WatchlistEvaluator::new();
+    let running = [process(10, "nginx", 1.0, 100), process(20, "postgres", 1.0, 100)];
+
+    assert!(evaluator.evaluate("server-1", &[watch("^nginx$", 1)], &running).is_empty());
+    // 같은 감시 대상의 패턴이 바뀌면 캐시된 정규식을 쓰지 않는다
+    let alerts = evaluator.evaluate("server-1", &[watch("^redis$", 1)], &running);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_type, "process_missing");
+
+    // 잘못된 패턴은 건너뛰고, 고치면 다시 평가한다
+    assert!(evaluator.evaluate("server-1", &[watch("(unclosed", 1)], &running).is_empty());
+    assert!(evaluator.evaluate("server-1", &[watch("(unclosed", 1)], &running).is_empty());
+    assert!(evaluator.evaluate("server-1", &[watch("^postgres$", 1)], &running).is_empty());
+}
@@ -1,247 +1,227 @@
 // tests/websockets/handlers.rs
-use actix::{Actor, ActorContext, ActorState, System};
-use actix_web::{test, web};
+use actix_web::{test, web, App};
 use actix_web_actors::ws;
+use futures::channel::mpsc;
+use futures::{Stream, StreamExt};
 use rust_server::{
-    websocket::handlers::{WebSocketConnection, ServerMetrics},
+    websocket::{ws_index, handlers::{WebSocketConnection, ServerMetrics}},
     monitoring::MonitoringService,
     db::{models::*, repository::Repository},
 };
+use sqlx::postgres::PgPoolOptions;
 use std::time::Duration;
-use tokio::time::sleep;
-use mockall::predicate::*;
-use mockall::mock;
-// use actix_http::ws::codec;
-
-mock! {
-    Repository {
-        async fn get_server_metrics(&self, server_id: &str) -> Result<Option<MetricsSnapshot>, anyhow::Error>;
+
+const OP_TEXT: u8 = 0x1;
+const OP_CLOSE: u8 = 0x8;
+const OP_PING: u8 = 0x9;
+const OP_PONG: u8 = 0xA;
+
+type Input = Result<web::Bytes, actix_web::error::PayloadError>;
+
+// 연결하지 않는 풀. 구독한 서버의 수집이 시작되어도 조회만 실패한다
+fn monitoring_service() -> MonitoringService {
+    let pool = PgPoolOptions::new()
+        .acquire_timeout(Duration::from_millis(100))
+        .connect_lazy("postgres://localhost:1/unused")
+        .unwrap();
+    MonitoringService::new(web::Data::new(Repository::new(pool)))
+}
+
+fn test_metrics(server_id: &str) -> ServerMetrics {
+    ServerMetrics {
+        server_id: server_id.to_string(),
+        metrics: MetricsSnapshot {
+            id: 0,
+            server_id: server_id.to_string(),
+            cpu_usage: 50.0,
+            memory_usage: 70.0,
+            disk_usage: 60.0,
+            network_rx: 1000,
+            network_tx: 2000,
+            processes: serde_json::json!([]),
+            timestamp: chrono::Utc::now(),
+            cpu_per_core: serde_json::json!([]),
+            load_1: None,
+            load_5: None,
+            load_15: None,
+            cpu_user: None,
+            cpu_system: None,
+            cpu_iowait: None,
+            cpu_steal: None,
+            filesystems: serde_json::json!([]),
+            network_interfaces: serde_json::json!([]),
+            disk_io: serde_json::json!([]),
+            disk_io_util: None,
+            disk_read_bps: None,
+            disk_write_bps: None,
+            memory_total: None,
+            memory_used: None,
+            memory_available: None,
+            memory_cached: None,
+            memory_buffers: None,
+            swap_total: None,
+            swap_used: None,
+        },
     }
 }
 
-// 테스트용 WebSocket Context
-struct TestContext {
-    actor: WebSocketConnection,
+// 클라이언트 프레임 하나. 클라이언트는 마스킹해야 하므로 0 키로 마스킹한다
+fn client_frame(opcode: u8, payload: &[u8]) -> Input {
+    assert!(payload.len() < 126);
+    let mut frame = vec![0x80 | opcode, 0x80 | payload.len() as u8, 0, 0, 0, 0];
+    frame.extend_from_slice(payload);
+    Ok(web::Bytes::from(frame))
 }
 
-impl TestContext {
-    fn new(actor: WebSocketConnection) -> Self {
-        TestContext { actor }
-    }
+fn text(value: serde_json::Value) -> Input {
+    client_frame(OP_TEXT, value.to_string().as_bytes())
 }
 
-impl ActorContext for TestContext {
-    fn stop(&mut self) {
-        // 테스트용 구현
-    }
+fn subscribe(server_id: &str) -> Input {
+    text(serde_json::json!({"type": "server_metrics.subscribe", "data": {"server_id": server_id}}))
+}
 
-    fn terminate(&mut self) {
-        // 테스트용 구현
-    }
+// 서버가 보낸 프레임 하나를 (opcode, payload) 로. 서버 프레임은 마스킹하지 않는다
+fn decode_frame(frame: &[u8]) -> (u8, Vec<u8>) {
+    let opcode = frame[0] & 0x0f;
+    let (len, start) = match frame[1] & 0x7f {
+        126 => (u16::from_be_bytes([frame[2], frame[3]]) as usize, 4),
+        127 => (u64::from_be_bytes(frame[2..10].try_into().unwrap()) as usize, 10),
+        len => (len as usize, 2),
+    };
+    (opcode, frame[start..start + len].to_vec())
+}
 
-    fn state(&self) -> ActorState {
-        ActorState::Started
+// 다음 프레임. 시간 안에 오지 않으면 None
+async fn next_frame<S>(output: &mut S) -> Option<(u8, Vec<u8>)>
+where
+    S: Stream<Item = Result<web::Bytes, actix_web::Error>> + Unpin,
+{
+    match tokio::time::timeout(Duration::from_millis(200), output.next()).await {
+        Ok(Some(Ok(bytes))) => Some(decode_frame(&bytes)),
+        _ => None,
     }
 }
 
 #[actix_rt::test]
-async fn test_websocket_connection_creation() {
-    let mock_repo = Repository::new(test::TestServer::new().await.to_owned());
-    let monitoring_service = MonitoringService::new(web::Data::new(mock_repo));
-    let connection = WebSocketConnection::new(monitoring_service);
-    
-    // 기본적인 생성 확인
-    assert!(connection.last_heartbeat.elapsed() < Duration::from_secs(1));
+async fn test_ping_is_answered_with_pong() {
+    let (tx, rx) = mpsc::unbounded::<Input>();
+    let mut output = Box::pin(ws::WebsocketContext::create(WebSocketConnection::new(monitoring_service()), rx));
+
+    tx.unbounded_send(client_frame(OP_PING, b"hello")).unwrap();
+    assert_eq!(next_frame(&mut output).await, Some((OP_PONG, b"hello".to_vec())));
 }
 
 #[actix_rt::test]
-async fn test_websocket_message_handling() {
-    let mock_repo = Repository::new(test::TestServer::new().await.to_owned());
-    let monitoring_service = MonitoringService::new(web::Data::new(mock_repo));
-    let connection = WebSocketConnection::new(monitoring_service);
-    
-    // Actor 시스템 생성
-    let sys = System::new();
-    
-    sys.block_on(async {
-        // Actor 시작
-        let addr = connection.start();
-        
-        // 구독 메시지 전송
-        let subscribe_msg = ws::Message::Text(
-            r#"{"type":"server_metrics.subscribe","data":{"server_id":"test-server"}}"#.into()
-        );
-        addr.do_send(subscribe_msg);
-        
-        // 약간의 시간을 주어 메시지 처리
-        sleep(Duration::from_millis(100)).await;
-        
-        // Actor 종료
-        addr.do_send(ws::Message::Close(None));
-    });
+async fn test_metrics_sent_for_subscribed_server_only() {
+    let (tx, rx) = mpsc::unbounded::<Input>();
+    let (addr, output) = ws::WebsocketContext::create_with_addr(WebSocketConnection::new(monitoring_service()), rx);
+    let mut output = Box::pin(output);
+
+    // 구독 전에는 보내지 않는다
+    addr.do_send(test_metrics("test-server"));
+    assert_eq!(next_frame(&mut output).await, None);
+
+    tx.unbounded_send(subscribe("test-server")).unwrap();
+    assert_eq!(next_frame(&mut output).await, None);
+    addr.do_send(test_metrics("other-server"));
+    addr.do_send(test_metrics("test-server"));
+
+    let (opcode, payload) = next_frame(&mut output).await.unwrap();
+    assert_eq!(opcode, OP_TEXT);
+    let message: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+    assert_eq!(message["type"], "server_metrics.update");
+    assert_eq!(message["data"]["server_id"], "test-server");
+    assert_eq!(message["data"]["metrics"]["cpu_usage"], 50.0);
+    assert_eq!(next_frame(&mut output).await, None);
 }
 
 #[actix_rt::test]
-async fn test_metrics_handling() {
-    let mock_repo = Repository::new(test::TestServer::new().await.to_owned());
-    let monitoring_service = MonitoringService::new(web::Data::new(mock_repo));
-    let connection = WebSocketConnection::new(monitoring_service);
-    
-    // Actor 시스템 생성
-    let sys = System::new();
-    
-    sys.block_on(async {
-        let addr = connection.start();
-        
-        // 테스트 메트릭 생성
-        let metrics = ServerMetrics {
-            server_id: "test-server".to_string(),
-            metrics: MetricsSnapshot {
-                id: 0,
-                server_id: "test-server".to_string(),
-                cpu_usage: 50.0,
-                memory_usage: 70.0,
-                disk_usage: 60.0,
-                network_rx: 1000,
-                network_tx: 2000,
-                processes: serde_json::json!([]),
-                timestamp: chrono::Utc::now(),
-            },
-        };
-        
-        // 메트릭 메시지 전송
-        addr.do_send(metrics);
-        
-        // 메시지 처리 대기
-        sleep(Duration::from_millis(100)).await;
-        
-        // Actor 종료
-        addr.do_send(ws::Message::Close(None));
-    });
+async fn test_unsubscribe_stops_metrics() {
+    let (tx, rx) = mpsc::unbounded::<Input>();
+    let (addr, output) = ws::WebsocketContext::create_with_addr(WebSocketConnection::new(monitoring_service()), rx);
+    let mut output = Box::pin(output);
+
+    tx.unbounded_send(subscribe("test-server")).unwrap();
+    tx.unbounded_send(text(serde_json::json!({"type": "server_metrics.unsubscribe"}))).unwrap();
+    assert_eq!(next_frame(&mut output).await, None);
+
+    addr.do_send(test_metrics("test-server"));
+    assert_eq!(next_frame(&mut output).await, None);
 }
 
 #[actix_rt::test]
-async fn test_heartbeat() {
-    let mock_repo = Repository::new(test::TestServer::new().await.to_owned());
-    let monitoring_service = MonitoringService::new(web::Data::new(mock_repo));
-    let connection = WebSocketConnection::new(monitoring_service);
-    
-    let sys = System::new();
-    
-    sys.block_on(async {
-        let addr = connection.start();
-        
-        // Ping 메시지 전송
-        addr.do_send(ws::Message::Ping(vec![]));
-        
-        // 응답 대기
-        sleep(Duration::from_millis(100)).await;
-        
-        // Actor 종료
-        addr.do_send(ws::Message::Close(None));
-    });
+async fn test_close_ends_connection() {
+    let (tx, rx) = mpsc::unbounded::<Input>();
+    let mut output = Box::pin(ws::WebsocketContext::create(WebSocketConnection::new(monitoring_service()), rx));
+
+    tx.unbounded_send(client_frame(OP_CLOSE, b"")).unwrap();
+    assert_eq!(next_frame(&mut output).await, Some((OP_CLOSE, Vec::new())));
+    assert!(output.next().await.is_none());
 }
 
 #[actix_rt::test]
 async fn test_websocket_connection_lifecycle() {
-    let mock_repo = web::Data::new(MockRepository::new());
-    let monitoring_service = MonitoringService::new(mock_repo);
-    let connection = WebSocketConnection::new(monitoring_service);
-    
-    // Start actor
-    let addr = connection.start();
+    let (tx, rx) = mpsc::unbounded::<Input>();
+    let (addr, output) = ws::WebsocketContext::create_with_addr(WebSocketConnection::new(monitoring_service()), rx);
+    let mut output = Box::pin(output);
     assert!(addr.connected());
-    
-    // Stop actor
-    addr.stop();
-    sleep(Duration::from_millis(100)).await;
-    assert!(!addr.connected());
-}
-
-#[actix_rt::test]
-async fn test_metrics_subscription() {
-    let mock_repo = web::Data::new(MockRepository::new());
-    let monitoring_service = MonitoringService::new(mock_repo);
-    let mut connection = WebSocketConnection::new(monitoring_service);
-    let mut ctx = ws::WebsocketContext::new(connection);
-
-    // Subscribe to metrics
-    let subscribe_msg = r#"{"type":"server_metrics.subscribe","data":{"server_id":"test-server"}}"#;
-    ctx.address().do_send(ws::Message::Text(subscribe_msg.into()));
-    
-    // Give some time for subscription to process
-    sleep(Duration::from_millis(100)).await;
-    
-    // Check if metrics stream started
-    assert!(ctx.address().connected());
-}
 
-#[actix_rt::test]
-async fn test_metrics_unsubscription() {
-    let mock_repo = web::Data::new(MockRepository::new());
-    let monitoring_service = MonitoringService::new(mock_repo);
-    let mut connection = WebSocketConnection::new(monitoring_service);
-    let mut ctx = ws::WebsocketContext::new(connection);
-
-    // First subscribe
-    let subscribe_msg = r#"{"type":"server_metrics.subscribe","data":{"server_id":"test-server"}}"#;
-    ctx.address().do_send(ws::Message::Text(subscribe_msg.into()));
-    sleep(Duration::from_millis(100)).await;
-
-    // Then unsubscribe
-    let unsubscribe_msg = r#"{"type":"server_metrics.unsubscribe"}"#;
-    ctx.address().do_send(ws::Message::Text(unsubscribe_msg.into()));
-    sleep(Duration::from_millis(100)).await;
-
-    // Verify unsubscription
-    assert!(ctx.address().connected());
+    // 클라이언트가 연결을 끊으면 액터도 멈춘다
+    drop(tx);
+    while output.next().await.is_some() {}
+    drop(output);
+    assert!(!addr.connected());
 }
 
+// 클라이언트가 응답하지 않으면 heartbeat 가 핑을 보내다가 CLIENT_TIMEOUT(10초) 뒤 연결을 닫는다
 #[actix_rt::test]
 async fn test_heartbeat_timeout() {
-    let mock_repo = web::Data::new(MockRepository::new());
-    let monitoring_service = MonitoringService::new(mock_repo);
-    let mut connection = WebSocketConnection::new(monitoring_service);
-    let mut ctx = ws::WebsocketContext::new(connection);
-
-    // Start connection
-    ctx.address().do_send(ws::Message::Ping(vec![]));
-    assert!(ctx.address().connected());
-
-    // Wait for more than heartbeat timeout
-    sleep(Duration::from_secs(11)).await;
-    
-    // Connection should be closed due to timeout
-    assert!(!ctx.address().connected());
+    let (_tx, rx) = mpsc::unbounded::<Input>();
+    let (addr, output) = ws::WebsocketContext::create_with_addr(WebSocketConnection::new(monitoring_service()), rx);
+    let mut output = Box::pin(output);
+
+    let mut pings = 0;
+    let closed = tokio::time::timeout(Duration::from_secs(20), async {
+        while let Some(Ok(bytes)) = output.next().await {
+            if decode_frame(&bytes).0 == OP_PING {
+                pings += 1;
+            }
+        }
+    })
+    .await;
+
+    assert!(closed.is_ok());
+    assert!(pings >= 1);
+    drop(output);
+    assert!(!addr.connected());
 }
 
 #[actix_rt::test]
-async fn test_metrics_message_format() {
-    let mock_repo = web::Data::new(MockRepository::new());
-    let monitoring_service = MonitoringService::new(mock_repo);
-    let mut connection = WebSocketConnection::new(monitoring_service);
-    let mut ctx = ws::WebsocketContext::new(connection);
-
-    // Create test metrics
-    let metrics = ServerMetrics {
-        server_id: "test-server".to_string(),
-        metrics: MetricsSnapshot {
-            id: 0,
-            server_id: "test-server".to_string(),
-            cpu_usage: 50.0,
-            memory_usage: 70.0,
-            disk_usage: 60.0,
-            network_rx: 1000,
-            network_tx: 2000,
-            processes: serde_json::json!([]),
-            timestamp: chrono::Utc::now(),
-        },
-    };
-
-    // Send metrics message
-    ctx.address().do_send(metrics);
-    sleep(Duration::from_millis(100)).await;
-
-    // Verify message format (would need to capture output in real implementation)
-    assert!(ctx.address().connected());
-}
\ No newline at end of file
+async fn test_ws_index_handshake() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(monitoring_service()))
+            .route("/ws", web::get().to(ws_index)),
+    )
+    .await;
+
+    // 업그레이드 헤더 없이는 거절
+    let req = test::TestRequest::get().uri("/ws").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::get()
+        .uri("/ws")
+        .insert_header(("upgrade", "websocket"))
+        .insert_header(("connection", "upgrade"))
+        .insert_header(("sec-websocket-version", "13"))
+        .insert_header(("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ=="))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), actix_web::http::StatusCode::SWITCHING_PROTOCOLS);
+    assert_eq!(
+        resp.headers().get("sec-websocket-accept").unwrap(),
+        "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+    );
+}